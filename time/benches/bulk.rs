@@ -0,0 +1,59 @@
+//! Compares the bulk Gregorian conversions with converting each day on its own, for the clustered days of a
+//! timeline.
+//!
+//! Run with `cargo bench --bench bulk`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use time::StandardCalendar;
+use time::date::gregorian::{Date, dates_from_days, days_from_dates};
+
+/// How many times each conversion runs, keeping the fastest run.
+const RUNS: usize = 20;
+
+/// The fastest of [`RUNS`] runs of `run`.
+fn fastest<T>(mut run: impl FnMut() -> T) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(name: &str, bulk: Duration, scalar: Duration) {
+    println!(
+        "{name}: {bulk:?} in bulk, {scalar:?} one by one ({:.2}x)",
+        scalar.as_secs_f64() / bulk.as_secs_f64()
+    );
+}
+
+fn main() {
+    // A day apart, over about 2,700 years from 2020.
+    let days: Vec<i128> = (737_424..737_424 + 1_000_000).collect();
+    let dates = dates_from_days(&days);
+
+    report(
+        "days to dates",
+        fastest(|| dates_from_days(black_box(&days))),
+        fastest(|| {
+            black_box(&days)
+                .iter()
+                .map(|days| Date::from(StandardCalendar::new(*days)))
+                .collect::<Vec<_>>()
+        }),
+    );
+    report(
+        "dates to days",
+        fastest(|| days_from_dates(black_box(&dates))),
+        fastest(|| {
+            black_box(&dates)
+                .iter()
+                .map(|date| StandardCalendar::from(date).days)
+                .collect::<Vec<_>>()
+        }),
+    );
+}
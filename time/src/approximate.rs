@@ -0,0 +1,409 @@
+//! Dates that are only known up to some [`Precision`], such as "March 1917" or "the 4th century BCE".
+//!
+//! They can be read and written as [EDTF](edtf) with [`ApproximateDate::from_edtf`] and
+//! [`ApproximateDate::to_edtf`].
+
+use std::cmp::Ordering;
+
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+use crate::date::gregorian::{Date, Month, Year};
+
+pub mod edtf;
+
+/// How precisely a date is known.
+///
+/// Ordered from the finest to the coarsest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precision {
+    Day,
+    Month,
+    Year,
+    /// A span of 10 years.
+    Decade,
+    /// A span of 100 years.
+    Century,
+    /// A span of 1000 years.
+    Millennium,
+}
+
+/// A [Gregorian](crate::date::gregorian) date known only up to some [`Precision`].
+///
+/// It stands for a window of time starting on [`start`](ApproximateDate::start) and lasting one unit of its
+/// precision. The window of a [`Precision::Decade`] starting in 1990 is 1990 to 1999, so "the 1990s" and
+/// "the 4th century BCE" (starting in 400 BCE) can both be represented.
+///
+/// # Examples
+/// ```
+/// use time::{approximate::{ApproximateDate, Precision}, date::gregorian::{Date, Month, year}};
+///
+/// let date = ApproximateDate::new(Date::from_parts(year!(1917), Month::March, 8).unwrap(), Precision::Month);
+/// assert_eq!(date.start(), Date::from_parts(year!(1917), Month::March, 1).unwrap());
+/// assert_eq!(date.end(), Date::from_parts(year!(1917), Month::March, 31).unwrap());
+///
+/// let date = ApproximateDate::new(Date::from_year(year!(-400)), Precision::Century);
+/// assert_eq!(date.end(), Date::from_parts(year!(-301), Month::December, 31).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApproximateDate {
+    start: Date,
+    precision: Precision,
+    approximate: bool,
+    uncertain: bool,
+}
+
+impl ApproximateDate {
+    /// Creates an [`ApproximateDate`] from the start of its window and its `precision`.
+    ///
+    /// Parts of `start` finer than `precision` are dropped: a date with [`Precision::Year`] always starts on January
+    /// 1st. Coarser precisions keep the year as given, since their windows don't have to be aligned.
+    pub fn new(start: Date, precision: Precision) -> Self {
+        let start = match precision {
+            Precision::Day => start,
+            // The 1st is valid in every month.
+            Precision::Month => Date::from_parts(start.year(), start.month(), 1).unwrap(),
+            _ => Date::from_year(start.year()),
+        };
+        Self {
+            start,
+            precision,
+            approximate: false,
+            uncertain: false,
+        }
+    }
+
+    /// Marks this date as approximate, like "c. 1066": even its window is not known for sure.
+    pub fn approximately(self) -> Self {
+        Self {
+            approximate: true,
+            ..self
+        }
+    }
+
+    /// Marks this date as uncertain, like "1984?": its window is known, but the source for it may be wrong.
+    pub fn uncertainly(self) -> Self {
+        Self {
+            uncertain: true,
+            ..self
+        }
+    }
+
+    /// The first day of this date's window.
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// The last day of this date's window, inclusive.
+    pub fn end(&self) -> Date {
+        let year = self.start.year();
+        let years_after =
+            |years: i128| Date::from_year(Year::from_astronomical(year.to_astronomical() + years));
+        let mut end = match self.precision {
+            Precision::Day => return self.start,
+            Precision::Month => match self.start.month() {
+                Month::December => Date::from_year(year.next()),
+                // The next month always exists, and has a 1st.
+                month => Date::from_parts(year, Month::try_from(u8::from(month) + 1).unwrap(), 1)
+                    .unwrap(),
+            },
+            Precision::Year => years_after(1),
+            Precision::Decade => years_after(10),
+            Precision::Century => years_after(100),
+            Precision::Millennium => years_after(1000),
+        };
+        // The day before the next window starts.
+        end.add_days(-1);
+        end
+    }
+
+    /// How precisely this date is known.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Whether this date is [approximate](ApproximateDate::approximately).
+    pub fn is_approximate(&self) -> bool {
+        self.approximate
+    }
+
+    /// Whether this date is [uncertain](ApproximateDate::uncertainly).
+    pub fn is_uncertain(&self) -> bool {
+        self.uncertain
+    }
+
+    /// How many days there could be from `first` to `second`, counting every day of their windows.
+    ///
+    /// The interval is negative when `second` is before `first`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{approximate::{ApproximateDate, DayInterval, Precision}, date::gregorian::{Date, Month, year}};
+    ///
+    /// let year_1850 = ApproximateDate::new(Date::from_year(year!(1850)), Precision::Year);
+    /// let new_year = ApproximateDate::from(Date::from_year(year!(1851)));
+    /// assert_eq!(ApproximateDate::days_between(&year_1850, &new_year), DayInterval { min: 1, max: 365 });
+    /// assert_eq!(ApproximateDate::days_between(&new_year, &year_1850), DayInterval { min: -365, max: -1 });
+    /// ```
+    pub fn days_between(first: &Self, second: &Self) -> DayInterval {
+        let (first_start, first_end) = first.days();
+        let (second_start, second_end) = second.days();
+        DayInterval {
+            min: second_start - first_end,
+            max: second_end - first_start,
+        }
+    }
+
+    /// Orders two dates only if one of them is certainly before the other, which means their windows don't overlap.
+    ///
+    /// Windows that are the same single day are [`Equal`](Ordering::Equal). This isn't a [`PartialOrd`]
+    /// implementation because two dates with the same wide window are equal, but not ordered.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use time::{approximate::{ApproximateDate, Precision}, date::gregorian::{Date, Month, year}};
+    ///
+    /// let year_1850 = ApproximateDate::new(Date::from_year(year!(1850)), Precision::Year);
+    /// let march_1850 = ApproximateDate::new(Date::from_year(year!(1850)), Precision::Month);
+    /// let year_1851 = ApproximateDate::new(Date::from_year(year!(1851)), Precision::Year);
+    ///
+    /// assert_eq!(year_1850.cmp_windows(&year_1850), None);
+    /// assert_eq!(year_1850.cmp_windows(&march_1850), None);
+    /// assert_eq!(year_1850.cmp_windows(&year_1851), Some(Ordering::Less));
+    /// ```
+    pub fn cmp_windows(&self, other: &Self) -> Option<Ordering> {
+        let (start, end) = self.days();
+        let (other_start, other_end) = other.days();
+        if end < other_start {
+            Some(Ordering::Less)
+        } else if start > other_end {
+            Some(Ordering::Greater)
+        } else if start == end && (start, end) == (other_start, other_end) {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    /// Orders two dates by the middle of their windows, for when every date needs a place, like when sorting.
+    ///
+    /// Dates with the same middle are ordered by their start, and then by their precision, finest first.
+    pub fn cmp_by_midpoint(&self, other: &Self) -> Ordering {
+        let key = |date: &Self| {
+            let (start, end) = date.days();
+            // Twice the middle, to stay in whole days.
+            (
+                start + end,
+                start,
+                date.precision,
+                date.approximate,
+                date.uncertain,
+            )
+        };
+        key(self).cmp(&key(other))
+    }
+
+    /// The first and last days of this date's window, in [`StandardCalendar`] days.
+    fn days(&self) -> (i128, i128) {
+        (
+            StandardCalendar::from(&self.start).days,
+            StandardCalendar::from(&self.end()).days,
+        )
+    }
+}
+
+/// A range of possible day counts, both inclusive, returned by [`ApproximateDate::days_between`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayInterval {
+    pub min: i128,
+    pub max: i128,
+}
+
+impl DayInterval {
+    /// Whether only one day count is possible.
+    pub fn is_exact(&self) -> bool {
+        self.min == self.max
+    }
+}
+
+impl From<Date> for ApproximateDate {
+    /// A [`Date`] is known up to the [day](Precision::Day).
+    fn from(date: Date) -> Self {
+        Self::new(date, Precision::Day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::year;
+
+    #[test]
+    fn windows() {
+        let window = |start, precision| {
+            let date = ApproximateDate::new(start, precision);
+            (date.start(), date.end())
+        };
+        let date = |year, month, day| Date::from_parts(year, month, day).unwrap();
+
+        assert_eq!(
+            window(date(year!(2024), Month::February, 10), Precision::Day),
+            (
+                date(year!(2024), Month::February, 10),
+                date(year!(2024), Month::February, 10)
+            )
+        );
+        assert_eq!(
+            window(date(year!(2024), Month::February, 10), Precision::Month),
+            (
+                date(year!(2024), Month::February, 1),
+                date(year!(2024), Month::February, 29)
+            )
+        );
+        assert_eq!(
+            window(date(year!(2024), Month::December, 10), Precision::Month),
+            (
+                date(year!(2024), Month::December, 1),
+                date(year!(2024), Month::December, 31)
+            )
+        );
+        assert_eq!(
+            window(date(year!(-1), Month::June, 10), Precision::Year),
+            (
+                date(year!(-1), Month::January, 1),
+                date(year!(-1), Month::December, 31)
+            )
+        );
+        assert_eq!(
+            window(Date::from_year(year!(1990)), Precision::Decade),
+            (
+                date(year!(1990), Month::January, 1),
+                date(year!(1999), Month::December, 31)
+            )
+        );
+        // The 1st century BCE ends right before the year 1.
+        assert_eq!(
+            window(Date::from_year(year!(-100)), Precision::Century),
+            (
+                date(year!(-100), Month::January, 1),
+                date(year!(-1), Month::December, 31)
+            )
+        );
+        assert_eq!(
+            window(Date::from_year(year!(1001)), Precision::Millennium),
+            (
+                date(year!(1001), Month::January, 1),
+                date(year!(2000), Month::December, 31)
+            )
+        );
+    }
+
+    #[test]
+    fn day_intervals() {
+        let year_1850 = ApproximateDate::new(Date::from_year(year!(1850)), Precision::Year);
+        let year_1852 = ApproximateDate::new(Date::from_year(year!(1852)), Precision::Year);
+        let leap_february = ApproximateDate::new(
+            Date::from_parts(year!(1852), Month::February, 1).unwrap(),
+            Precision::Month,
+        );
+        let day = ApproximateDate::from(Date::from_parts(year!(1850), Month::July, 1).unwrap());
+
+        assert_eq!(
+            ApproximateDate::days_between(&day, &day),
+            DayInterval { min: 0, max: 0 }
+        );
+        assert!(ApproximateDate::days_between(&day, &day).is_exact());
+        // Both windows overlap with themselves, so the interval goes both ways.
+        assert_eq!(
+            ApproximateDate::days_between(&year_1850, &year_1850),
+            DayInterval {
+                min: -364,
+                max: 364
+            }
+        );
+        assert_eq!(
+            ApproximateDate::days_between(&year_1850, &year_1852),
+            DayInterval {
+                min: 366,
+                max: 365 + 365 + 365
+            }
+        );
+        assert_eq!(
+            ApproximateDate::days_between(&leap_february, &year_1850),
+            DayInterval {
+                min: -(365 + 365 + 31 + 28),
+                max: -(1 + 365 + 31)
+            }
+        );
+        assert_eq!(
+            ApproximateDate::days_between(&day, &year_1850),
+            DayInterval {
+                min: -181,
+                max: 183
+            }
+        );
+    }
+
+    #[test]
+    fn window_comparisons() {
+        let year = |year| ApproximateDate::new(Date::from_year(year), Precision::Year);
+        let month = |year, month| {
+            ApproximateDate::new(Date::from_parts(year, month, 1).unwrap(), Precision::Month)
+        };
+        let day =
+            |year, month, day| ApproximateDate::from(Date::from_parts(year, month, day).unwrap());
+
+        let cases = [
+            (year(year!(1850)), year(year!(1850)), None),
+            (year(year!(1850)), month(year!(1850), Month::March), None),
+            (month(year!(1850), Month::March), year(year!(1850)), None),
+            (year(year!(1850)), year(year!(1851)), Some(Ordering::Less)),
+            (
+                year(year!(1851)),
+                year(year!(1850)),
+                Some(Ordering::Greater),
+            ),
+            (year(year!(-1)), year(year!(1)), Some(Ordering::Less)),
+            (
+                day(year!(1850), Month::December, 31),
+                year(year!(1851)),
+                Some(Ordering::Less),
+            ),
+            (
+                day(year!(1850), Month::December, 31),
+                year(year!(1850)),
+                None,
+            ),
+            (
+                day(year!(1850), Month::May, 1),
+                day(year!(1850), Month::May, 1),
+                Some(Ordering::Equal),
+            ),
+        ];
+        for (first, second, ordering) in cases {
+            assert_eq!(first.cmp_windows(&second), ordering, "{first:?} {second:?}");
+        }
+    }
+
+    #[test]
+    fn midpoint_order() {
+        let mut dates = [
+            ApproximateDate::new(Date::from_year(year!(1801)), Precision::Century),
+            ApproximateDate::from(Date::from_parts(year!(1850), Month::March, 1).unwrap()),
+            ApproximateDate::new(Date::from_year(year!(1850)), Precision::Year),
+            ApproximateDate::new(Date::from_year(year!(1810)), Precision::Decade),
+            ApproximateDate::new(Date::from_year(year!(1850)), Precision::Year).approximately(),
+        ];
+        dates.sort_by(ApproximateDate::cmp_by_midpoint);
+        assert_eq!(
+            dates.map(|date| (date.start().year(), date.precision(), date.is_approximate())),
+            [
+                (year!(1810), Precision::Decade, false),
+                (year!(1850), Precision::Day, false),
+                (year!(1850), Precision::Year, false),
+                (year!(1850), Precision::Year, true),
+                (year!(1801), Precision::Century, false),
+            ]
+        );
+    }
+}
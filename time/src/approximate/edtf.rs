@@ -0,0 +1,477 @@
+//! The [Extended Date/Time Format](https://www.loc.gov/standards/datetime/) (EDTF), standardized as ISO 8601-2,
+//! which scientific and archival data use to say how well a date is known.
+//!
+//! Level 1 of EDTF is supported, as far as an [`ApproximateDate`] can represent it:
+//!
+//! | EDTF                     | Meaning                                                 | Precision                 |
+//! |--------------------------|---------------------------------------------------------|---------------------------|
+//! | `1985-04-12`             | A day.                                                  | [`Day`](Precision::Day)   |
+//! | `1985-04`, `1985-04-XX`  | A month.                                                | [`Month`](Precision::Month) |
+//! | `1985`, `1985-XX-XX`     | A year.                                                 | [`Year`](Precision::Year) |
+//! | `198X`, `19XX`, `1XXX`   | A decade, century or millennium, from the year given.   | [`Decade`](Precision::Decade) and coarser |
+//! | `-0043`, `0000`          | Years before the common era, numbered astronomically.   |                           |
+//! | `Y170000002`             | A year with more than 4 digits.                         | [`Year`](Precision::Year) |
+//! | `1990/1999`              | An interval, if it covers exactly one window.           | The window's              |
+//!
+//! Any of them, or each end of an interval, can end with `?` for an [uncertain](ApproximateDate::uncertainly) date,
+//! `~` for an [approximate](ApproximateDate::approximately) one, or `%` for both.
+//!
+//! EDTF numbers years astronomically, so `0000` is 1 BCE and `-0043` is 44 BCE.
+//!
+//! Intervals with an open (`..`) or unknown (an empty end) end, intervals whose ends don't make up one window, and
+//! seasons (months 21 to 24) are valid EDTF, but have no [`ApproximateDate`].
+
+use std::fmt;
+
+use super::{ApproximateDate, Precision};
+use crate::calendar::Calendar;
+use crate::date::gregorian::{Date, IsoYear, Month, Year, errors::DateCreationError};
+
+/// The largest year written with its 4 digits. Years further from the year 0 are written with a `Y` prefix.
+const MAX_SHORT_YEAR: i128 = 9_999;
+
+/// Every precision, from the finest to the coarsest.
+const PRECISIONS: [Precision; 6] = [
+    Precision::Day,
+    Precision::Month,
+    Precision::Year,
+    Precision::Decade,
+    Precision::Century,
+    Precision::Millennium,
+];
+
+/// The error type for [`ApproximateDate::from_edtf`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdtfParseError {
+    /// The input stops being EDTF at byte `position`.
+    Malformed { position: usize },
+    /// The parts read don't make up a valid date, such as February 30th.
+    InvalidDate(DateCreationError),
+    /// The input from byte `position` is EDTF, but there is no [`ApproximateDate`] for it, such as an open interval,
+    /// a season or a year too large to be represented.
+    Unrepresentable { position: usize },
+}
+
+impl fmt::Display for EdtfParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { position } => write!(f, "not an EDTF date from byte {position}"),
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+            Self::Unrepresentable { position } => write!(
+                f,
+                "the EDTF date from byte {position} has no approximate date"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EdtfParseError {}
+
+impl From<DateCreationError> for EdtfParseError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
+
+impl ApproximateDate {
+    /// Writes this date in level 1 of EDTF.
+    ///
+    /// Decades, centuries and millennia starting on a multiple of their length are written with unspecified digits,
+    /// like "199X". Other windows are written as the interval of their years, like "1995/2004".
+    ///
+    /// Returns [`None`] for days and months of years before -9999 or after 9999 (astronomically), since only years
+    /// can be written with more than 4 digits.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{approximate::{ApproximateDate, Precision}, date::gregorian::{Date, Month, year}};
+    ///
+    /// let june = ApproximateDate::new(Date::from_parts(year!(1950), Month::June, 1).unwrap(), Precision::Month);
+    /// assert_eq!(june.approximately().to_edtf().as_deref(), Some("1950-06~"));
+    ///
+    /// let decade = ApproximateDate::new(Date::from_year(year!(1560)), Precision::Decade);
+    /// assert_eq!(decade.to_edtf().as_deref(), Some("156X"));
+    /// let decade = ApproximateDate::new(Date::from_year(year!(1565)), Precision::Decade);
+    /// assert_eq!(decade.uncertainly().to_edtf().as_deref(), Some("1565?/1574?"));
+    ///
+    /// // 44 BCE is the astronomical year -43.
+    /// let ides = ApproximateDate::from(Date::from_parts(year!(-44), Month::March, 15).unwrap());
+    /// assert_eq!(ides.to_edtf().as_deref(), Some("-0043-03-15"));
+    /// ```
+    pub fn to_edtf(&self) -> Option<String> {
+        let year = self.start.year().to_astronomical();
+        let short = (-MAX_SHORT_YEAR..=MAX_SHORT_YEAR).contains(&year);
+        let qualifier = match (self.uncertain, self.approximate) {
+            (true, true) => "%",
+            (true, false) => "?",
+            (false, true) => "~",
+            (false, false) => "",
+        };
+        let unspecified = match self.precision {
+            Precision::Decade => 1,
+            Precision::Century => 2,
+            Precision::Millennium => 3,
+            _ => 0,
+        };
+        let (month, day) = (u8::from(self.start.month()), self.start.day());
+
+        let text = match self.precision {
+            Precision::Day | Precision::Month if !short => return None,
+            Precision::Day => format!("{}-{month:02}-{day:02}", edtf_year(year)),
+            Precision::Month => format!("{}-{month:02}", edtf_year(year)),
+            Precision::Year => edtf_year(year),
+            _ if short && year >= 0 && year % 10_i128.pow(unspecified) == 0 => {
+                let mut text = edtf_year(year);
+                text.truncate(4 - unspecified as usize);
+                text + &"X".repeat(unspecified as usize)
+            }
+            _ => {
+                let end = self.end().year().to_astronomical();
+                format!("{}{qualifier}/{}", edtf_year(year), edtf_year(end))
+            }
+        };
+        Some(text + qualifier)
+    }
+
+    /// Reads a date written in level 1 of EDTF, as described in the [module documentation](self).
+    ///
+    /// # Errors
+    /// * [`EdtfParseError::Malformed`] if the input isn't EDTF, or uses features beyond level 1.
+    /// * [`EdtfParseError::InvalidDate`] if the month or the day doesn't exist, like "2023-02-29".
+    /// * [`EdtfParseError::Unrepresentable`] if the input is EDTF, but there is no [`ApproximateDate`] for it.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::approximate::{ApproximateDate, Precision, edtf::EdtfParseError};
+    /// use time::date::gregorian::{Date, errors::DateCreationError, year};
+    ///
+    /// let date = ApproximateDate::from_edtf("1984?").unwrap();
+    /// assert_eq!((date.start(), date.precision()), (Date::from_year(year!(1984)), Precision::Year));
+    /// assert!(date.is_uncertain() && !date.is_approximate());
+    ///
+    /// let sixties = ApproximateDate::from_edtf("196X").unwrap();
+    /// assert_eq!(ApproximateDate::from_edtf("1960/1969"), Ok(sixties));
+    /// assert_eq!(sixties.precision(), Precision::Decade);
+    ///
+    /// assert_eq!(
+    ///     ApproximateDate::from_edtf("1984-13"),
+    ///     Err(EdtfParseError::InvalidDate(DateCreationError::InvalidMonth(13)))
+    /// );
+    /// assert_eq!(ApproximateDate::from_edtf("1984/.."), Err(EdtfParseError::Unrepresentable { position: 5 }));
+    /// assert_eq!(ApproximateDate::from_edtf("[1667,1668]"), Err(EdtfParseError::Malformed { position: 0 }));
+    /// ```
+    pub fn from_edtf(input: &str) -> Result<Self, EdtfParseError> {
+        let Some((first, last)) = input.split_once('/') else {
+            return single(input, 0);
+        };
+        let slash = first.len();
+        for (end, position) in [(first, 0), (last, slash + 1)] {
+            if end.is_empty() || end == ".." {
+                return Err(EdtfParseError::Unrepresentable { position });
+            }
+        }
+        let (first, last) = (single(first, 0)?, single(last, slash + 1)?);
+        let unrepresentable = EdtfParseError::Unrepresentable { position: slash };
+        if (first.uncertain, first.approximate) != (last.uncertain, last.approximate) {
+            return Err(unrepresentable);
+        }
+        PRECISIONS
+            .into_iter()
+            .map(|precision| Self {
+                uncertain: first.uncertain,
+                approximate: first.approximate,
+                ..Self::new(first.start, precision)
+            })
+            .find(|window| window.start == first.start && window.end() == last.end())
+            .ok_or(unrepresentable)
+    }
+}
+
+/// Writes an astronomical `year` with at least 4 digits, or with a `Y` prefix if it needs more.
+fn edtf_year(year: i128) -> String {
+    if (-MAX_SHORT_YEAR..=MAX_SHORT_YEAR).contains(&year) {
+        IsoYear(year).to_string()
+    } else {
+        format!("Y{year}")
+    }
+}
+
+/// Reads a date that isn't an interval, starting at byte `offset` of the whole input.
+fn single(input: &str, offset: usize) -> Result<ApproximateDate, EdtfParseError> {
+    let malformed = |index: usize| EdtfParseError::Malformed {
+        position: offset + index,
+    };
+    let unrepresentable = |index: usize| EdtfParseError::Unrepresentable {
+        position: offset + index,
+    };
+    if let Some(index) = input.find(|c: char| !c.is_ascii()) {
+        return Err(malformed(index));
+    }
+    let (input, uncertain, approximate) = match input.as_bytes().last() {
+        Some(b'?') => (&input[..input.len() - 1], true, false),
+        Some(b'~') => (&input[..input.len() - 1], false, true),
+        Some(b'%') => (&input[..input.len() - 1], true, true),
+        _ => (input, false, false),
+    };
+    let bytes = input.as_bytes();
+    let with_qualifiers = |date: ApproximateDate| ApproximateDate {
+        uncertain,
+        approximate,
+        ..date
+    };
+    let year = |year: i128, index: usize| {
+        let supported = Date::MIN.year().to_astronomical()..=Date::MAX.year().to_astronomical();
+        supported
+            .contains(&year)
+            .then(|| Year::from_astronomical(year))
+            .ok_or(unrepresentable(index))
+    };
+
+    if let Some(digits) = input.strip_prefix('Y') {
+        let sign = usize::from(digits.starts_with('-'));
+        if let Some(index) = digits[sign..].find(|c: char| !c.is_ascii_digit()) {
+            return Err(malformed(1 + sign + index));
+        }
+        // Shorter years are written without the prefix.
+        if digits.len() - sign <= 4 {
+            return Err(malformed(1 + sign));
+        }
+        let value = digits.parse().map_err(|_| unrepresentable(0))?;
+        let date = Date::from_year(year(value, 0)?);
+        return Ok(with_qualifiers(ApproximateDate::new(date, Precision::Year)));
+    }
+
+    let sign = usize::from(bytes.first() == Some(&b'-'));
+    let year_digits = bytes.get(sign..sign + 4).ok_or(malformed(bytes.len()))?;
+    let known = year_digits
+        .iter()
+        .position(|&byte| byte == b'X')
+        .unwrap_or(4);
+    if let Some(index) = year_digits
+        .iter()
+        .position(|byte| !byte.is_ascii_digit() && *byte != b'X')
+    {
+        return Err(malformed(sign + index));
+    }
+    if let Some(index) = year_digits[known..].iter().position(|&byte| byte != b'X') {
+        return Err(malformed(sign + known + index));
+    }
+    if sign == 1 && known < 4 {
+        return Err(malformed(sign + known));
+    }
+    if known == 0 {
+        return Err(unrepresentable(0));
+    }
+    // Only ASCII digits, and at most 4 of them.
+    let mut value =
+        input[sign..sign + known].parse::<i128>().unwrap() * 10_i128.pow(4 - known as u32);
+    if sign == 1 {
+        if value == 0 {
+            return Err(malformed(0));
+        }
+        value = -value;
+    }
+
+    // The month and the day, each either known or unspecified (`XX`).
+    let mut parts: [Option<Option<u8>>; 2] = [None; 2];
+    let mut index = sign + 4;
+    for part in &mut parts {
+        if index == bytes.len() {
+            break;
+        }
+        if bytes[index] != b'-' || known < 4 {
+            return Err(malformed(index));
+        }
+        let digits = bytes
+            .get(index + 1..index + 3)
+            .ok_or(malformed(bytes.len()))?;
+        *part = Some(match digits {
+            b"XX" => None,
+            _ => match digits.iter().position(|byte| !byte.is_ascii_digit()) {
+                Some(position) => return Err(malformed(index + 1 + position)),
+                None => Some((digits[0] - b'0') * 10 + digits[1] - b'0'),
+            },
+        });
+        index += 3;
+    }
+    if index != bytes.len() {
+        return Err(malformed(index));
+    }
+
+    let month_position = sign + 5;
+    let (date, precision) = match (known, parts) {
+        (1, _) => (Date::from_year(year(value, 0)?), Precision::Millennium),
+        (2, _) => (Date::from_year(year(value, 0)?), Precision::Century),
+        (3, _) => (Date::from_year(year(value, 0)?), Precision::Decade),
+        (_, [None | Some(None), Some(Some(_))]) => return Err(malformed(month_position + 3)),
+        (_, [None | Some(None), _]) => (Date::from_year(year(value, 0)?), Precision::Year),
+        (_, [Some(Some(21..=24)), _]) => return Err(unrepresentable(month_position)),
+        (_, [Some(Some(month)), day]) => {
+            let (year, month) = (year(value, 0)?, Month::try_from(month)?);
+            match day {
+                Some(Some(day)) => (Date::from_parts(year, month, day)?, Precision::Day),
+                _ => (Date::from_parts(year, month, 1)?, Precision::Month),
+            }
+        }
+    };
+    Ok(with_qualifiers(ApproximateDate::new(date, precision)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::year;
+
+    fn date(year: Year, month: Month, day: u8) -> Date {
+        Date::from_parts(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn round_trips() {
+        let cases = [
+            (
+                ApproximateDate::from(date(year!(1985), Month::April, 12)),
+                "1985-04-12",
+            ),
+            (
+                ApproximateDate::new(date(year!(1950), Month::June, 1), Precision::Month)
+                    .approximately(),
+                "1950-06~",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(1984)), Precision::Year).uncertainly(),
+                "1984?",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(2004)), Precision::Year)
+                    .approximately()
+                    .uncertainly(),
+                "2004%",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(1560)), Precision::Decade),
+                "156X",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(1900)), Precision::Century),
+                "19XX",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(1000)), Precision::Millennium)
+                    .uncertainly(),
+                "1XXX?",
+            ),
+            // 1 BCE is the year 0, and 44 BCE is -43.
+            (
+                ApproximateDate::new(Date::from_year(year!(-1)), Precision::Year),
+                "0000",
+            ),
+            (
+                ApproximateDate::from(date(year!(-44), Month::March, 15)),
+                "-0043-03-15",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(170_000_002)), Precision::Year),
+                "Y170000002",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(-10_001)), Precision::Year),
+                "Y-10000",
+            ),
+            // Windows that aren't aligned, or are before the year 0, are intervals.
+            (
+                ApproximateDate::new(Date::from_year(year!(1565)), Precision::Decade)
+                    .approximately(),
+                "1565~/1574~",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(-100)), Precision::Century),
+                "-0099/0000",
+            ),
+            (
+                ApproximateDate::new(Date::from_year(year!(20_000)), Precision::Millennium),
+                "Y20000/Y20999",
+            ),
+        ];
+        for (date, text) in cases {
+            assert_eq!(date.to_edtf().as_deref(), Some(text), "{date:?}");
+            assert_eq!(ApproximateDate::from_edtf(text), Ok(date), "{text}");
+        }
+    }
+
+    #[test]
+    fn equivalent_forms() {
+        let cases = [
+            ("1985-04-XX", "1985-04"),
+            ("1985-XX", "1985"),
+            ("1985-XX-XX~", "1985~"),
+            ("1990/1999", "199X"),
+            ("1985/1985", "1985"),
+            ("1985-04-01/1985-04-30", "1985-04"),
+            ("1985-01/1985-12", "1985"),
+            ("1985-04-12/1985-04-12", "1985-04-12"),
+            ("1000?/1999?", "1XXX?"),
+        ];
+        for (text, canonical) in cases {
+            let date = ApproximateDate::from_edtf(text).unwrap();
+            assert_eq!(Ok(date), ApproximateDate::from_edtf(canonical), "{text}");
+            assert_eq!(date.to_edtf().as_deref(), Some(canonical), "{text}");
+        }
+
+        let far_day = ApproximateDate::from(date(year!(12_000), Month::May, 1));
+        assert_eq!(far_day.to_edtf(), None);
+    }
+
+    #[test]
+    fn errors() {
+        let malformed = |position| EdtfParseError::Malformed { position };
+        let unrepresentable = |position| EdtfParseError::Unrepresentable { position };
+        let cases = [
+            ("", malformed(0)),
+            ("198", malformed(3)),
+            ("19850", malformed(4)),
+            ("1985-4", malformed(6)),
+            ("1985-04-1", malformed(9)),
+            ("1985-04-12-01", malformed(10)),
+            ("1985/04", malformed(5 + 2)),
+            ("1985 ", malformed(4)),
+            ("1985??", malformed(4)),
+            ("?1985", malformed(0)),
+            ("1X85", malformed(2)),
+            ("198X-04", malformed(4)),
+            ("-198X", malformed(4)),
+            ("-0000", malformed(0)),
+            ("1985-0X", malformed(6)),
+            ("1985-XX-12", malformed(8)),
+            ("Y1985", malformed(1)),
+            ("Y12x45", malformed(3)),
+            ("[1667,1668]", malformed(0)),
+            ("1985é", malformed(4)),
+            (
+                "1985-13",
+                EdtfParseError::InvalidDate(DateCreationError::InvalidMonth(13)),
+            ),
+            (
+                "2023-02-29",
+                EdtfParseError::InvalidDate(DateCreationError::InvalidDay(29)),
+            ),
+            ("2001-21", unrepresentable(5)),
+            ("XXXX", unrepresentable(0)),
+            ("1985/..", unrepresentable(5)),
+            ("../1985", unrepresentable(0)),
+            ("1985/", unrepresentable(5)),
+            ("/1985", unrepresentable(0)),
+            ("1964/2008", unrepresentable(4)),
+            ("1984?/1993", unrepresentable(5)),
+            ("1990/1980", unrepresentable(4)),
+            ("Y1000000000000000000000000000000000000", unrepresentable(0)),
+            (
+                "Y9999999999999999999999999999999999999999",
+                unrepresentable(0),
+            ),
+        ];
+        for (text, error) in cases {
+            assert_eq!(ApproximateDate::from_edtf(text), Err(error), "{text}");
+        }
+    }
+}
@@ -1,3 +1,9 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::Weekday;
+use crate::date::gregorian;
+
 /// A standard reference calendar that counts the days from a reference date.
 ///
 /// Its purpose is as a "Rosetta Stone" to act as a man-in-the-middle between other [`Calendar`] conversions.
@@ -12,10 +18,180 @@ pub struct StandardCalendar {
 }
 
 impl StandardCalendar {
+    /// The earliest supported day, which is [`Date::MIN`](crate::date::gregorian::Date::MIN).
+    pub const MIN: Self = Self::new(-36_524_250_000_000_000_000_000_000_000_000_000_000);
+    /// The latest supported day, which is [`Date::MAX`](crate::date::gregorian::Date::MAX).
+    pub const MAX: Self = Self::new(36_524_249_999_999_999_999_999_999_999_999_999_999);
+
+    /// The day of the week of _day 0_, January 1st of year 1 in the proleptic Gregorian Calendar.
+    pub const REFERENCE_WEEKDAY: Weekday = Weekday::Monday;
+
     /// Creates a new [`StandardCalendar`] from the given difference since _day 0_.
-    pub fn new(days_from: i128) -> Self {
+    pub const fn new(days_from: i128) -> Self {
         Self { days: days_from }
     }
+
+    /// Which day of the week this day falls on.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{StandardCalendar, Weekday};
+    ///
+    /// assert_eq!(StandardCalendar::new(0).weekday(), Weekday::Monday);
+    /// assert_eq!(StandardCalendar::new(-1).weekday(), Weekday::Sunday);
+    /// assert_eq!(StandardCalendar::new(-7).weekday(), Weekday::Monday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        // Euclidean, so days before _day 0_ count backwards from its weekday instead of getting a negative offset.
+        let offset = self.days.rem_euclid(7) as usize;
+        Weekday::ALL[(Self::REFERENCE_WEEKDAY as usize - 1 + offset) % 7]
+    }
+
+    /// The day before this one, unless it would be before [`StandardCalendar::MIN`].
+    pub fn pred(&self) -> Option<Self> {
+        (Self::MIN < *self && *self <= Self::MAX).then(|| Self::new(self.days - 1))
+    }
+
+    /// The day after this one, unless it would be after [`StandardCalendar::MAX`].
+    pub fn succ(&self) -> Option<Self> {
+        (Self::MIN <= *self && *self < Self::MAX).then(|| Self::new(self.days + 1))
+    }
+
+    /// The same day `months` months later, or earlier if negative, in the Gregorian Calendar.
+    ///
+    /// The months are counted on the Gregorian date of this day, which is clamped to the length of the month it
+    /// lands in, as in [`Date::apply_offset`](gregorian::Date::apply_offset).
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{StandardCalendar, date::gregorian::date};
+    ///
+    /// let day = StandardCalendar::from(&date!(2024-01-31));
+    /// assert_eq!(day.add_months(1), StandardCalendar::from(&date!(2024-02-29)));
+    /// assert_eq!(day.add_months(-2), StandardCalendar::from(&date!(2023-11-30)));
+    /// ```
+    pub fn add_months(&self, months: i128) -> Self {
+        self.add_offset(gregorian::DateOffset {
+            months,
+            ..Default::default()
+        })
+    }
+
+    /// The same day `years` years later, or earlier if negative, in the Gregorian Calendar.
+    ///
+    /// Like [`StandardCalendar::add_months`] with 12 months per year, so February 29th moves to February 28th in
+    /// common years.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{StandardCalendar, date::gregorian::date};
+    ///
+    /// let day = StandardCalendar::from(&date!(2024-02-29));
+    /// assert_eq!(day.add_years(1), StandardCalendar::from(&date!(2025-02-28)));
+    /// assert_eq!(day.add_years(4), StandardCalendar::from(&date!(2028-02-29)));
+    /// ```
+    pub fn add_years(&self, years: i128) -> Self {
+        self.add_offset(gregorian::DateOffset {
+            years,
+            ..Default::default()
+        })
+    }
+
+    /// This day moved by `offset`, applied to its Gregorian date.
+    fn add_offset(&self, offset: gregorian::DateOffset) -> Self {
+        Self::from(&gregorian::Date::from(*self).apply_offset(&offset))
+    }
+
+    /// Whether `other` is at most `tolerance` days away from this day, in either direction.
+    ///
+    /// The boundary is inclusive: days exactly `tolerance` days apart are within it.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::StandardCalendar;
+    ///
+    /// assert!(StandardCalendar::new(-3).within_days(&StandardCalendar::new(2), 5));
+    /// assert!(!StandardCalendar::new(2).within_days(&StandardCalendar::new(-3), 4));
+    /// ```
+    pub fn within_days(&self, other: &Self, tolerance: u64) -> bool {
+        self.days.abs_diff(other.days) <= u128::from(tolerance)
+    }
+
+    /// This day as an ISO 8601 Gregorian date, such as "2024-02-29". Years are
+    /// [astronomical](gregorian::Year::to_astronomical) and have at least 4 digits, such as "-0418-06-14" for 419 BCE.
+    /// Days outside of [`StandardCalendar::MIN`] and [`StandardCalendar::MAX`] are written as their count, such as
+    /// "day 170141183460469231731687303715884105727".
+    ///
+    /// # Examples
+    /// ```
+    /// use time::StandardCalendar;
+    ///
+    /// assert_eq!(StandardCalendar::new(0).to_gregorian_string(), "0001-01-01");
+    /// assert_eq!(StandardCalendar::new(-152_873).to_gregorian_string(), "-0418-06-14");
+    /// ```
+    pub fn to_gregorian_string(&self) -> String {
+        match self.gregorian() {
+            Some(date) => GregorianDate { date, era: false }.to_string(),
+            None => format!("day {}", self.days),
+        }
+    }
+
+    /// The Gregorian date of this day, unless it is outside of [`StandardCalendar::MIN`] and [`StandardCalendar::MAX`].
+    fn gregorian(&self) -> Option<gregorian::Date> {
+        (Self::MIN..=Self::MAX)
+            .contains(self)
+            .then(|| gregorian::Date::from(*self))
+    }
+}
+
+impl fmt::Display for StandardCalendar {
+    /// Writes the count of days with its Gregorian date, such as "day -152873 (0419-06-14 BCE)". The alternate form
+    /// (`{:#}`) writes only the date, as [`to_gregorian_string`](StandardCalendar::to_gregorian_string) does, such
+    /// as "-0418-06-14".
+    ///
+    /// Days outside of [`StandardCalendar::MIN`] and [`StandardCalendar::MAX`] have no date, so both forms write only
+    /// the count, such as "day 170141183460469231731687303715884105727".
+    ///
+    /// # Examples
+    /// ```
+    /// use time::StandardCalendar;
+    ///
+    /// let day = StandardCalendar::new(-152_873);
+    /// assert_eq!(day.to_string(), "day -152873 (0419-06-14 BCE)");
+    /// assert_eq!(format!("{day:#}"), "-0418-06-14");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.gregorian() {
+            Some(date) if f.alternate() => GregorianDate { date, era: false }.fmt(f),
+            Some(date) => write!(
+                f,
+                "day {} ({})",
+                self.days,
+                GregorianDate { date, era: true }
+            ),
+            None => write!(f, "day {}", self.days),
+        }
+    }
+}
+
+/// Writes a Gregorian date as its year of at least 4 digits, month and day, with years before the common era either
+/// astronomical or followed by `BCE`.
+struct GregorianDate {
+    date: gregorian::Date,
+    era: bool,
+}
+
+impl fmt::Display for GregorianDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let year = self.date.year().to_astronomical();
+        let (month, day) = (u8::from(self.date.month()), self.date.day());
+        if self.era && year <= 0 {
+            // 1 BCE is the astronomical year 0.
+            write!(f, "{:04}-{month:02}-{day:02} BCE", 1 - year)
+        } else {
+            write!(f, "{}-{month:02}-{day:02}", gregorian::IsoYear(year))
+        }
+    }
 }
 
 /// Trait that provides tools for general calendar management.
@@ -74,20 +250,219 @@ pub trait Calendar: ConvertCalendar {
     ///
     /// Leap years represent added days to the year, in order to mantain sync with Earth's rotation.
     fn is_leap_year(year: Self::Year) -> bool;
+
+    /// The months of `year`, in order.
+    ///
+    /// Along with [`days_of`](Calendar::days_of), this is what a date picker lists for this calendar. Every month
+    /// listed must have at least one day that the calendar's constructors accept.
+    ///
+    /// By default, this goes through every day of `year`, after searching the supported range for its first one,
+    /// and is empty if the calendar doesn't support any of it. Calendars that know their months should override it.
+    fn months_of(year: Self::Year) -> Vec<Self::Month>
+    where
+        Self: Sized,
+        Self::Year: Ord,
+        Self::Month: PartialEq,
+    {
+        let mut months = Vec::new();
+        for date in dates_of_year::<Self>(&year) {
+            let month = date.month();
+            if months.last() != Some(&month) {
+                months.push(month);
+            }
+        }
+        months
+    }
+
+    /// The days of `month` in `year`, from the first to the last.
+    ///
+    /// Every day in the range must be accepted by the calendar's constructors, except for days skipped by a calendar
+    /// reform, which should be documented by the implementor.
+    ///
+    /// By default, this goes through the days of `year` like [`months_of`](Calendar::months_of) does. Calendars that
+    /// know the length of their months should override it.
+    ///
+    /// # Panics
+    /// By default, if the calendar doesn't support any day of `month` in `year`.
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day>
+    where
+        Self: Sized,
+        Self::Year: Ord,
+        Self::Month: PartialEq,
+    {
+        let mut dates = dates_of_year::<Self>(&year)
+            .skip_while(|date| date.month() != month)
+            .take_while(|date| date.month() == month);
+        let first = dates.next().expect("the year doesn't have the month");
+        let last = dates.last().map_or_else(|| first.day(), |last| last.day());
+        first.day()..=last
+    }
+
+    /// Which day of the week the date falls on.
+    ///
+    /// By default, this is the [`StandardCalendar::weekday`] of the date, which holds for any calendar that uses
+    /// the usual seven-day week.
+    fn weekday(&self) -> Weekday {
+        self.to_standard().weekday()
+    }
+}
+
+/// The dates of `year` in `C`, in order, starting from its first supported day.
+fn dates_of_year<C: Calendar>(year: &C::Year) -> impl Iterator<Item = C>
+where
+    C::Year: Ord,
+{
+    let date = |days| C::from_standard(StandardCalendar::new(days));
+    let (mut low, mut high) = (C::min_supported().days, C::max_supported().days);
+    // Years only go up, so this finds the first day that isn't before `year`.
+    while low < high {
+        // Rounded down, so that the search always narrows.
+        let middle = low.wrapping_add_unsigned(high.abs_diff(low) / 2);
+        if date(middle).year() < *year {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    (low..=C::max_supported().days)
+        .map(date)
+        .take_while(move |date| date.year() == *year)
+}
+
+/// The error for a day outside of the range a calendar supports, from [`ConvertCalendar::checked_from_standard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfCalendarRange {
+    /// The day that was converted.
+    pub day: StandardCalendar,
+}
+
+impl fmt::Display for OutOfCalendarRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is outside of the calendar's supported range",
+            self.day
+        )
+    }
 }
 
+impl std::error::Error for OutOfCalendarRange {}
+
+/// Conversion between calendars, through the [`StandardCalendar`].
+///
+/// This is implemented for every type that converts to and from the [`StandardCalendar`] through [`From`]. The
+/// `validate` feature checks that those conversions round-trip, so calendars that can't represent every day, such
+/// as ones counting in whole years, implement this trait directly instead.
 pub trait ConvertCalendar {
     /// Converts a date from one calendar to another.
     fn convert_to<T: ConvertCalendar>(&self) -> T {
         T::from_standard(self.to_standard())
     }
 
+    /// Converts a date from one calendar to another, reporting how faithfully `T` represents it.
+    ///
+    /// # Errors
+    /// [`OutOfCalendarRange`] if the day is outside of `T`'s [`min_supported`](ConvertCalendar::min_supported) and
+    /// [`max_supported`](ConvertCalendar::max_supported).
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{calendar::{ConvertCalendar, Fidelity}, date::{chinese, gregorian::{self, date}}};
+    ///
+    /// let converted = date!(1969-07-20).try_convert_to::<gregorian::Date>().unwrap();
+    /// assert_eq!(converted.value, date!(1969-07-20));
+    /// assert_eq!(converted.fidelity, Fidelity::Exact);
+    ///
+    /// assert!(date!(1850-01-01).try_convert_to::<chinese::Date>().is_err());
+    /// let converted = date!(2024-02-10).try_convert_to::<chinese::Date>().unwrap();
+    /// assert_eq!(format!("{:#}", converted.value), "Jiachen year, month 1, day 1");
+    /// ```
+    fn try_convert_to<T: ConvertCalendar>(
+        &self,
+    ) -> Result<ConversionResult<T>, OutOfCalendarRange> {
+        let standard = self.to_standard();
+        let value = T::checked_from_standard(standard)?;
+        Ok(ConversionResult {
+            fidelity: value.fidelity(standard),
+            value,
+        })
+    }
+
+    /// How faithfully this date represents `original`, the day it was converted from.
+    ///
+    /// By default, this compares the date's own [`StandardCalendar`] day to `original`, which can only tell
+    /// [`Fidelity::Exact`] and [`Fidelity::Rounded`] apart. Calendars that can be ambiguous should override it.
+    fn fidelity(&self, original: StandardCalendar) -> Fidelity {
+        let error_days = self.to_standard().days - original.days;
+        if error_days == 0 {
+            Fidelity::Exact
+        } else {
+            Fidelity::Rounded { error_days }
+        }
+    }
+
+    /// The earliest day this calendar can represent.
+    ///
+    /// By default, this is [`StandardCalendar::MIN`]. Calendars with a smaller range, such as ones driven by a table
+    /// of years, should override it along with [`max_supported`](ConvertCalendar::max_supported).
+    fn min_supported() -> StandardCalendar {
+        StandardCalendar::MIN
+    }
+
+    /// The latest day this calendar can represent. See [`min_supported`](ConvertCalendar::min_supported).
+    fn max_supported() -> StandardCalendar {
+        StandardCalendar::MAX
+    }
+
+    /// Converts `standard` into this calendar, unless it is outside of [`min_supported`](ConvertCalendar::min_supported)
+    /// and [`max_supported`](ConvertCalendar::max_supported).
+    ///
+    /// # Errors
+    /// [`OutOfCalendarRange`] if the calendar doesn't support `standard`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{StandardCalendar, calendar::{ConvertCalendar, OutOfCalendarRange}, date::{chinese, gregorian::date}};
+    ///
+    /// let day = StandardCalendar::from(&date!(1850-01-01));
+    /// assert_eq!(chinese::Date::checked_from_standard(day), Err(OutOfCalendarRange { day }));
+    /// assert!(chinese::Date::checked_from_standard(StandardCalendar::from(&date!(2024-02-10))).is_ok());
+    /// ```
+    fn checked_from_standard(standard: StandardCalendar) -> Result<Self, OutOfCalendarRange>
+    where
+        Self: Sized,
+    {
+        if (Self::min_supported()..=Self::max_supported()).contains(&standard) {
+            Ok(Self::from_standard(standard))
+        } else {
+            Err(OutOfCalendarRange { day: standard })
+        }
+    }
+
     /// Convert from the date in the current calendar to the [`StandardCalendar`] (days passed since _day 0_).
     fn to_standard(&self) -> StandardCalendar;
     /// Convert from a [`StandardCalendar`] to `Self`.
     fn from_standard(standard: StandardCalendar) -> Self;
 }
 
+/// A date converted by [`ConvertCalendar::try_convert_to`], with how faithful the conversion was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionResult<T> {
+    pub value: T,
+    pub fidelity: Fidelity,
+}
+
+/// How faithfully a converted date represents the day it was converted from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fidelity {
+    /// The converted date is the same day.
+    Exact,
+    /// The converted date is another day, `error_days` after the original one, or before it if negative.
+    Rounded { error_days: i128 },
+    /// The converted date could stand for more than one day, such as in a month that may or may not be a leap month.
+    Ambiguous,
+}
+
 impl<T> ConvertCalendar for T
 where
     StandardCalendar: Into<T>,
@@ -98,6 +473,388 @@ where
     }
 
     fn from_standard(standard: StandardCalendar) -> Self {
-        standard.into()
+        let date: Self = standard.into();
+        #[cfg(feature = "validate")]
+        debug_assert_eq!((&date).into(), standard, "converting back");
+        date
+    }
+}
+
+/// The identity of a day, whatever calendar it was written in.
+///
+/// Dates of different calendars can't be compared directly, but the same day has the same key in all of them, so it
+/// can be used to sort, deduplicate or hash dates. Only the day is part of the key: anything finer, such as the time
+/// of a [`UtcInstant`](crate::instant::UtcInstant), is not.
+///
+/// # Examples
+/// ```
+/// use time::{calendar::TimeKey, date::{gregorian::{self, date}, historical::{self, Britain}}};
+///
+/// // Britain still used the Julian Calendar when Catholic countries switched.
+/// let julian = historical::Date::<Britain>::from_parts(gregorian::year!(1582), gregorian::Month::October, 4).unwrap();
+/// assert_eq!(TimeKey::of(&julian), TimeKey::of(&date!(1582-10-14)));
+/// assert!(TimeKey::of(&julian) < TimeKey::of(&date!(1582-10-15)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeKey(i128);
+
+impl TimeKey {
+    /// The key of the day `date` falls on.
+    pub fn of(date: &impl ConvertCalendar) -> Self {
+        date.to_standard().into()
+    }
+
+    /// The day of this key.
+    pub fn standard(self) -> StandardCalendar {
+        StandardCalendar::new(self.0)
+    }
+}
+
+impl From<StandardCalendar> for TimeKey {
+    fn from(standard: StandardCalendar) -> Self {
+        Self(standard.days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{self, date};
+
+    /// A calendar counting whole weeks, which rounds every day down to its Monday.
+    #[derive(Debug, PartialEq)]
+    struct Weeks(i128);
+
+    impl ConvertCalendar for Weeks {
+        fn to_standard(&self) -> StandardCalendar {
+            StandardCalendar::new(self.0 * 7)
+        }
+
+        fn from_standard(standard: StandardCalendar) -> Self {
+            Self(standard.days.div_euclid(7))
+        }
+    }
+
+    /// The Gregorian Calendar, with the default `months_of` and `days_of`.
+    #[derive(Debug)]
+    struct Searched(gregorian::Date);
+
+    impl From<StandardCalendar> for Searched {
+        fn from(standard: StandardCalendar) -> Self {
+            Self(standard.into())
+        }
+    }
+
+    impl From<&Searched> for StandardCalendar {
+        fn from(date: &Searched) -> Self {
+            (&date.0).into()
+        }
+    }
+
+    impl Calendar for Searched {
+        type Month = gregorian::Month;
+        type Day = u8;
+        type Year = gregorian::Year;
+
+        fn day(&self) -> Self::Day {
+            self.0.day()
+        }
+        fn month(&self) -> Self::Month {
+            self.0.month()
+        }
+        fn year(&self) -> Self::Year {
+            self.0.year()
+        }
+        fn reference_date() -> Self {
+            Self(gregorian::Date::reference_date())
+        }
+        fn add_days(&mut self, days: i128) {
+            self.0.add_days(days);
+        }
+        fn as_days(&self) -> i128 {
+            self.0.as_days()
+        }
+        fn days_between(first: &Self, second: &Self) -> i128 {
+            gregorian::Date::days_between(&first.0, &second.0)
+        }
+        fn is_leap_year(year: Self::Year) -> bool {
+            year.is_leap_year()
+        }
+    }
+
+    #[test]
+    fn default_months_and_days() {
+        let years = [
+            gregorian::year!(2024),
+            gregorian::year!(2023),
+            gregorian::year!(1900),
+            gregorian::year!(1),
+            gregorian::year!(-1),
+            gregorian::Date::MIN.year(),
+            gregorian::Date::MAX.year(),
+        ];
+        for year in years {
+            let months = Searched::months_of(year);
+            assert_eq!(months, gregorian::Date::months_of(year), "{year}");
+            for month in months {
+                assert_eq!(
+                    Searched::days_of(year, month),
+                    gregorian::Date::days_of(year, month),
+                    "{year} {month:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn conversion_fidelity() {
+        let exact = date!(2024 - 02 - 29)
+            .try_convert_to::<gregorian::Date>()
+            .unwrap();
+        assert_eq!(exact.fidelity, Fidelity::Exact);
+
+        // 2024-02-29 is a Thursday.
+        let rounded = date!(2024 - 02 - 29).try_convert_to::<Weeks>().unwrap();
+        assert_eq!(rounded.fidelity, Fidelity::Rounded { error_days: -3 });
+        assert_eq!(
+            rounded.value.convert_to::<gregorian::Date>(),
+            date!(2024 - 02 - 26)
+        );
+
+        let monday = date!(-1 - 12 - 25).try_convert_to::<Weeks>().unwrap();
+        assert_eq!(monday.value, Weeks(-1));
+        assert_eq!(monday.fidelity, Fidelity::Exact);
+        assert_eq!(
+            monday
+                .value
+                .try_convert_to::<gregorian::Date>()
+                .map(|converted| converted.fidelity),
+            Ok(Fidelity::Exact)
+        );
+    }
+
+    #[test]
+    fn weekdays() {
+        let cases = [
+            (0, Weekday::Monday),
+            (6, Weekday::Sunday),
+            (7, Weekday::Monday),
+            // `-1 % 7` would be -1 here, rather than the 6 days after a Monday.
+            (-1, Weekday::Sunday),
+            (-6, Weekday::Tuesday),
+            (-7, Weekday::Monday),
+            (-8, Weekday::Sunday),
+            (StandardCalendar::MIN.days, Weekday::Monday),
+            (i128::MIN, Weekday::Saturday),
+            (i128::MAX, Weekday::Tuesday),
+        ];
+        for (days, weekday) in cases {
+            assert_eq!(StandardCalendar::new(days).weekday(), weekday, "{days}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        // (days, with the count, alternate, to_gregorian_string)
+        let cases = [
+            (0, "day 0 (0001-01-01)", "0001-01-01", "0001-01-01"),
+            (
+                738_944,
+                "day 738944 (2024-02-29)",
+                "2024-02-29",
+                "2024-02-29",
+            ),
+            (-1, "day -1 (0001-12-31 BCE)", "0000-12-31", "0000-12-31"),
+            (
+                -366,
+                "day -366 (0001-01-01 BCE)",
+                "0000-01-01",
+                "0000-01-01",
+            ),
+            (
+                -367,
+                "day -367 (0002-12-31 BCE)",
+                "-0001-12-31",
+                "-0001-12-31",
+            ),
+            (
+                -152_873,
+                "day -152873 (0419-06-14 BCE)",
+                "-0418-06-14",
+                "-0418-06-14",
+            ),
+        ];
+        for (days, text, alternate, gregorian) in cases {
+            let standard = StandardCalendar::new(days);
+            assert_eq!(standard.to_string(), text);
+            assert_eq!(format!("{standard:#}"), alternate);
+            assert_eq!(standard.to_gregorian_string(), gregorian);
+        }
+
+        let max = StandardCalendar::MAX.to_string();
+        assert!(
+            max.ends_with(&format!("({}-12-31)", gregorian::Date::MAX.year())),
+            "{max}"
+        );
+        for days in [i128::MIN, StandardCalendar::MAX.days + 1] {
+            let standard = StandardCalendar::new(days);
+            assert_eq!(standard.to_string(), format!("day {days}"));
+            assert_eq!(format!("{standard:#}"), format!("day {days}"));
+            assert_eq!(standard.to_gregorian_string(), format!("day {days}"));
+        }
+    }
+
+    #[test]
+    fn calendar_steps() {
+        let day = |date| StandardCalendar::from(&date);
+        // (day, months, day after the months)
+        let months = [
+            (date!(2024 - 01 - 31), 1, date!(2024 - 02 - 29)),
+            (date!(2023 - 01 - 31), 1, date!(2023 - 02 - 28)),
+            (date!(2024 - 01 - 31), 2, date!(2024 - 03 - 31)),
+            (date!(2024 - 03 - 31), -1, date!(2024 - 02 - 29)),
+            (date!(2024 - 05 - 15), 0, date!(2024 - 05 - 15)),
+            (date!(-1 - 12 - 15), 1, date!(1 - 01 - 15)),
+            (date!(1 - 01 - 31), -11, date!(-1 - 02 - 29)),
+        ];
+        for (start, months, end) in months {
+            assert_eq!(
+                day(start).add_months(months),
+                day(end),
+                "{start:?} {months}"
+            );
+        }
+        // (day, years, day after the years)
+        let years = [
+            (date!(2024 - 02 - 29), 1, date!(2025 - 02 - 28)),
+            (date!(2024 - 02 - 29), -4, date!(2020 - 02 - 29)),
+            (date!(2024 - 02 - 29), 100, date!(2124 - 02 - 29)),
+            (date!(2000 - 02 - 29), 100, date!(2100 - 02 - 28)),
+            // There is no year 0.
+            (date!(-1 - 07 - 04), 1, date!(1 - 07 - 04)),
+            (date!(1 - 02 - 28), -1, date!(-1 - 02 - 28)),
+        ];
+        for (start, years, end) in years {
+            assert_eq!(day(start).add_years(years), day(end), "{start:?} {years}");
+            assert_eq!(
+                day(start).add_years(years),
+                day(start).add_months(years * 12)
+            );
+        }
+    }
+
+    #[test]
+    fn supported_ranges() {
+        use crate::date::{chinese, coptic, historical, offset, ordinal, shifted};
+
+        fn check<T: Calendar + std::fmt::Debug>(min: StandardCalendar, max: StandardCalendar) {
+            let name = std::any::type_name::<T>();
+            assert_eq!(
+                (T::min_supported(), T::max_supported()),
+                (min, max),
+                "{name}"
+            );
+            for day in [min, max] {
+                let date = T::checked_from_standard(day);
+                assert_eq!(date.map(|date| date.to_standard()), Ok(day), "{name}");
+            }
+            for day in [
+                StandardCalendar::new(min.days - 1),
+                StandardCalendar::new(max.days + 1),
+                StandardCalendar::new(i128::MIN),
+                StandardCalendar::new(i128::MAX),
+            ] {
+                assert_eq!(
+                    T::checked_from_standard(day).unwrap_err(),
+                    OutOfCalendarRange { day },
+                    "{name}"
+                );
+            }
+        }
+
+        let (min, max) = (StandardCalendar::MIN, StandardCalendar::MAX);
+        check::<gregorian::Date>(min, max);
+        check::<ordinal::Date>(min, max);
+        check::<coptic::Date>(min, max);
+        check::<historical::Date<historical::Britain>>(min, max);
+        check::<offset::BuddhistDate>(min, max);
+        check::<offset::HoloceneDate>(min, max);
+        check::<shifted::ShiftedYearCalendar<shifted::AprilFiscal>>(min, max);
+        check::<chinese::Date>(
+            StandardCalendar::from(&date!(1900 - 01 - 31)),
+            StandardCalendar::from(&date!(2101 - 01 - 28)),
+        );
+
+        let converted = date!(1850 - 01 - 01).try_convert_to::<chinese::Date>();
+        assert_eq!(
+            converted,
+            Err(OutOfCalendarRange {
+                day: StandardCalendar::from(&date!(1850 - 01 - 01))
+            })
+        );
+        let converted = date!(1900 - 01 - 31).try_convert_to::<chinese::Date>();
+        assert_eq!(
+            converted.map(|converted| converted.value),
+            Ok(chinese::Date::MIN)
+        );
+    }
+
+    #[test]
+    fn tolerances() {
+        // (first, second, tolerance, within)
+        let cases = [
+            (0, 0, 0, true),
+            (0, 1, 0, false),
+            (5, 10, 5, true),
+            (10, 5, 5, true),
+            (10, 5, 4, false),
+            (-3, 3, 6, true),
+            (-3, 3, 5, false),
+            (i128::MIN, i128::MAX, u64::MAX, false),
+            (i128::MAX, i128::MAX - i128::from(u64::MAX), u64::MAX, true),
+            (i128::MIN, i128::MIN + i128::from(u64::MAX), u64::MAX, true),
+        ];
+        for (first, second, tolerance, within) in cases {
+            let (first, second) = (StandardCalendar::new(first), StandardCalendar::new(second));
+            assert_eq!(
+                first.within_days(&second, tolerance),
+                within,
+                "{first:?} {second:?} {tolerance}"
+            );
+        }
+    }
+
+    #[test]
+    fn time_keys() {
+        use crate::date::{coptic, historical, offset::BuddhistDate, ordinal};
+        use std::collections::HashSet;
+
+        let gregorian = date!(1582 - 10 - 14);
+        let julian = historical::Date::<historical::Britain>::from_parts(
+            gregorian::year!(1582),
+            gregorian::Month::October,
+            4,
+        )
+        .unwrap();
+        let keys = [
+            TimeKey::of(&gregorian),
+            TimeKey::of(&julian),
+            TimeKey::of(&gregorian.convert_to::<historical::Date<historical::Catholic>>()),
+            TimeKey::of(&gregorian.convert_to::<coptic::Date>()),
+            TimeKey::of(&ordinal::Date::from(gregorian)),
+            TimeKey::of(&BuddhistDate::from(gregorian)),
+        ];
+        let unique: HashSet<_> = keys.iter().collect();
+        assert_eq!(unique.len(), 1);
+        assert_eq!(keys[0].standard(), StandardCalendar::from(&gregorian));
+
+        // Keys order like the days they stand for.
+        let mut keys =
+            [date!(1582 - 10 - 15), date!(-1 - 12 - 31), gregorian].map(|date| TimeKey::of(&date));
+        keys.sort();
+        assert_eq!(
+            keys.map(|key| gregorian::Date::from(key.standard())),
+            [date!(-1 - 12 - 31), gregorian, date!(1582 - 10 - 15)]
+        );
     }
 }
@@ -0,0 +1,643 @@
+//! The [Chinese Calendar](https://en.wikipedia.org/wiki/Chinese_calendar), a lunisolar calendar.
+//!
+//! Its months follow the Moon and have 29 or 30 days. Twelve of them fall short of a solar year, so some years
+//! repeat one of their months as a leap month, which keeps the New Year between late January and mid-February.
+//!
+//! Which months are long and which are repeated depends on astronomical observations, so instead of computing them,
+//! this module uses a precomputed table of the years from 1900 to 2100. Dates outside of it can't be represented.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::gregorian::Year;
+use crate::StandardCalendar;
+use crate::calendar::{Calendar, ConvertCalendar};
+
+/// The first year in the table.
+const FIRST_YEAR: i128 = 1900;
+/// How many years are in the table.
+const YEAR_COUNT: usize = 201;
+/// The [`StandardCalendar`] day of the first New Year in the table, January 31st of 1900.
+const FIRST_NEW_YEAR: i128 = 693_625;
+
+/// The months of each year in the table, from 1900.
+///
+/// Bits 15 to 4 are set for the months, from the 1st to the 12th, that have 30 days rather than 29. Bits 3 to 0 are
+/// the month the leap month comes after, or 0 for a year without one, and bit 16 is set if the leap month has 30
+/// days.
+const YEARS: [u32; YEAR_COUNT] = [
+    // 1900
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2,
+    // 1910
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    // 1920
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    // 1930
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    // 1940
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    // 1950
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    // 1960
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    // 1970
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    // 1980
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    // 1990
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    // 2000
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    // 2010
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    // 2020
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    // 2030
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    // 2040
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    // 2050
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0,
+    // 2060
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    // 2070
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    // 2080
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    // 2090
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    // 2100
+    0x0d520,
+];
+
+/// The [`StandardCalendar`] day of each New Year in the table, and of the one right after it.
+const NEW_YEARS: [i128; YEAR_COUNT + 1] = {
+    let mut new_years = [FIRST_NEW_YEAR; YEAR_COUNT + 1];
+    let mut year = 0;
+    while year < YEAR_COUNT {
+        let months = YEARS[year];
+        let mut length = 0;
+        let mut month = 1;
+        while month <= 12 {
+            length += regular_month_length(months, month) as i128;
+            month += 1;
+        }
+        if leap_month(months) != 0 {
+            length += leap_month_length(months) as i128;
+        }
+        new_years[year + 1] = new_years[year] + length;
+        year += 1;
+    }
+    new_years
+};
+
+/// The month the leap month of `months` comes after, or 0 if there is none.
+const fn leap_month(months: u32) -> u8 {
+    (months & 0xf) as u8
+}
+
+/// How many days the regular `month` of `months` has.
+const fn regular_month_length(months: u32, month: u8) -> u8 {
+    if months & (0x10000 >> month) != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+/// How many days the leap month of `months` has, if it has one.
+const fn leap_month_length(months: u32) -> u8 {
+    if months & 0x10000 != 0 { 30 } else { 29 }
+}
+
+/// Where `year` is in the table, if it is in it.
+fn table_index(year: Year) -> Option<usize> {
+    usize::try_from(year.to_astronomical() - FIRST_YEAR)
+        .ok()
+        .filter(|index| *index < YEAR_COUNT)
+}
+
+/// The months of the year at `index` in the table, in order, with how many days each has.
+fn months_in(index: usize) -> impl Iterator<Item = (Month, u8)> {
+    let months = YEARS[index];
+    (1..=12).flat_map(move |number| {
+        let regular = (Month::new(number), regular_month_length(months, number));
+        let leap = (leap_month(months) == number)
+            .then(|| (Month::leap(number), leap_month_length(months)));
+        std::iter::once(regular).chain(leap)
+    })
+}
+
+/// A month of the Chinese Calendar: its number, and whether it is the leap month repeating that number.
+///
+/// Leap months come right after the regular month with the same number, and are ordered that way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Month {
+    /// From 1 to 12.
+    pub number: u8,
+    /// Whether this is the leap month after the regular month `number`.
+    pub leap: bool,
+}
+
+impl Month {
+    /// The regular month numbered `number`.
+    pub const fn new(number: u8) -> Self {
+        Self {
+            number,
+            leap: false,
+        }
+    }
+
+    /// The leap month after the regular month numbered `number`.
+    pub const fn leap(number: u8) -> Self {
+        Self { number, leap: true }
+    }
+}
+
+/// The error type for creating a Chinese [`Date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChineseDateError {
+    /// The date is outside of the years in the table, from 1900 to 2100.
+    OutOfRange,
+    /// The year doesn't have this month, such as a leap month in a year that doesn't repeat it.
+    InvalidMonth(Month),
+    /// The month doesn't have this day.
+    InvalidDay(u8),
+}
+
+impl fmt::Display for ChineseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange => f.write_str(
+                "the date is outside of the Chinese calendar's table of years, from 1900 to 2100",
+            ),
+            Self::InvalidMonth(Month { number, leap: true }) => {
+                write!(f, "the year has no leap month {number}")
+            }
+            Self::InvalidMonth(Month {
+                number,
+                leap: false,
+            }) => {
+                write!(f, "the year has no month {number}")
+            }
+            Self::InvalidDay(day) => write!(f, "the month has no day {day}"),
+        }
+    }
+}
+
+impl std::error::Error for ChineseDateError {}
+
+/// A year of the [sexagenary cycle](https://en.wikipedia.org/wiki/Sexagenary_cycle), which names years by pairing
+/// one of ten heavenly stems with one of twelve earthly branches, repeating every 60 years.
+///
+/// # Examples
+/// ```
+/// use time::date::{chinese::Sexagenary, gregorian::year};
+///
+/// assert_eq!(Sexagenary::of(year!(2024)).to_string(), "甲辰");
+/// assert_eq!(format!("{:#}", Sexagenary::of(year!(2024))), "Jiachen");
+/// assert_eq!(Sexagenary::of(year!(1984)).number(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sexagenary(u8);
+
+impl Sexagenary {
+    const STEMS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+    const ROMANIZED_STEMS: [&str; 10] = [
+        "Jia", "Yi", "Bing", "Ding", "Wu", "Ji", "Geng", "Xin", "Ren", "Gui",
+    ];
+    const BRANCHES: [&str; 12] = [
+        "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+    ];
+    const ROMANIZED_BRANCHES: [&str; 12] = [
+        "zi", "chou", "yin", "mao", "chen", "si", "wu", "wei", "shen", "you", "xu", "hai",
+    ];
+
+    /// The name of the Chinese year starting in `year`.
+    pub fn of(year: Year) -> Self {
+        // 4 CE started a cycle, and the cycle doesn't skip year 0.
+        Self((year.to_astronomical() - 4).rem_euclid(60) as u8)
+    }
+
+    /// Where this year is in the cycle, from 1 for 甲子 (Jiazi) to 60 for 癸亥 (Guihai).
+    pub fn number(self) -> u8 {
+        self.0 + 1
+    }
+
+    /// The heavenly stem, such as "甲".
+    pub fn stem(self) -> &'static str {
+        Self::STEMS[usize::from(self.0 % 10)]
+    }
+
+    /// The earthly branch, such as "辰".
+    pub fn branch(self) -> &'static str {
+        Self::BRANCHES[usize::from(self.0 % 12)]
+    }
+}
+
+impl fmt::Display for Sexagenary {
+    /// Writes the stem and the branch, such as "甲辰". The alternate form (`{:#}`) writes them in
+    /// [pinyin](https://en.wikipedia.org/wiki/Pinyin) without tones instead, such as "Jiachen".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let (stem, branch) = (usize::from(self.0 % 10), usize::from(self.0 % 12));
+            write!(
+                f,
+                "{}{}",
+                Self::ROMANIZED_STEMS[stem],
+                Self::ROMANIZED_BRANCHES[branch]
+            )
+        } else {
+            write!(f, "{}{}", self.stem(), self.branch())
+        }
+    }
+}
+
+/// A date in the Chinese Calendar, from the New Year of 1900 to the end of the Chinese year 2100.
+///
+/// Years are numbered by the Gregorian year they start in, and named by their [`Sexagenary`] year.
+///
+/// # Examples
+/// ```
+/// use time::{calendar::ConvertCalendar, date::{chinese::{Date, Month}, gregorian::{self, year}}};
+///
+/// // The Mid-Autumn Festival, on the 15th of the 8th month.
+/// let mid_autumn = Date::from_parts(year!(2024), Month::new(8), 15).unwrap();
+/// assert_eq!(mid_autumn.convert_to::<gregorian::Date>(), gregorian::date!(2024-09-17));
+/// assert_eq!(mid_autumn.to_string(), "甲辰年 八月 十五");
+///
+/// let leap_month = Date::try_from(gregorian::date!(2023-04-01)).unwrap();
+/// assert_eq!(leap_month.to_string(), "癸卯年 闰二月 十一");
+/// assert_eq!(format!("{leap_month:#}"), "Guimao year, leap month 2, day 11");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: Year,
+    month: Month,
+    day: u8,
+}
+
+impl Date {
+    /// The earliest supported date, the New Year of 1900.
+    pub const MIN: Self = Self {
+        year: crate::date::gregorian::year!(1900),
+        month: Month::new(1),
+        day: 1,
+    };
+    /// The latest supported date, the last day of the Chinese year 2100.
+    pub const MAX: Self = Self {
+        year: crate::date::gregorian::year!(2100),
+        month: Month::new(12),
+        day: 29,
+    };
+
+    /// Creates a date from the day, month and year.
+    ///
+    /// # Errors
+    /// * [`ChineseDateError::OutOfRange`] if `year` is not from 1900 to 2100.
+    /// * [`ChineseDateError::InvalidMonth`] if `year` doesn't have `month`.
+    /// * [`ChineseDateError::InvalidDay`] if `month` doesn't have `day`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::{chinese::{ChineseDateError, Date, Month}, gregorian::year};
+    ///
+    /// assert!(Date::from_parts(year!(2023), Month::leap(2), 30).is_err());
+    /// assert!(Date::from_parts(year!(2023), Month::leap(2), 29).is_ok());
+    /// assert_eq!(
+    ///     Date::from_parts(year!(2024), Month::leap(2), 1),
+    ///     Err(ChineseDateError::InvalidMonth(Month::leap(2)))
+    /// );
+    /// assert_eq!(Date::from_parts(year!(1899), Month::new(1), 1), Err(ChineseDateError::OutOfRange));
+    /// ```
+    pub fn from_parts(year: Year, month: Month, day: u8) -> Result<Self, ChineseDateError> {
+        let index = table_index(year).ok_or(ChineseDateError::OutOfRange)?;
+        let (_, length) = months_in(index)
+            .find(|(other, _)| *other == month)
+            .ok_or(ChineseDateError::InvalidMonth(month))?;
+        if day < 1 || day > length {
+            return Err(ChineseDateError::InvalidDay(day));
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// The name of this date's year in the sexagenary cycle.
+    pub fn sexagenary_year(&self) -> Sexagenary {
+        Sexagenary::of(self.year)
+    }
+}
+
+impl TryFrom<StandardCalendar> for Date {
+    type Error = ChineseDateError;
+
+    /// # Errors
+    /// [`ChineseDateError::OutOfRange`] for days before [`Date::MIN`] or after [`Date::MAX`].
+    fn try_from(standard: StandardCalendar) -> Result<Self, Self::Error> {
+        let days = standard.days;
+        if !(NEW_YEARS[0]..NEW_YEARS[YEAR_COUNT]).contains(&days) {
+            return Err(ChineseDateError::OutOfRange);
+        }
+        // At least 1, since the day is on or after the first New Year.
+        let index = NEW_YEARS.partition_point(|new_year| *new_year <= days) - 1;
+        let mut day_of_year = days - NEW_YEARS[index];
+        for (month, length) in months_in(index) {
+            if day_of_year < i128::from(length) {
+                return Ok(Self {
+                    year: Year::from_astronomical(FIRST_YEAR + index as i128),
+                    month,
+                    day: day_of_year as u8 + 1,
+                });
+            }
+            day_of_year -= i128::from(length);
+        }
+        unreachable!("the months of a year add up to its length")
+    }
+}
+
+impl TryFrom<super::gregorian::Date> for Date {
+    type Error = ChineseDateError;
+
+    fn try_from(date: super::gregorian::Date) -> Result<Self, Self::Error> {
+        Self::try_from(StandardCalendar::from(&date))
+    }
+}
+
+impl From<&Date> for StandardCalendar {
+    fn from(date: &Date) -> Self {
+        // In the table, as checked when the date was created.
+        let index = table_index(date.year).unwrap();
+        let before: i128 = months_in(index)
+            .take_while(|(month, _)| *month != date.month)
+            .map(|(_, length)| i128::from(length))
+            .sum();
+        StandardCalendar::new(NEW_YEARS[index] + before + i128::from(date.day) - 1)
+    }
+}
+
+/// Implemented directly, rather than through [`From<StandardCalendar>`], since not every day is in the table.
+impl ConvertCalendar for Date {
+    fn to_standard(&self) -> StandardCalendar {
+        self.into()
+    }
+
+    /// # Panics
+    /// For days before [`Date::MIN`] or after [`Date::MAX`]. Use [`Date::try_from`] or
+    /// [`checked_from_standard`](ConvertCalendar::checked_from_standard) for days that may be outside.
+    fn from_standard(standard: StandardCalendar) -> Self {
+        Self::try_from(standard).expect("day outside of the Chinese Calendar table")
+    }
+
+    /// The New Year of 1900, which is [`Date::MIN`].
+    fn min_supported() -> StandardCalendar {
+        Self::MIN.to_standard()
+    }
+
+    /// The last day of the year 2100, which is [`Date::MAX`].
+    fn max_supported() -> StandardCalendar {
+        Self::MAX.to_standard()
+    }
+}
+
+impl Calendar for Date {
+    type Month = Month;
+    type Day = u8;
+    type Year = Year;
+
+    fn day(&self) -> Self::Day {
+        self.day
+    }
+
+    fn month(&self) -> Self::Month {
+        self.month
+    }
+
+    fn year(&self) -> Self::Year {
+        self.year
+    }
+
+    /// The New Year of 1900, which is [`Date::MIN`].
+    fn reference_date() -> Self {
+        Self::MIN
+    }
+
+    /// # Panics
+    /// If the date moves outside of [`Date::MIN`] to [`Date::MAX`].
+    fn add_days(&mut self, days: i128) {
+        *self = Self::from_standard(StandardCalendar::new(self.to_standard().days + days));
+    }
+
+    fn as_days(&self) -> i128 {
+        self.to_standard().days - FIRST_NEW_YEAR
+    }
+
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        (second.as_days() - first.as_days()).abs()
+    }
+
+    /// Whether the year has a leap month. Years outside of the table don't.
+    fn is_leap_year(year: Self::Year) -> bool {
+        table_index(year).is_some_and(|index| leap_month(YEARS[index]) != 0)
+    }
+
+    /// The months of `year`, with its leap month if it has one. Years outside of the table have none.
+    fn months_of(year: Self::Year) -> Vec<Self::Month> {
+        table_index(year).map_or(Vec::new(), |index| {
+            months_in(index).map(|(month, _)| month).collect()
+        })
+    }
+
+    /// The days of `month` in `year`, which is empty if the year doesn't have the month.
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day> {
+        let length = table_index(year)
+            .and_then(|index| months_in(index).find(|(other, _)| *other == month))
+            .map_or(0, |(_, length)| length);
+        1..=length
+    }
+}
+
+impl fmt::Display for Date {
+    /// Writes the date in Chinese, such as "甲辰年 闰二月 初三". The alternate form (`{:#}`) writes it romanized
+    /// instead, such as "Jiachen year, leap month 2, day 3".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MONTHS: [&str; 12] = [
+            "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二",
+        ];
+        const DIGITS: [&str; 10] = ["", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+        let year = self.sexagenary_year();
+        if f.alternate() {
+            let leap = if self.month.leap { "leap " } else { "" };
+            return write!(
+                f,
+                "{year:#} year, {leap}month {}, day {}",
+                self.month.number, self.day
+            );
+        }
+        let leap = if self.month.leap { "闰" } else { "" };
+        write!(
+            f,
+            "{year}年 {leap}{}月 ",
+            MONTHS[usize::from(self.month.number - 1)]
+        )?;
+        let (tens, ones) = (self.day / 10, usize::from(self.day % 10));
+        match (tens, ones) {
+            (0, _) => write!(f, "初{}", DIGITS[ones]),
+            (1, 0) => write!(f, "初十"),
+            (1, _) => write!(f, "十{}", DIGITS[ones]),
+            (2, 0) => write!(f, "二十"),
+            (2, _) => write!(f, "廿{}", DIGITS[ones]),
+            _ => write!(f, "三十"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{self, date, year};
+
+    #[test]
+    fn new_years() {
+        // Published dates of the Chinese New Year.
+        let cases = [
+            (year!(1900), date!(1900 - 01 - 31)),
+            (year!(1912), date!(1912 - 02 - 18)),
+            (year!(1949), date!(1949 - 01 - 29)),
+            (year!(1950), date!(1950 - 02 - 17)),
+            (year!(1970), date!(1970 - 02 - 06)),
+            (year!(1976), date!(1976 - 01 - 31)),
+            (year!(1985), date!(1985 - 02 - 20)),
+            (year!(1990), date!(1990 - 01 - 27)),
+            (year!(2000), date!(2000 - 02 - 05)),
+            (year!(2001), date!(2001 - 01 - 24)),
+            (year!(2008), date!(2008 - 02 - 07)),
+            (year!(2012), date!(2012 - 01 - 23)),
+            (year!(2016), date!(2016 - 02 - 08)),
+            (year!(2019), date!(2019 - 02 - 05)),
+            (year!(2020), date!(2020 - 01 - 25)),
+            (year!(2021), date!(2021 - 02 - 12)),
+            (year!(2022), date!(2022 - 02 - 01)),
+            (year!(2023), date!(2023 - 01 - 22)),
+            (year!(2024), date!(2024 - 02 - 10)),
+            (year!(2025), date!(2025 - 01 - 29)),
+            (year!(2026), date!(2026 - 02 - 17)),
+        ];
+        for (year, gregorian) in cases {
+            let new_year = Date::from_parts(year, Month::new(1), 1).unwrap();
+            assert_eq!(
+                new_year.convert_to::<gregorian::Date>(),
+                gregorian,
+                "{year}"
+            );
+            assert_eq!(Date::try_from(gregorian), Ok(new_year));
+            // The day before is the last of the previous year.
+            if year == year!(1900) {
+                continue;
+            }
+            let eve = Date::try_from(gregorian - 1).unwrap();
+            assert_eq!(
+                eve.year(),
+                Year::from_astronomical(year.to_astronomical() - 1)
+            );
+            assert_eq!(eve.month(), Month::new(12));
+        }
+    }
+
+    #[test]
+    fn leap_months() {
+        // (first day of the leap month, its number)
+        let cases = [
+            (date!(2017 - 07 - 23), 6),
+            (date!(2020 - 05 - 23), 4),
+            (date!(2023 - 03 - 22), 2),
+        ];
+        for (gregorian, number) in cases {
+            let date = Date::try_from(gregorian).unwrap();
+            assert_eq!((date.month(), date.day()), (Month::leap(number), 1));
+            assert!(Date::is_leap_year(date.year()));
+            assert_eq!(Date::months_of(date.year()).len(), 13);
+            let before = Date::try_from(gregorian - 1).unwrap();
+            assert_eq!(before.month(), Month::new(number));
+        }
+        assert!(!Date::is_leap_year(year!(2024)));
+        assert_eq!(Date::months_of(year!(2024)).len(), 12);
+        assert!(Date::days_of(year!(2024), Month::leap(2)).is_empty());
+    }
+
+    #[test]
+    fn every_day_round_trips() {
+        assert_eq!(StandardCalendar::from(&Date::MIN).days, FIRST_NEW_YEAR);
+        assert_eq!(
+            Date::MIN.convert_to::<gregorian::Date>(),
+            date!(1900 - 01 - 31)
+        );
+        assert_eq!(
+            Date::MAX.convert_to::<gregorian::Date>(),
+            date!(2101 - 01 - 28)
+        );
+
+        let mut date = Date::MIN;
+        for days in NEW_YEARS[0]..NEW_YEARS[YEAR_COUNT] {
+            let standard = StandardCalendar::new(days);
+            assert_eq!(Date::try_from(standard), Ok(date), "{days}");
+            assert_eq!(StandardCalendar::from(&date), standard);
+            assert!(Date::days_of(date.year, date.month).contains(&date.day));
+            if date != Date::MAX {
+                date.add_days(1);
+            }
+        }
+        assert_eq!(date, Date::MAX);
+
+        let out_of_range = [NEW_YEARS[0] - 1, NEW_YEARS[YEAR_COUNT], i128::MIN];
+        for days in out_of_range {
+            assert_eq!(
+                Date::try_from(StandardCalendar::new(days)),
+                Err(ChineseDateError::OutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn formatting() {
+        let cases = [
+            (
+                date!(2024 - 02 - 12),
+                "甲辰年 正月 初三",
+                "Jiachen year, month 1, day 3",
+            ),
+            (
+                date!(2024 - 02 - 19),
+                "甲辰年 正月 初十",
+                "Jiachen year, month 1, day 10",
+            ),
+            (
+                date!(2024 - 03 - 01),
+                "甲辰年 正月 廿一",
+                "Jiachen year, month 1, day 21",
+            ),
+            (
+                date!(2023 - 03 - 24),
+                "癸卯年 闰二月 初三",
+                "Guimao year, leap month 2, day 3",
+            ),
+            (
+                date!(2024 - 06 - 10),
+                "甲辰年 五月 初五",
+                "Jiachen year, month 5, day 5",
+            ),
+            (
+                date!(2025 - 01 - 28),
+                "甲辰年 十二月 廿九",
+                "Jiachen year, month 12, day 29",
+            ),
+        ];
+        for (gregorian, chinese, romanized) in cases {
+            let date = Date::try_from(gregorian).unwrap();
+            assert_eq!(date.to_string(), chinese, "{gregorian:?}");
+            assert_eq!(format!("{date:#}"), romanized, "{gregorian:?}");
+        }
+        assert_eq!(Sexagenary::of(year!(1984)).to_string(), "甲子");
+        assert_eq!(Sexagenary::of(year!(2043)).to_string(), "癸亥");
+        assert_eq!(Sexagenary::of(year!(-1)).number(), 57);
+    }
+}
@@ -0,0 +1,297 @@
+//! The [Coptic Calendar](https://en.wikipedia.org/wiki/Coptic_calendar), used by the Coptic Orthodox Church.
+//!
+//! It has twelve months of 30 days, followed by the short month of Nasie with 5 days, or 6 in leap years. Like the
+//! Julian Calendar, it has a leap year every fourth year, so its New Year falls on August 29th of the Julian
+//! Calendar, or the 30th before a Julian leap year. The Julian Calendar drifts from the Gregorian one, which moves
+//! the New Year from August 29th in year 284 to September 11th or 12th from 1900 to 2099.
+//!
+//! Years are counted in the Era of the Martyrs (Anno Martyrum), from the year Diocletian became emperor.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::gregorian::errors::DateCreationError;
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+
+/// The [`StandardCalendar`] day of 1 Thout of year 1, which was August 29th of 284 in both the Julian and the
+/// Gregorian Calendars.
+const EPOCH: i128 = 103_604;
+/// Every 4 years have 3 common years and a leap year.
+const DAYS_IN_4_YEARS: i128 = 1_461;
+
+/// A month of the Coptic Calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Month {
+    Thout = 1,
+    Paopi = 2,
+    Hathor = 3,
+    Koiak = 4,
+    Tobi = 5,
+    Meshir = 6,
+    Paremhat = 7,
+    Parmouti = 8,
+    Pashons = 9,
+    Paoni = 10,
+    Epip = 11,
+    Mesori = 12,
+    /// The epagomenal month, with the 5 or 6 days left after the other twelve.
+    Nasie = 13,
+}
+
+impl Month {
+    /// All months, from Thout to Nasie.
+    pub const ALL: [Month; 13] = [
+        Self::Thout,
+        Self::Paopi,
+        Self::Hathor,
+        Self::Koiak,
+        Self::Tobi,
+        Self::Meshir,
+        Self::Paremhat,
+        Self::Parmouti,
+        Self::Pashons,
+        Self::Paoni,
+        Self::Epip,
+        Self::Mesori,
+        Self::Nasie,
+    ];
+
+    /// The name of this month, as transliterated from Bohairic Coptic.
+    pub fn name(self) -> &'static str {
+        const NAMES: [&str; 13] = [
+            "Thout", "Paopi", "Hathor", "Koiak", "Tobi", "Meshir", "Paremhat", "Parmouti",
+            "Pashons", "Paoni", "Epip", "Mesori", "Nasie",
+        ];
+        NAMES[usize::from(u8::from(self)) - 1]
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = DateCreationError;
+
+    /// The month numbered `value`, from 1 for Thout to 13 for Nasie.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        value
+            .checked_sub(1)
+            .and_then(|index| Self::ALL.get(usize::from(index)))
+            .copied()
+            .ok_or(DateCreationError::InvalidMonth(value))
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(month: Month) -> Self {
+        month as u8
+    }
+}
+
+/// A date in the Coptic Calendar.
+///
+/// Years before the Era of the Martyrs are counted backwards from year 0, which is the year before year 1.
+///
+/// # Examples
+/// ```
+/// use time::{calendar::ConvertCalendar, date::{coptic::{Date, Month}, gregorian::{self, date}}};
+///
+/// // Christmas, on 29 Koiak.
+/// let christmas = Date::from_parts(1740, Month::Koiak, 29).unwrap();
+/// assert_eq!(christmas.convert_to::<gregorian::Date>(), date!(2024-01-08));
+/// assert_eq!(christmas.to_string(), "29 Koiak AM 1740");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i128,
+    month: Month,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date from the day, month and year.
+    ///
+    /// # Errors
+    /// [`DateCreationError::InvalidDay`] if the day doesn't exist in the month, such as the 6th of Nasie in a common
+    /// year.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::coptic::{Date, Month};
+    ///
+    /// assert!(Date::from_parts(1739, Month::Nasie, 6).is_ok());
+    /// assert!(Date::from_parts(1740, Month::Nasie, 6).is_err());
+    /// assert!(Date::from_parts(1740, Month::Thout, 31).is_err());
+    /// ```
+    pub fn from_parts(year: i128, month: Month, day: u8) -> Result<Self, DateCreationError> {
+        if !Self::days_of(year, month).contains(&day) {
+            return Err(DateCreationError::InvalidDay(day));
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// How many days there are from 1 Thout of year 1 to 1 Thout of `year`.
+    fn days_before_year(year: i128) -> i128 {
+        // The leap years are the ones just before a multiple of 4.
+        365 * (year - 1) + year.div_euclid(4)
+    }
+}
+
+impl From<&Date> for StandardCalendar {
+    fn from(date: &Date) -> Self {
+        StandardCalendar::new(
+            EPOCH
+                + Date::days_before_year(date.year)
+                + 30 * (i128::from(u8::from(date.month)) - 1)
+                + i128::from(date.day)
+                - 1,
+        )
+    }
+}
+
+impl From<StandardCalendar> for Date {
+    fn from(standard: StandardCalendar) -> Self {
+        let days = standard.days - EPOCH;
+        // The offset of 2 puts the leap day of each 4 years at the end of their third year.
+        let year = (4 * days + DAYS_IN_4_YEARS + 2).div_euclid(DAYS_IN_4_YEARS);
+        let day_of_year = days - Date::days_before_year(year);
+        Self {
+            year,
+            // At most 13, since the year has at most 366 days.
+            month: Month::try_from((day_of_year / 30 + 1) as u8).unwrap(),
+            day: (day_of_year % 30 + 1) as u8,
+        }
+    }
+}
+
+impl Calendar for Date {
+    type Month = Month;
+    type Day = u8;
+    type Year = i128;
+
+    fn day(&self) -> Self::Day {
+        self.day
+    }
+
+    fn month(&self) -> Self::Month {
+        self.month
+    }
+
+    fn year(&self) -> Self::Year {
+        self.year
+    }
+
+    /// January 1st of year 1 in the Gregorian Calendar, which is 8 Tobi of year -283.
+    fn reference_date() -> Self {
+        StandardCalendar::new(0).into()
+    }
+
+    fn add_days(&mut self, days: i128) {
+        *self = StandardCalendar::new(self.as_days() + days).into();
+    }
+
+    fn as_days(&self) -> i128 {
+        StandardCalendar::from(self).days
+    }
+
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        (second.as_days() - first.as_days()).abs()
+    }
+
+    /// Whether Nasie has 6 days in `year`, which is every fourth year starting from year 3.
+    fn is_leap_year(year: Self::Year) -> bool {
+        year.rem_euclid(4) == 3
+    }
+
+    fn months_of(_: Self::Year) -> Vec<Self::Month> {
+        Month::ALL.to_vec()
+    }
+
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day> {
+        match month {
+            Month::Nasie if Self::is_leap_year(year) => 1..=6,
+            Month::Nasie => 1..=5,
+            _ => 1..=30,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    /// Writes the date with its era, such as "29 Koiak AM 1740".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} AM {}", self.day, self.month.name(), self.year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::ConvertCalendar;
+    use crate::date::gregorian::{self, date};
+
+    #[test]
+    fn new_years() {
+        // (year, Gregorian date of 1 Thout)
+        let cases = [
+            (1, date!(284 - 08 - 29)),
+            // Before a Julian leap year, which moves it a day later in the Julian Calendar.
+            (1616, date!(1899 - 09 - 11)),
+            (1617, date!(1900 - 09 - 11)),
+            // A leap year, so the next one starts a day later.
+            (1739, date!(2022 - 09 - 11)),
+            (1740, date!(2023 - 09 - 12)),
+            (1741, date!(2024 - 09 - 11)),
+            // 2100 is a leap year only in the Julian Calendar, so New Year moves a day later from then on.
+            (1817, date!(2100 - 09 - 12)),
+        ];
+        for (year, gregorian) in cases {
+            let new_year = Date::from_parts(year, Month::Thout, 1).unwrap();
+            assert_eq!(
+                new_year.convert_to::<gregorian::Date>(),
+                gregorian,
+                "{year}"
+            );
+            assert_eq!(gregorian.convert_to::<Date>(), new_year);
+
+            let eve = (gregorian - 1).convert_to::<Date>();
+            let last_day = if Date::is_leap_year(year - 1) { 6 } else { 5 };
+            assert_eq!(
+                (eve.year, eve.month, eve.day),
+                (year - 1, Month::Nasie, last_day)
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut date = Date::from(StandardCalendar::new(-3_000));
+        for days in -3_000..3_000 {
+            let standard = StandardCalendar::new(days);
+            assert_eq!(Date::from(standard), date, "{days}");
+            assert_eq!(StandardCalendar::from(&date), standard);
+            assert_eq!(Date::from_parts(date.year, date.month, date.day), Ok(date));
+            date.add_days(1);
+        }
+        for standard in [StandardCalendar::MIN, StandardCalendar::MAX] {
+            assert_eq!(StandardCalendar::from(&Date::from(standard)), standard);
+        }
+        assert_eq!(
+            Date::reference_date(),
+            Date::from_parts(-283, Month::Tobi, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn months() {
+        for (index, month) in Month::ALL.into_iter().enumerate() {
+            assert_eq!(Month::try_from(u8::from(month)), Ok(month));
+            assert_eq!(u8::from(month), index as u8 + 1);
+        }
+        assert_eq!(Month::try_from(0), Err(DateCreationError::InvalidMonth(0)));
+        assert_eq!(
+            Month::try_from(14),
+            Err(DateCreationError::InvalidMonth(14))
+        );
+        assert_eq!(Month::Nasie.name(), "Nasie");
+        assert!(Date::is_leap_year(-1));
+        assert!(!Date::is_leap_year(0));
+    }
+}
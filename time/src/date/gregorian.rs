@@ -1,11 +1,29 @@
+pub mod bulk;
+pub mod computus;
+pub mod errors;
+mod format;
+pub mod iter;
+pub mod partial;
+pub mod season;
+
+pub use bulk::{dates_from_days, days_from_dates};
+
+use std::fmt;
 use std::num::NonZeroI128;
-use std::ops::Sub;
+use std::ops::{Add, AddAssign, RangeInclusive, Sub, SubAssign};
+use std::str::FromStr;
 
 use crate::StandardCalendar;
+use crate::Weekday;
 use crate::calendar::Calendar;
+use crate::locale::Locale;
+use crate::weekday::WeekendDef;
 
 /// A date in the [Gregorian Calendar](https://en.wikipedia.org/wiki/Gregorian_calendar).
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Dates are local and zone-free: a date is a day on the calendar, which starts at different moments in different
+/// places. See [`UtcInstant`](crate::instant::UtcInstant) for single moments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Date {
     year: Year,
     month: Month,
@@ -17,6 +35,29 @@ impl Date {
         [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
     const LEAP_DAYS_IN_MONTH: [<Self as Calendar>::Day; 12] =
         [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    /// How many days there are before each month of a common year, and in the whole year at the end.
+    const CUMULATIVE_REG: [u16; 13] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365];
+    /// How many days there are before each month of a leap year, and in the whole year at the end.
+    const CUMULATIVE_LEAP: [u16; 13] = [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335, 366];
+    /// The Gregorian Calendar repeats itself every 400 years, which always have this many days.
+    const DAYS_IN_400_YEARS: i128 = 146_097;
+    /// The earliest supported date, January 1st of 10<sup>35</sup> BCE.
+    ///
+    /// Dates from [`Date::MIN`] to [`Date::MAX`] can all be converted to and from a [`StandardCalendar`], between
+    /// [`StandardCalendar::MIN`] and [`StandardCalendar::MAX`]. Dates outside of this range can be created, but
+    /// converting them may overflow.
+    pub const MIN: Self = Self {
+        year: Year(NonZeroI128::new(-100_000_000_000_000_000_000_000_000_000_000_000).unwrap()),
+        month: Month::January,
+        day: 1,
+    };
+    /// The latest supported date, December 31st of 10<sup>35</sup> CE. See [`Date::MIN`].
+    pub const MAX: Self = Self {
+        year: Year(NonZeroI128::new(100_000_000_000_000_000_000_000_000_000_000_000).unwrap()),
+        month: Month::December,
+        day: 31,
+    };
+
     /// Creates a date in the Gregorian Calendar from the day, month and year.
     ///
     /// # Examples
@@ -38,297 +79,1269 @@ impl Date {
     /// assert!(Date::from_parts(year!(2000), Month::February, 29).is_ok());
     /// # Ok::<(),std::num::IntErrorKind>(())
     /// ```
-    pub fn from_parts(
+    pub const fn from_parts(
         year: Year,
         month: <Self as Calendar>::Month,
         day: <Self as Calendar>::Day,
     ) -> Result<Self, errors::DateCreationError> {
         // convert to the appropriate list indices
-        let days_in_month = if Self::is_leap_year(year) {
+        let days_in_month = if year.is_leap_year() {
             Self::LEAP_DAYS_IN_MONTH
         } else {
             Self::REG_DAYS_IN_MONTH
         };
 
-        // Subtract one because the list is 0-indexed.
-        if !(1..=days_in_month[month as usize - 1]).contains(&day) {
+        if day < 1 || day > days_in_month[month.index0()] {
             return Err(errors::DateCreationError::InvalidDay(day));
         }
 
         Ok(Self { year, day, month })
     }
 
-    pub fn from_year(year: Year) -> Self {
-        Self {
-            year,
-            month: Month::January,
-            day: 1,
-        }
-    }
-
-    fn leap_days_between(first: &Self, second: &Self) -> usize {
-        let (first, second) = if first > second {
-            (second, first)
-        } else {
-            (first, second)
-        };
-
-        // Doesn't include either `first` or `second`
-        let leap_years = ((first.year.0.get() + 1)..second.year.0.get())
-            .filter(|year| Self::is_leap_year(Year::try_from(*year).unwrap()))
-            .count();
-
-        let mut leap_days = leap_years;
-        if first.month <= Month::February && Self::is_leap_year(first.year) {
-            leap_days += 1;
-        }
-        if second.month > Month::February && Self::is_leap_year(second.year) {
-            leap_days += 1;
+    /// Creates a date like [`Date::from_parts`], for use in `const` contexts.
+    ///
+    /// For literals, the [`date`] macro is shorter.
+    ///
+    /// # Panics
+    /// If the day doesn't exist in the month. In a `const`, this is a compile-time error.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// const MOON_LANDING: Date = Date::from_parts_const(year!(1969), Month::July, 20);
+    /// assert_eq!(Date::from_parts(year!(1969), Month::July, 20), Ok(MOON_LANDING));
+    /// ```
+    /// ```compile_fail
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// const NOT_A_LEAP_DAY: Date = Date::from_parts_const(year!(1900), Month::February, 29);
+    /// ```
+    pub const fn from_parts_const(
+        year: Year,
+        month: <Self as Calendar>::Month,
+        day: <Self as Calendar>::Day,
+    ) -> Self {
+        match Self::from_parts(year, month, day) {
+            Ok(date) => date,
+            Err(_) => panic!("The day doesn't exist in this month."),
         }
-
-        leap_days
     }
-}
 
-impl From<&Date> for StandardCalendar {
-    // The standard calendar has day 0 set as the GregorianCalendar's 1/1/1
-    fn from(date: &Date) -> Self {
-        let between = Date::days_between(&Date::reference_date(), date);
-        let diff = if &Date::reference_date() < date {
-            between
+    /// Creates a date like [`Date::from_parts`], but moving days that don't exist in the month to its closest day.
+    ///
+    /// Day 0 becomes the 1st, and days after the month ends become its last day. The [`DayAdjustment`] tells
+    /// whether that happened.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, DayAdjustment, Month, year};
+    ///
+    /// // Strict: an error.
+    /// assert!(Date::from_parts(year!(1900), Month::February, 29).is_err());
+    /// // Clamped: the last day of the month.
+    /// assert_eq!(
+    ///     Date::from_parts_clamped(year!(1900), Month::February, 29),
+    ///     (Date::from_parts(year!(1900), Month::February, 28).unwrap(), DayAdjustment::Clamped)
+    /// );
+    /// // Rolled over: into the next month.
+    /// assert_eq!(
+    ///     Date::from_parts_rollover(year!(1900), Month::February, 29),
+    ///     (Date::from_parts(year!(1900), Month::March, 1).unwrap(), DayAdjustment::RolledOver)
+    /// );
+    /// ```
+    pub fn from_parts_clamped(
+        year: Year,
+        month: <Self as Calendar>::Month,
+        day: <Self as Calendar>::Day,
+    ) -> (Self, DayAdjustment) {
+        let length = Self::month_lengths(year)[month.index0()];
+        let clamped = day.clamp(1, length);
+        let adjustment = if clamped == day {
+            DayAdjustment::Unchanged
         } else {
-            -between
+            DayAdjustment::Clamped
         };
-        StandardCalendar::new(diff)
+        (
+            Self {
+                year,
+                month,
+                day: clamped,
+            },
+            adjustment,
+        )
     }
-}
 
-impl From<StandardCalendar> for Date {
-    fn from(standard: StandardCalendar) -> Self {
-        // TODO: fix
-        Self {
-            year: todo!(),
-            month: Month::try_from(((standard.days % 365) / 12) as u8 + 1).unwrap(),
-            day: (standard.days % 365) as u8,
+    /// Creates a date like [`Date::from_parts`], but counting `day` from the start of the month even past its end.
+    ///
+    /// Days after the month ends roll into the following months, so the 32nd of January is February 1st, and day 0
+    /// is the last day of the previous month. The [`DayAdjustment`] tells whether that happened.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, DayAdjustment, Month, year};
+    ///
+    /// assert_eq!(
+    ///     Date::from_parts_rollover(year!(2024), Month::January, 32),
+    ///     (Date::from_parts(year!(2024), Month::February, 1).unwrap(), DayAdjustment::RolledOver)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_parts_rollover(year!(1), Month::January, 0),
+    ///     (Date::from_parts(year!(-1), Month::December, 31).unwrap(), DayAdjustment::RolledOver)
+    /// );
+    /// ```
+    pub fn from_parts_rollover(
+        year: Year,
+        month: <Self as Calendar>::Month,
+        day: <Self as Calendar>::Day,
+    ) -> (Self, DayAdjustment) {
+        if let Ok(date) = Self::from_parts(year, month, day) {
+            return (date, DayAdjustment::Unchanged);
         }
+        let mut date = Self {
+            year,
+            month,
+            day: 1,
+        };
+        date.add_days(i128::from(day) - 1);
+        (date, DayAdjustment::RolledOver)
     }
-}
-
-impl Calendar for Date {
-    type Day = u8;
-    type Month = Month;
-    type Year = Year;
 
-    fn day(&self) -> Self::Day {
-        self.day
+    /// The day before this one, unless it would be before [`Date::MIN`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// let new_year = Date::from_parts(year!(1), Month::January, 1).unwrap();
+    /// assert_eq!(new_year.pred(), Date::from_parts(year!(-1), Month::December, 31).ok());
+    /// assert_eq!(Date::MIN.pred(), None);
+    /// ```
+    pub fn pred(&self) -> Option<Self> {
+        (Self::MIN < *self && *self <= Self::MAX)
+            .then(|| StandardCalendar::new(self.as_days() - 1).into())
     }
 
-    fn year(&self) -> Self::Year {
-        self.year
+    /// The day after this one, unless it would be after [`Date::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// let leap_day = Date::from_parts(year!(2024), Month::February, 28).unwrap().succ();
+    /// assert_eq!(leap_day, Date::from_parts(year!(2024), Month::February, 29).ok());
+    /// assert_eq!(Date::MAX.succ(), None);
+    /// ```
+    pub fn succ(&self) -> Option<Self> {
+        (Self::MIN <= *self && *self < Self::MAX)
+            .then(|| StandardCalendar::new(self.as_days() + 1).into())
     }
 
-    fn month(&self) -> Self::Month {
-        self.month
+    /// The date `days` days after this one, or before it if negative, unless it would be outside of [`Date::MIN`]
+    /// and [`Date::MAX`].
+    ///
+    /// This is [`Date::checked_add_days_with`] the default [`DateArithmeticPolicy`], which steps over the year 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, date};
+    ///
+    /// assert_eq!(date!(-1-12-31).checked_add_days(1), Some(date!(1-01-01)));
+    /// assert_eq!(Date::MAX.checked_add_days(1), None);
+    /// assert_eq!(date!(2024-01-01).checked_add_days(i128::MIN), None);
+    /// ```
+    pub fn checked_add_days(&self, days: i128) -> Option<Self> {
+        self.checked_add_days_with(days, DateArithmeticPolicy::default())
     }
 
-    fn reference_date() -> Self {
-        Self {
-            year: year!(1),
-            month: Month::January,
-            day: 1,
+    /// The date `days` days after this one, or before it if negative, unless `policy` rejects it.
+    ///
+    /// Returns [`None`] if this date or the result is outside of [`Date::MIN`] and [`Date::MAX`], or if
+    /// [`DateArithmeticPolicy::Strict`] is used and the result is on the other side of the year 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{DateArithmeticPolicy, date};
+    ///
+    /// // 1 BCE is a leap year, as the year 0 of astronomical numbering.
+    /// let start = date!(-1-01-01);
+    /// assert_eq!(start.checked_add_days_with(366, DateArithmeticPolicy::SkipYearZero), Some(date!(1-01-01)));
+    /// assert_eq!(start.checked_add_days_with(366, DateArithmeticPolicy::Strict), None);
+    /// assert_eq!(start.checked_add_days_with(365, DateArithmeticPolicy::Strict), Some(date!(-1-12-31)));
+    /// ```
+    pub fn checked_add_days_with(&self, days: i128, policy: DateArithmeticPolicy) -> Option<Self> {
+        if !(Self::MIN..=Self::MAX).contains(self) {
+            return None;
         }
-    }
-    fn add_days(&mut self, days: i128) {
-        // TODO: fix
-        todo!()
-        /* self.year += days / 365;
-        self.day += (days % 365) as u8; */
+        let days = self.as_days().checked_add(days)?;
+        if !(StandardCalendar::MIN.days..=StandardCalendar::MAX.days).contains(&days) {
+            return None;
+        }
+        let date = Self::from(StandardCalendar::new(days));
+        let crosses_zero = (date.year.to_astronomical() > 0) != (self.year.to_astronomical() > 0);
+        (policy == DateArithmeticPolicy::SkipYearZero || !crosses_zero).then_some(date)
     }
 
-    fn as_days(&self) -> i128 {
-        // TODO: fix
-        todo!();
-        //self.year * 365 + self.day as i128
+    /// Which quarter of the year this date is in, from 1 to 4.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// assert_eq!(Date::from_parts(year!(2024), Month::January, 1).unwrap().quarter(), 1);
+    /// assert_eq!(Date::from_parts(year!(2024), Month::June, 30).unwrap().quarter(), 2);
+    /// assert_eq!(Date::from_parts(year!(2024), Month::July, 1).unwrap().quarter(), 3);
+    /// assert_eq!(Date::from_parts(year!(-44), Month::December, 31).unwrap().quarter(), 4);
+    /// ```
+    pub fn quarter(&self) -> u8 {
+        (self.month.index0() / 3 + 1) as u8
     }
 
-    /// Returns the amount of days between `first` and `second`.
+    /// How many full years have passed since this date when it is `later`, like someone's age.
+    ///
+    /// Someone born on February 29th only completes a year on March 1st in regular years.
+    /// If `later` is before this date, the result is negative.
     ///
     /// # Examples
     /// ```
     /// use time::date::gregorian::{Date, Month, year};
-    /// use time::calendar::Calendar;
     ///
-    /// // Equal dates are 0 days apart.
-    /// assert_eq!(Date::days_between(&Date::from_parts(year!(1), Month::January, 1).unwrap(), &Date::from_parts(year!(1), Month::January, 1).unwrap()), 0);
-    /// assert_eq!(Date::days_between(&Date::from_parts(year!(1), Month::January, 2).unwrap(), &Date::from_parts(year!(1), Month::January, 1).unwrap()), 1);
+    /// let birth = Date::from_parts(year!(1879), Month::March, 14).unwrap();
+    /// assert_eq!(birth.age_at(&Date::from_parts(year!(1955), Month::April, 18).unwrap()), 76);
+    /// assert_eq!(birth.age_at(&Date::from_parts(year!(1955), Month::March, 13).unwrap()), 75);
     ///
-    /// assert_eq!(Date::days_between(&Date::from_parts(year!(2), Month::January, 1).unwrap(), &Date::from_parts(year!(1), Month::January, 1).unwrap()), 365);
+    /// // There is no year 0.
+    /// let birth = Date::from_parts(year!(-5), Month::January, 1).unwrap();
+    /// assert_eq!(birth.age_at(&Date::from_parts(year!(6), Month::January, 1).unwrap()), 10);
     /// ```
-    fn days_between(first: &Self, second: &Self) -> i128 {
-        let (first, second) = if first > second {
-            (second, first)
-        } else {
-            (first, second)
-        };
-        // If they're in the same year, we just calculate the days between.
-        if first.year == second.year {
-            let days_in_month = if Self::is_leap_year(first.year) {
-                Self::LEAP_DAYS_IN_MONTH
-            } else {
-                Self::REG_DAYS_IN_MONTH
-            };
-
-            return days_in_month[first.month as usize..second.month as usize]
-                .iter()
-                .map(|i| *i as u16)
-                .sum::<u16>() as i128
-                + second.day as i128
-                - first.day as i128;
+    pub fn age_at(&self, later: &Self) -> i128 {
+        if later < self {
+            return -later.age_at(self);
         }
 
-        let days_in_month_second = if Self::is_leap_year(second.year) {
-            Self::LEAP_DAYS_IN_MONTH
-        } else {
-            Self::REG_DAYS_IN_MONTH
-        };
-        // How many days from Jan 1st we are on the second year.
-        let days_last_year: u16 = days_in_month_second
-            .iter()
-            .take(second.month as usize - 1) // Month as usize -> starts from 1, so this will never panic.
-            .map(|i| *i as u16)
-            .sum::<u16>()
-            + second.day as u16
-            - 1;
-
-        let days_in_month_first = if Self::is_leap_year(first.year) {
-            Self::LEAP_DAYS_IN_MONTH
-        } else {
-            Self::REG_DAYS_IN_MONTH
-        };
-        // How many days until Jan 1st of the year after first.
-        let days_first_year = days_in_month_first
-            // Month as usize starts from 1, so by not subtracting 1, we start from the month after
-            .get((first.month as usize)..)
-            .map_or(0, |months| months.iter().map(|i| *i as u16).sum())
-            + days_in_month_first[first.month as usize - 1] as u16 // Sub 1 to get the actual month in 0 indexing
-            - first.day as u16
-            + 1;
-
-        let leap_days = Self::leap_days_between(
-            &Date::from_year(first.year.next()),
-            &Date::from_year(second.year),
-        );
-        let days_other_years = if second.year - first.year > 1 {
-            (second.year - first.year - 1) * 365 + leap_days as i128
+        let years = later.year - self.year;
+        if self.anniversary_in(later.year) > *later {
+            years - 1
         } else {
-            0
-        };
-        days_other_years + days_first_year as i128 + days_last_year as i128
+            years
+        }
     }
 
-    /// Returns whether the date is a leap year.
+    /// The first anniversary of this date that comes strictly after `after`.
     ///
-    /// Leap years represent added days to the year, in order to mantain sync with Earth's rotation.
-    /// For more information on how leap years are checked, read [`Year::is_leap_year`].
-    fn is_leap_year(year: Self::Year) -> bool {
-        year.is_leap_year()
-    }
-}
+    /// February 29th anniversaries fall on March 1st in regular years, as in [`Date::age_at`].
+    /// If `after` is before this date, the date itself is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// let founding = Date::from_parts(year!(1876), Month::March, 10).unwrap();
+    /// assert_eq!(
+    ///     founding.next_anniversary_after(&Date::from_parts(year!(2026), Month::January, 1).unwrap()),
+    ///     Date::from_parts(year!(2026), Month::March, 10).unwrap()
+    /// );
+    /// // Strictly after: the anniversary itself moves on to the following year.
+    /// assert_eq!(
+    ///     founding.next_anniversary_after(&Date::from_parts(year!(2026), Month::March, 10).unwrap()),
+    ///     Date::from_parts(year!(2027), Month::March, 10).unwrap()
+    /// );
+    /// ```
+    pub fn next_anniversary_after(&self, after: &Self) -> Self {
+        if after < self {
+            return *self;
+        }
 
-impl PartialOrd for Date {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        let anniversary = self.anniversary_in(after.year);
+        if anniversary > *after {
+            anniversary
+        } else {
+            self.anniversary_in(after.year.next())
+        }
     }
-}
 
-impl Ord for Date {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.year.cmp(&other.year) {
-            std::cmp::Ordering::Equal => {}
-            order => return order,
-        }
-        match self.month.cmp(&other.month) {
-            std::cmp::Ordering::Equal => {}
-            order => return order,
+    /// This date's anniversary in `year`.
+    ///
+    /// February 29th falls on March 1st when `year` is not a leap year.
+    fn anniversary_in(&self, year: Year) -> Self {
+        if self.month == Month::February && self.day == 29 && !year.is_leap_year() {
+            return Self {
+                year,
+                month: Month::March,
+                day: 1,
+            };
         }
-        self.day.cmp(&other.day)
+        Self { year, ..*self }
     }
-}
-
-/// Representation of a year for the [Gregorian Calendar](https://en.wikipedia.org/wiki/Gregorian_calendar).
-///
-/// For creation with variables, use [`Year::new`]. For creation with literals, use the [`year`] macro.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Year(std::num::NonZeroI128);
 
-impl Year {
-    /// Constructor for [`Year`].
-    ///
-    /// Simply wraps the given `year` and returns a new [`Year`].
+    /// Which day of the week this date falls on.
     ///
     /// # Examples
     /// ```
-    /// # use std::num::NonZeroI128;
-    /// use time::date::gregorian;
-    /// let some_year = gregorian::Year::new(NonZeroI128::new(1528).unwrap());
+    /// use time::{Weekday, date::gregorian::{Date, Month, year}};
+    ///
+    /// assert_eq!(Date::from_parts(year!(1), Month::January, 1).unwrap().weekday(), Weekday::Monday);
+    /// assert_eq!(Date::from_parts(year!(1969), Month::July, 20).unwrap().weekday(), Weekday::Sunday);
     /// ```
-    pub fn new(year: std::num::NonZeroI128) -> Self {
-        Self(year)
+    pub fn weekday(&self) -> Weekday {
+        StandardCalendar::from(self).weekday()
     }
 
-    /// Returns whether this is a leap year.
+    /// Creates the date of the `n`th `weekday` of the given month, such as "the third Thursday of November 1863".
     ///
-    /// In the [Gregorian Calendar](https://en.wikipedia.org/wiki/Gregorian_calendar), a leap year happens in all years that
-    /// are divisible by 4, except by those that are divisible by 100, except in turn those that are divisible by 400.
+    /// # Errors
+    /// Returns `DateCreationError::NoSuchOccurrence` if asking for the 0th weekday, or for one that goes past the end
+    /// of the month (like a fifth Thursday in a month that only has four).
     ///
     /// # Examples
     /// ```
-    /// # use time::{Calendar, date::gregorian};
-    /// assert!(gregorian::year!(2020).is_leap_year());
-    /// assert!(gregorian::year!(2000).is_leap_year());
+    /// use time::{Weekday, date::gregorian::{Date, Month, Occurrence, year}};
     ///
-    /// assert!(!gregorian::year!(1900).is_leap_year());
-    /// assert!(!gregorian::year!(2017).is_leap_year());
-    /// assert!(!gregorian::year!(2018).is_leap_year());
-    /// # Ok::<(),std::num::IntErrorKind>(())
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(year!(1863), Month::November, Weekday::Thursday, Occurrence::Nth(3)).unwrap(),
+    ///     Date::from_parts(year!(1863), Month::November, 19).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(year!(1863), Month::November, Weekday::Thursday, Occurrence::Last).unwrap(),
+    ///     Date::from_parts(year!(1863), Month::November, 26).unwrap()
+    /// );
+    /// assert!(
+    ///     Date::nth_weekday_of_month(year!(1863), Month::November, Weekday::Thursday, Occurrence::Nth(5)).is_err()
+    /// );
     /// ```
-    pub fn is_leap_year(&self) -> bool {
-        let inner = self.0.get();
-        inner % 4 == 0 && ((inner % 400 == 0) || inner % 100 != 0)
-    }
-
-    pub fn next(self) -> Self {
-        match self.0.get() {
-            -1 => {
-                year!(1)
+    pub fn nth_weekday_of_month(
+        year: Year,
+        month: Month,
+        weekday: Weekday,
+        n: Occurrence,
+    ) -> Result<Self, errors::DateCreationError> {
+        let days_in_month = Self::month_lengths(year)[month.index0()];
+        let day = match n {
+            Occurrence::Nth(nth) => {
+                let first = Self {
+                    year,
+                    month,
+                    day: 1,
+                };
+                let day = u16::from(nth)
+                    .checked_sub(1)
+                    .map(|weeks| 1 + u16::from(first.weekday().days_until(weekday)) + weeks * 7);
+                match day {
+                    Some(day) if day <= u16::from(days_in_month) => day as u8,
+                    _ => return Err(errors::DateCreationError::NoSuchOccurrence(nth)),
+                }
             }
-            // Safety: We already handled the case where the year + 1 would be 0.
-            other => unsafe { Self(NonZeroI128::new_unchecked(other + 1)) },
-        }
-    }
-}
+            Occurrence::Last => {
+                let last = Self {
+                    year,
+                    month,
+                    day: days_in_month,
+                };
+                days_in_month - weekday.days_until(last.weekday())
+            }
+        };
 
-impl TryFrom<i128> for Year {
-    type Error = std::num::IntErrorKind;
-    fn try_from(year: i128) -> Result<Self, Self::Error> {
-        let year = NonZeroI128::new(year).ok_or(std::num::IntErrorKind::Zero)?;
-        Ok(Year::new(year))
+        Ok(Self { year, month, day })
     }
-}
 
-impl Sub<Year> for Year {
-    type Output = i128;
-    /// A subtraction between years is handled as the difference between them.
+    /// The first date strictly after this one that falls on `weekday`.
     ///
-    /// That is, how many [Year]s 'apart' they are.
+    /// # Examples
+    /// ```
+    /// use time::{Weekday, date::gregorian::{Date, Month, year}};
     ///
-    /// Since there is no _year 0_, this is **not** equivalent to `i128 - i128`.
+    /// // A Sunday.
+    /// let date = Date::from_parts(year!(1969), Month::July, 20).unwrap();
+    /// assert_eq!(date.next_weekday(Weekday::Monday), Date::from_parts(year!(1969), Month::July, 21).unwrap());
+    /// assert_eq!(date.next_weekday(Weekday::Sunday), Date::from_parts(year!(1969), Month::July, 27).unwrap());
+    /// ```
+    pub fn next_weekday(&self, weekday: Weekday) -> Self {
+        let ahead = match self.weekday().days_until(weekday) {
+            0 => 7,
+            days => days,
+        };
+        let mut date = *self;
+        date.add_days(i128::from(ahead));
+        date
+    }
+
+    /// The last date strictly before this one that falls on `weekday`.
     ///
     /// # Examples
     /// ```
-    /// # use time::date::gregorian::year;
-    /// assert_eq!(year!(1528) - year!(1528), 0);
-    /// assert_eq!(year!(1528) - year!(1527), 1);
+    /// use time::{Weekday, date::gregorian::{Date, Month, year}};
+    ///
+    /// // A Sunday.
+    /// let date = Date::from_parts(year!(1969), Month::July, 20).unwrap();
+    /// assert_eq!(date.previous_weekday(Weekday::Saturday), Date::from_parts(year!(1969), Month::July, 19).unwrap());
+    /// assert_eq!(date.previous_weekday(Weekday::Sunday), Date::from_parts(year!(1969), Month::July, 13).unwrap());
+    /// ```
+    pub fn previous_weekday(&self, weekday: Weekday) -> Self {
+        let behind = match weekday.days_until(self.weekday()) {
+            0 => 7,
+            days => days,
+        };
+        let mut date = *self;
+        date.add_days(-i128::from(behind));
+        date
+    }
+
+    /// Moves this date by `n` business days, skipping the days in `weekend`.
+    ///
+    /// The date itself is never counted: adding 1 business day to a Friday lands on the next Monday, and so does
+    /// adding 1 to a Saturday. Negative values of `n` walk backwards in the same way, and `0` leaves the date as is,
+    /// even if it's on a weekend.
+    ///
+    /// # Panics
+    /// Panics if `n` isn't 0 and every day of the week is part of the `weekend`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{date::gregorian::{Date, Month, year}, weekday::WeekendDef};
+    ///
+    /// // A Friday.
+    /// let mut date = Date::from_parts(year!(2024), Month::March, 1).unwrap();
+    /// date.add_business_days(1, &WeekendDef::default());
+    /// assert_eq!(date, Date::from_parts(year!(2024), Month::March, 4).unwrap());
+    /// date.add_business_days(-6, &WeekendDef::default());
+    /// assert_eq!(date, Date::from_parts(year!(2024), Month::February, 23).unwrap());
+    /// ```
+    pub fn add_business_days(&mut self, n: i128, weekend: &WeekendDef) {
+        if n == 0 {
+            return;
+        }
+        let per_week = i128::from(weekend.working_days_per_week());
+        assert!(per_week > 0, "the whole week is part of the weekend");
+
+        // Any 7 days in a row have exactly `per_week` business days, so whole weeks can be skipped at once.
+        // The last one is walked day by day, since the date might start on a weekend.
+        let (mut weeks, mut rest) = (n / per_week, n % per_week);
+        if rest == 0 {
+            weeks -= n.signum();
+            rest = per_week * n.signum();
+        }
+        self.add_days(weeks * 7);
+
+        let step = rest.signum();
+        while rest != 0 {
+            self.add_days(step);
+            if !weekend.is_weekend(self.weekday()) {
+                rest -= step;
+            }
+        }
+    }
+
+    /// Returns the amount of business days between `first` and `second`, skipping the days in `weekend` and `holidays`.
+    ///
+    /// Start inclusive, end exclusive, like [`Date::days_between`]. The result is negative if `second` comes before
+    /// `first`, in which case `second` is the inclusive start. This mirrors [`Date::add_business_days`]: when both
+    /// dates are business days, adding the result to `first` lands on `second`.
+    ///
+    /// `holidays` must be sorted. Holidays that fall on a weekend are only skipped once.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{date::gregorian::{Date, Month, year}, weekday::WeekendDef};
+    ///
+    /// // From a Monday to the next Monday.
+    /// let first = Date::from_parts(year!(2024), Month::December, 23).unwrap();
+    /// let second = Date::from_parts(year!(2024), Month::December, 30).unwrap();
+    /// let christmas = Date::from_parts(year!(2024), Month::December, 25).unwrap();
+    ///
+    /// assert_eq!(Date::business_days_between(&first, &second, &WeekendDef::default(), &[]), 5);
+    /// assert_eq!(Date::business_days_between(&first, &second, &WeekendDef::default(), &[christmas]), 4);
+    /// assert_eq!(Date::business_days_between(&second, &first, &WeekendDef::default(), &[christmas]), -4);
+    /// ```
+    pub fn business_days_between(
+        first: &Self,
+        second: &Self,
+        weekend: &WeekendDef,
+        holidays: &[Self],
+    ) -> i128 {
+        if second < first {
+            return -Self::business_days_between(second, first, weekend, holidays);
+        }
+
+        let days = Self::days_between(first, second);
+        let mut business_days = days / 7 * i128::from(weekend.working_days_per_week());
+        // The days left over after the whole weeks.
+        let mut day = *first;
+        day.add_days(days / 7 * 7);
+        for _ in 0..days % 7 {
+            if !weekend.is_weekend(day.weekday()) {
+                business_days += 1;
+            }
+            day.add_days(1);
+        }
+
+        let holidays = &holidays[holidays.partition_point(|holiday| holiday < first)
+            ..holidays.partition_point(|holiday| holiday < second)];
+        let skipped_holidays = holidays
+            .iter()
+            .enumerate()
+            .filter(|(i, holiday)| {
+                // Skip repeated holidays, since the slice is sorted.
+                (*i == 0 || holidays[i - 1] != **holiday) && !weekend.is_weekend(holiday.weekday())
+            })
+            .count();
+
+        business_days - skipped_holidays as i128
+    }
+
+    /// How many days each month has in `year`.
+    fn month_lengths(year: Year) -> [<Self as Calendar>::Day; 12] {
+        if Self::is_leap_year(year) {
+            Self::LEAP_DAYS_IN_MONTH
+        } else {
+            Self::REG_DAYS_IN_MONTH
+        }
+    }
+
+    /// How many days there are before the 1st of `month`, in a leap year if `leap`.
+    fn days_before_month(month: Month, leap: bool) -> u16 {
+        Self::cumulative_days(leap)[month.index0()]
+    }
+
+    /// The month with the `ordinal`th day of the year, in a leap year if `leap`, unless the year doesn't have it.
+    fn month_of_ordinal(ordinal: u16, leap: bool) -> Option<Month> {
+        let cumulative = Self::cumulative_days(leap);
+        if !(1..=cumulative[12]).contains(&ordinal) {
+            return None;
+        }
+        // The first month whose end is on or after the day, which is at least January as the day is at least 1.
+        Some(Month::ALL[cumulative.partition_point(|&before| before < ordinal) - 1])
+    }
+
+    /// The prefix sums of the months' lengths, in a leap year if `leap`.
+    fn cumulative_days(leap: bool) -> &'static [u16; 13] {
+        if leap {
+            &Self::CUMULATIVE_LEAP
+        } else {
+            &Self::CUMULATIVE_REG
+        }
+    }
+
+    /// This date moved by `months` months, with the day clamped to the length of the month it lands in.
+    fn months_after(&self, months: i128) -> Self {
+        let total = self.year.to_astronomical() * 12 + self.month.index0() as i128 + months;
+        let year = Year::from_astronomical(total.div_euclid(12));
+        // `rem_euclid(12)` is always a valid month index.
+        let month = Month::ALL[total.rem_euclid(12) as usize];
+        let day = self.day.min(Self::month_lengths(year)[month.index0()]);
+        Self { year, month, day }
+    }
+
+    /// Which day of the year this date is, starting from 1 on January 1st.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// assert_eq!(Date::from_parts(year!(2023), Month::January, 1).unwrap().ordinal(), 1);
+    /// assert_eq!(Date::from_parts(year!(2023), Month::December, 31).unwrap().ordinal(), 365);
+    /// assert_eq!(Date::from_parts(year!(2024), Month::December, 31).unwrap().ordinal(), 366);
+    /// ```
+    pub fn ordinal(&self) -> u16 {
+        Self::days_before_month(self.month, self.year.is_leap_year()) + u16::from(self.day)
+    }
+
+    /// Creates a date from its year and its [day of the year](Date::ordinal).
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// assert_eq!(Date::from_ordinal(year!(2024), 60).unwrap(), Date::from_parts(year!(2024), Month::February, 29).unwrap());
+    /// assert_eq!(Date::from_ordinal(year!(2023), 60).unwrap(), Date::from_parts(year!(2023), Month::March, 1).unwrap());
+    /// assert!(Date::from_ordinal(year!(2023), 366).is_err());
+    /// assert!(Date::from_ordinal(year!(2023), 0).is_err());
+    /// ```
+    pub fn from_ordinal(year: Year, ordinal: u16) -> Result<Self, errors::DateCreationError> {
+        let leap = year.is_leap_year();
+        let month = Self::month_of_ordinal(ordinal, leap)
+            .ok_or(errors::DateCreationError::InvalidOrdinal(ordinal))?;
+        Ok(Self {
+            year,
+            month,
+            // At most 31, since the day is in `month`.
+            day: (ordinal - Self::days_before_month(month, leap)) as u8,
+        })
+    }
+
+    /// The [ISO 8601 week](https://en.wikipedia.org/wiki/ISO_week_date) this date is in.
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one with its first Thursday. The days around New
+    /// Year can be in a week of the year before or after.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{IsoWeek, date, year};
+    ///
+    /// assert_eq!(date!(2023-10-12).iso_week().to_string(), "2023-W41");
+    /// assert_eq!(date!(2021-01-01).iso_week(), IsoWeek { year: year!(2020), week: 53 });
+    /// assert_eq!(date!(2024-12-30).iso_week(), IsoWeek { year: year!(2025), week: 1 });
+    /// ```
+    pub fn iso_week(&self) -> IsoWeek {
+        // Weeks belong to the year their Thursday is in.
+        let thursday = *self + (Weekday::Thursday as i128 - self.weekday() as i128);
+        IsoWeek {
+            year: thursday.year,
+            // At most 53, since a year has at most 366 days.
+            week: ((thursday.ordinal() - 1) / 7 + 1) as u8,
+        }
+    }
+
+    /// Whether `other` is at most `tolerance` days away from this date, in either direction.
+    ///
+    /// The boundary is inclusive: dates exactly `tolerance` days apart are within it.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::date;
+    ///
+    /// assert!(date!(2024-03-01).within_days(&date!(2024-02-28), 2));
+    /// assert!(date!(2024-02-28).within_days(&date!(2024-03-01), 2));
+    /// assert!(!date!(2024-02-28).within_days(&date!(2024-03-01), 1));
+    /// ```
+    pub fn within_days(&self, other: &Self, tolerance: u64) -> bool {
+        StandardCalendar::from(self).within_days(&other.into(), tolerance)
+    }
+
+    /// Whether `other` is within `tolerance` of this date, in either direction.
+    ///
+    /// Months and years are counted on the calendar from the earlier date, with the day clamped to the length of the
+    /// month it lands in, as in [`Date::apply_offset`]. The boundary is inclusive: January 31st is within a month of
+    /// February 29th in a leap year, but not of March 1st.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{TimeTolerance, date};
+    ///
+    /// assert!(date!(2024-01-31).within(&date!(2024-02-29), TimeTolerance::Months(1)));
+    /// assert!(!date!(2024-01-31).within(&date!(2024-03-01), TimeTolerance::Months(1)));
+    /// assert!(date!(2025-02-28).within(&date!(2024-02-29), TimeTolerance::Years(1)));
+    /// assert!(date!(2024-01-01).within(&date!(2024-01-08), TimeTolerance::Days(7)));
+    /// ```
+    pub fn within(&self, other: &Self, tolerance: TimeTolerance) -> bool {
+        let (earlier, later) = if self <= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let months = match tolerance {
+            TimeTolerance::Days(days) => return self.within_days(other, days),
+            TimeTolerance::Months(months) => i128::from(months),
+            TimeTolerance::Years(years) => i128::from(years) * 12,
+        };
+        *later <= earlier.months_after(months)
+    }
+
+    /// This date moved by `offset`.
+    ///
+    /// The years and months are applied first, together, with the day clamped to the length of the month they land
+    /// in. The days are added after that.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{DateOffset, date};
+    ///
+    /// let months = |months| DateOffset { months, ..DateOffset::default() };
+    /// assert_eq!(date!(2024-01-31).apply_offset(&months(1)), date!(2024-02-29));
+    /// assert_eq!(date!(2024-03-31).apply_offset(&months(-13)), date!(2023-02-28));
+    ///
+    /// let offset = DateOffset { years: 0, months: 1, days: 1 };
+    /// assert_eq!(date!(2024-01-31).apply_offset(&offset), date!(2024-03-01));
+    /// ```
+    pub fn apply_offset(&self, offset: &DateOffset) -> Self {
+        self.months_after(offset.years * 12 + offset.months) + offset.days
+    }
+
+    /// Compares the dates by their year, then their month and then their day, without converting them to
+    /// [`StandardCalendar`] day counts.
+    ///
+    /// This is the comparison [`Ord`] uses, and always agrees with comparing the day counts, as dates of later years,
+    /// months and days are always later days.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use time::date::gregorian::date;
+    ///
+    /// assert_eq!(date!(-1-12-31).cmp_days(&date!(1-01-01)), Ordering::Less);
+    /// assert_eq!(date!(-1-12-31).cmp_days(&date!(1-01-01)), date!(-1-12-31).cmp(&date!(1-01-01)));
+    /// ```
+    pub fn cmp_days(&self, other: &Self) -> std::cmp::Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+
+    pub fn from_year(year: Year) -> Self {
+        Self {
+            year,
+            month: Month::January,
+            day: 1,
+        }
+    }
+
+    fn leap_days_between(first: &Self, second: &Self) -> i128 {
+        let (first, second) = if first > second {
+            (second, first)
+        } else {
+            (first, second)
+        };
+
+        // Doesn't include either `first` or `second`
+        let mut leap_days = leap_years_between(first.year.next(), second.year);
+        if first.month <= Month::February && Self::is_leap_year(first.year) {
+            leap_days += 1;
+        }
+        if second.month > Month::February && Self::is_leap_year(second.year) {
+            leap_days += 1;
+        }
+
+        leap_days
+    }
+}
+
+/// Slow reference implementations, which the fast paths are checked against with the `validate` feature.
+#[cfg(feature = "validate")]
+impl Date {
+    /// How far from _day 0_ dates are checked, since the slow paths take longer the further they go.
+    const CHECKED_DAYS: i128 = 36_525;
+
+    /// The [`StandardCalendar`] days of this date, counted through [`Date::days_between`].
+    fn slow_days(&self) -> i128 {
+        let between = Self::days_between(&Self::reference_date(), self);
+        if &Self::reference_date() < self {
+            between
+        } else {
+            -between
+        }
+    }
+
+    /// The day after this one if `forward`, or the one before it otherwise, found through the months' lengths.
+    fn slow_step(&self, forward: bool) -> Self {
+        let lengths = Self::month_lengths(self.year);
+        let month = self.month.index0();
+        match (forward, self.day, self.month) {
+            (true, day, _) if day < lengths[month] => Self {
+                day: day + 1,
+                ..*self
+            },
+            (true, _, Month::December) => Self::from_year(self.year.next()),
+            (true, _, _) => Self {
+                month: Month::ALL[month + 1],
+                day: 1,
+                ..*self
+            },
+            (false, day, _) if day > 1 => Self {
+                day: day - 1,
+                ..*self
+            },
+            (false, _, Month::January) => Self {
+                year: Year::from_astronomical(self.year.to_astronomical() - 1),
+                month: Month::December,
+                day: 31,
+            },
+            (false, _, _) => Self {
+                month: Month::ALL[month - 1],
+                day: lengths[month - 1],
+                ..*self
+            },
+        }
+    }
+}
+
+impl From<&Date> for StandardCalendar {
+    // The standard calendar has day 0 set as the GregorianCalendar's 1/1/1
+    fn from(date: &Date) -> Self {
+        // The inverse of the conversion below, with years shifted to start on March 1st.
+        let month = i128::from(u8::from(date.month));
+        let year = date.year.to_astronomical() - i128::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400); // [0, 399]
+        let month_from_march = (month + 9) % 12; // [0, 11]
+        let day_of_year = (153 * month_from_march + 2) / 5 + i128::from(date.day) - 1; // [0, 365]
+        let day_of_era = 365 * year_of_era + year_of_era / 4 - year_of_era / 100 + day_of_year; // [0, 146096]
+        let standard = StandardCalendar::new(era * Date::DAYS_IN_400_YEARS + day_of_era - 306);
+
+        #[cfg(feature = "validate")]
+        if standard.days.abs() <= Date::CHECKED_DAYS {
+            debug_assert_eq!(standard.days, date.slow_days(), "{date:?} to standard");
+        }
+        standard
+    }
+}
+
+impl From<StandardCalendar> for Date {
+    fn from(standard: StandardCalendar) -> Self {
+        // Shift _day 0_ back to March 1st of year 1 BCE, so that leap days land at the end of each shifted year.
+        let shifted = standard.days + 306;
+        let era = shifted.div_euclid(Self::DAYS_IN_400_YEARS);
+        let day_of_era = shifted.rem_euclid(Self::DAYS_IN_400_YEARS); // [0, 146096]
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365; // [0, 399]
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100); // [0, 365]
+        let month_from_march = (5 * day_of_year + 2) / 153; // [0, 11]
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        // January and February belong to the next year, since the shifted year starts in March.
+        let year = era * 400 + year_of_era + i128::from(month <= 2);
+
+        let date = Self {
+            year: Year::from_astronomical(year),
+            // Both are in range by construction.
+            month: Month::try_from(month as u8).unwrap(),
+            day: day as u8,
+        };
+
+        #[cfg(feature = "validate")]
+        if standard.days.abs() <= Self::CHECKED_DAYS {
+            debug_assert_eq!(date.slow_days(), standard.days, "{standard:?} to {date:?}");
+        }
+        date
+    }
+}
+
+impl Calendar for Date {
+    type Day = u8;
+    type Month = Month;
+    type Year = Year;
+
+    fn day(&self) -> Self::Day {
+        self.day
+    }
+
+    fn year(&self) -> Self::Year {
+        self.year
+    }
+
+    fn month(&self) -> Self::Month {
+        self.month
+    }
+
+    fn reference_date() -> Self {
+        Self {
+            year: year!(1),
+            month: Month::January,
+            day: 1,
+        }
+    }
+    fn add_days(&mut self, days: i128) {
+        #[cfg(feature = "validate")]
+        let start = *self;
+
+        *self = Self::from(StandardCalendar::new(self.as_days() + days));
+
+        #[cfg(feature = "validate")]
+        if days.abs() <= Self::CHECKED_DAYS {
+            let mut stepped = start;
+            for _ in 0..days.abs() {
+                stepped = stepped.slow_step(days > 0);
+            }
+            debug_assert_eq!(stepped, *self, "{start:?} plus {days} days");
+        }
+    }
+
+    fn as_days(&self) -> i128 {
+        // The reference date is also the standard calendar's _day 0_.
+        StandardCalendar::from(self).days
+    }
+
+    /// Returns the amount of days between `first` and `second`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    /// use time::calendar::Calendar;
+    ///
+    /// // Equal dates are 0 days apart.
+    /// assert_eq!(Date::days_between(&Date::from_parts(year!(1), Month::January, 1).unwrap(), &Date::from_parts(year!(1), Month::January, 1).unwrap()), 0);
+    /// assert_eq!(Date::days_between(&Date::from_parts(year!(1), Month::January, 2).unwrap(), &Date::from_parts(year!(1), Month::January, 1).unwrap()), 1);
+    ///
+    /// assert_eq!(Date::days_between(&Date::from_parts(year!(2), Month::January, 1).unwrap(), &Date::from_parts(year!(1), Month::January, 1).unwrap()), 365);
+    /// ```
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        let (first, second) = if first > second {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        // If they're in the same year, we just calculate the days between.
+        if first.year == second.year {
+            return i128::from(second.ordinal()) - i128::from(first.ordinal());
+        }
+
+        // How many days from Jan 1st we are on the second year.
+        let days_last_year = second.ordinal() - 1;
+
+        // How many days until Jan 1st of the year after first.
+        let days_first_year =
+            Self::cumulative_days(Self::is_leap_year(first.year))[12] - first.ordinal() + 1;
+
+        let leap_days = Self::leap_days_between(
+            &Date::from_year(first.year.next()),
+            &Date::from_year(second.year),
+        );
+        let days_other_years = if second.year - first.year > 1 {
+            (second.year - first.year - 1) * 365 + leap_days
+        } else {
+            0
+        };
+        days_other_years + days_first_year as i128 + days_last_year as i128
+    }
+
+    /// Returns whether the date is a leap year.
+    ///
+    /// Leap years represent added days to the year, in order to mantain sync with Earth's rotation.
+    /// For more information on how leap years are checked, read [`Year::is_leap_year`].
+    fn is_leap_year(year: Self::Year) -> bool {
+        year.is_leap_year()
+    }
+
+    fn months_of(_: Self::Year) -> Vec<Self::Month> {
+        Month::ALL.to_vec()
+    }
+
+    /// The days of `month` in `year`, which are all valid for [`Date::from_parts`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Calendar, date::gregorian::{Date, Month, year}};
+    ///
+    /// assert_eq!(Date::days_of(year!(2024), Month::February), 1..=29);
+    /// assert_eq!(Date::days_of(year!(2023), Month::February), 1..=28);
+    /// ```
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day> {
+        1..=Self::month_lengths(year)[month.index0()]
+    }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp_days(other)
+    }
+}
+
+impl Sub<Date> for Date {
+    type Output = i128;
+    /// A subtraction between dates is handled as the difference between them, in days.
+    ///
+    /// The result is positive when `self` is the later date, so it agrees with [`Ord`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::date;
+    /// assert_eq!(date!(2024-03-01) - date!(2024-02-28), 2);
+    /// assert_eq!(date!(2024-02-28) - date!(2024-03-01), -2);
+    ///
+    /// // Edge cases: no year 0!
+    /// assert_eq!(date!(1-01-01) - date!(-1-12-31), 1);
+    /// ```
+    fn sub(self, rhs: Date) -> Self::Output {
+        self.as_days() - rhs.as_days()
+    }
+}
+
+impl Add<i128> for Date {
+    type Output = Date;
+    /// The date `rhs` days after this one, or before it if `rhs` is negative. See [`Calendar::add_days`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::date;
+    /// assert_eq!(date!(2024-02-28) + 1, date!(2024-02-29));
+    /// assert_eq!(date!(-1-12-31) + 1, date!(1-01-01));
+    /// ```
+    fn add(mut self, rhs: i128) -> Self::Output {
+        self.add_days(rhs);
+        self
+    }
+}
+
+impl Sub<i128> for Date {
+    type Output = Date;
+    /// The date `rhs` days before this one, or after it if `rhs` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::date;
+    /// assert_eq!(date!(2024-03-01) - 1, date!(2024-02-29));
+    /// assert_eq!(date!(1-01-01) - 1, date!(-1-12-31));
+    /// ```
+    fn sub(self, rhs: i128) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl AddAssign<i128> for Date {
+    fn add_assign(&mut self, rhs: i128) {
+        self.add_days(rhs);
+    }
+}
+
+impl SubAssign<i128> for Date {
+    fn sub_assign(&mut self, rhs: i128) {
+        self.add_days(-rhs);
+    }
+}
+
+/// Representation of a year for the [Gregorian Calendar](https://en.wikipedia.org/wiki/Gregorian_calendar).
+///
+/// For creation with variables, use [`Year::new`]. For creation with literals, use the [`year`] macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Year(std::num::NonZeroI128);
+
+impl Year {
+    /// Constructor for [`Year`].
+    ///
+    /// Simply wraps the given `year` and returns a new [`Year`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::num::NonZeroI128;
+    /// use time::date::gregorian;
+    /// let some_year = gregorian::Year::new(NonZeroI128::new(1528).unwrap());
+    /// ```
+    pub const fn new(year: std::num::NonZeroI128) -> Self {
+        Self(year)
+    }
+
+    /// Returns whether this is a leap year.
+    ///
+    /// In the [Gregorian Calendar](https://en.wikipedia.org/wiki/Gregorian_calendar), a leap year happens in all years that
+    /// are divisible by 4, except by those that are divisible by 100, except in turn those that are divisible by 400.
+    ///
+    /// Years before the common era follow the same rule applied to their [astronomical](Year::to_astronomical) number,
+    /// so 1 BCE, 5 BCE, 401 BCE and so on are leap years.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::{Calendar, date::gregorian};
+    /// assert!(gregorian::year!(2020).is_leap_year());
+    /// assert!(gregorian::year!(2000).is_leap_year());
+    ///
+    /// assert!(!gregorian::year!(1900).is_leap_year());
+    /// assert!(!gregorian::year!(2017).is_leap_year());
+    /// assert!(!gregorian::year!(2018).is_leap_year());
+    ///
+    /// // BCE years
+    /// assert!(gregorian::year!(-1).is_leap_year());
+    /// assert!(gregorian::year!(-401).is_leap_year());
+    /// assert!(!gregorian::year!(-4).is_leap_year());
+    /// assert!(!gregorian::year!(-101).is_leap_year());
+    /// # Ok::<(),std::num::IntErrorKind>(())
+    /// ```
+    pub const fn is_leap_year(&self) -> bool {
+        let inner = self.to_astronomical();
+        inner % 4 == 0 && ((inner % 400 == 0) || inner % 100 != 0)
+    }
+
+    /// Returns this year in [astronomical year numbering](https://en.wikipedia.org/wiki/Astronomical_year_numbering).
+    ///
+    /// Astronomical numbering keeps CE years as they are, but counts 1 BCE as year 0, 2 BCE as year -1 and so on,
+    /// making it possible to use regular arithmetic on years.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(2024).to_astronomical(), 2024);
+    /// assert_eq!(year!(-1).to_astronomical(), 0);
+    /// assert_eq!(year!(-44).to_astronomical(), -43);
+    /// ```
+    pub const fn to_astronomical(&self) -> i128 {
+        let inner = self.0.get();
+        if inner < 0 { inner + 1 } else { inner }
+    }
+
+    /// Creates a [`Year`] from its [astronomical](Year::to_astronomical) number.
+    ///
+    /// # Panics
+    /// If `year` is [`i128::MIN`], which would be the year before the earliest [`Year`]. See
+    /// [`checked_from_astronomical`](Year::checked_from_astronomical) for years that may be.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::{Year, year};
+    /// assert_eq!(Year::from_astronomical(2024), year!(2024));
+    /// assert_eq!(Year::from_astronomical(0), year!(-1));
+    /// assert_eq!(Year::from_astronomical(-43), year!(-44));
+    /// ```
+    pub const fn from_astronomical(year: i128) -> Self {
+        match Self::checked_from_astronomical(year) {
+            Some(year) => year,
+            None => panic!("the astronomical year i128::MIN has no Year"),
+        }
+    }
+
+    /// Creates a [`Year`] from its [astronomical](Year::to_astronomical) number, unless it is [`i128::MIN`], which
+    /// would be the year before the earliest [`Year`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::{Year, year};
+    /// assert_eq!(Year::checked_from_astronomical(-43), Some(year!(-44)));
+    /// assert_eq!(Year::checked_from_astronomical(i128::MIN), None);
+    /// ```
+    pub const fn checked_from_astronomical(year: i128) -> Option<Self> {
+        let year = if year <= 0 {
+            let Some(year) = year.checked_sub(1) else {
+                return None;
+            };
+            year
+        } else {
+            year
+        };
+        // Safety: non-positive values were shifted down by one, so `year` is never 0.
+        Some(unsafe { Self(NonZeroI128::new_unchecked(year)) })
+    }
+
+    /// Which decade this year is in.
+    ///
+    /// Decades are counted like [centuries](Year::century): years 1 to 10 are the 1st decade, 1991 to 2000 are the
+    /// 200th, and years 1 BCE to 10 BCE are decade -1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(1995).decade(), 200);
+    /// assert_eq!(year!(2000).decade(), 200);
+    /// assert_eq!(year!(2001).decade(), 201);
+    /// assert_eq!(year!(-5).decade(), -1);
+    /// ```
+    pub fn decade(&self) -> i128 {
+        self.bucket(10)
+    }
+
+    /// Which century this year is in.
+    ///
+    /// This follows the historical convention: since there is no year 0, the 1st century is years 1 to 100, which
+    /// means the 20th century is 1901 to 2000. Before the common era, centuries count backwards, so years 1 BCE to
+    /// 100 BCE are the 1st century BCE, represented as -1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(1901).century(), 20);
+    /// assert_eq!(year!(2000).century(), 20);
+    /// assert_eq!(year!(2001).century(), 21);
+    /// assert_eq!(year!(-5).century(), -1);
+    /// assert_eq!(year!(-101).century(), -2);
+    /// ```
+    pub fn century(&self) -> i128 {
+        self.bucket(100)
+    }
+
+    /// Which millennium this year is in, counted like [centuries](Year::century).
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(2000).millennium(), 2);
+    /// assert_eq!(year!(2001).millennium(), 3);
+    /// assert_eq!(year!(-1000).millennium(), -1);
+    /// ```
+    pub fn millennium(&self) -> i128 {
+        self.bucket(1000)
+    }
+
+    /// The chronologically first year of this year's [decade](Year::decade).
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(1995).decade_start(), year!(1991));
+    /// assert_eq!(year!(-5).decade_start(), year!(-10));
+    /// ```
+    pub fn decade_start(&self) -> Self {
+        self.bucket_start(10)
+    }
+
+    /// The chronologically first year of this year's [century](Year::century).
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(1995).century_start(), year!(1901));
+    /// assert_eq!(year!(2000).century_start(), year!(1901));
+    /// assert_eq!(year!(-5).century_start(), year!(-100));
+    /// ```
+    pub fn century_start(&self) -> Self {
+        self.bucket_start(100)
+    }
+
+    /// The chronologically first year of this year's [millennium](Year::millennium).
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(1995).millennium_start(), year!(1001));
+    /// assert_eq!(year!(-5).millennium_start(), year!(-1000));
+    /// ```
+    pub fn millennium_start(&self) -> Self {
+        self.bucket_start(1000)
+    }
+
+    /// Which bucket of `size` years this year falls in, counting from 1 away from the nonexistent year 0.
+    fn bucket(&self, size: i128) -> i128 {
+        let inner = self.0.get();
+        let ordinal = (inner.unsigned_abs() - 1) / size as u128 + 1;
+        ordinal as i128 * inner.signum()
+    }
+
+    /// The chronologically first year of the [`bucket`](Year::bucket) this year falls in.
+    fn bucket_start(&self, size: i128) -> Self {
+        let bucket = self.bucket(size);
+        let start = if bucket > 0 {
+            (bucket - 1) * size + 1
+        } else {
+            bucket * size
+        };
+        // Safety: the start is either at least 1 or at most `-size`.
+        unsafe { Self(NonZeroI128::new_unchecked(start)) }
+    }
+
+    pub fn next(self) -> Self {
+        match self.0.get() {
+            -1 => {
+                year!(1)
+            }
+            // Safety: We already handled the case where the year + 1 would be 0.
+            other => unsafe { Self(NonZeroI128::new_unchecked(other + 1)) },
+        }
+    }
+}
+
+impl TryFrom<i128> for Year {
+    type Error = std::num::IntErrorKind;
+    fn try_from(year: i128) -> Result<Self, Self::Error> {
+        let year = NonZeroI128::new(year).ok_or(std::num::IntErrorKind::Zero)?;
+        Ok(Year::new(year))
+    }
+}
+
+impl Sub<Year> for Year {
+    type Output = i128;
+    /// A subtraction between years is handled as the difference between them.
+    ///
+    /// That is, how many [Year]s 'apart' they are.
+    ///
+    /// Since there is no _year 0_, this is **not** equivalent to `i128 - i128`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(1528) - year!(1528), 0);
+    /// assert_eq!(year!(1528) - year!(1527), 1);
     ///
     /// // Edge cases: no year 0!
     /// assert_eq!(year!(1) - year!(-1), 1);
@@ -342,161 +1355,1533 @@ impl Sub<Year> for Year {
         } else if self.0.is_negative() && rhs.0.is_positive() {
             return diff + 1;
         }
-        diff
+        diff
+    }
+}
+
+impl fmt::Display for Year {
+    /// Writes the year with a `BCE` suffix before the common era, such as "500 BCE", and as a bare number
+    /// otherwise. The alternate form (`{:#}`) writes years before the common era as negative numbers instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use time::date::gregorian::year;
+    /// assert_eq!(year!(2024).to_string(), "2024");
+    /// assert_eq!(year!(-500).to_string(), "500 BCE");
+    /// assert_eq!(format!("{:#}", year!(-500)), "-500");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let year = self.0.get();
+        if year < 0 && !f.alternate() {
+            write!(f, "{} BCE", year.unsigned_abs())
+        } else {
+            write!(f, "{year}")
+        }
+    }
+}
+
+/// Writes an [astronomical](Year::to_astronomical) year with at least 4 digits, as in ISO 8601, so that 1 BCE is
+/// "0000" and 44 BCE is "-0043".
+pub(crate) struct IsoYear(pub(crate) i128);
+
+impl From<Year> for IsoYear {
+    fn from(year: Year) -> Self {
+        Self(year.to_astronomical())
+    }
+}
+
+impl fmt::Display for IsoYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The sign doesn't count toward the digits.
+        let sign = if self.0 < 0 { "-" } else { "" };
+        write!(f, "{sign}{:04}", self.0.unsigned_abs())
+    }
+}
+
+/// Era markers accepted by [`Year::from_str`], and whether they are before the common era.
+///
+/// Markers that end with others go first, so that "BCE" isn't read as "B" followed by "CE".
+const ERAS: [(&str, bool); 8] = [
+    ("B.C.E.", true),
+    ("B.C.", true),
+    ("BCE", true),
+    ("BC", true),
+    ("C.E.", false),
+    ("A.D.", false),
+    ("CE", false),
+    ("AD", false),
+];
+
+impl FromStr for Year {
+    type Err = errors::YearParseError;
+
+    /// Reads a year either as a signed number, like "-500", or as a number with an era before or after it, like
+    /// "500 BC" or "AD 14". Era markers are matched ignoring case.
+    ///
+    /// # Errors
+    /// * [`YearParseError::Zero`](errors::YearParseError::Zero) if the year is 0.
+    /// * [`YearParseError::Invalid`](errors::YearParseError::Invalid) if `input` is not a year, or has both a
+    ///   sign and an era.
+    /// * [`YearParseError::OutOfRange`](errors::YearParseError::OutOfRange) if the year is before [`Date::MIN`] or
+    ///   after [`Date::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Year, errors::YearParseError, year};
+    ///
+    /// assert_eq!("2024".parse(), Ok(year!(2024)));
+    /// assert_eq!("-500".parse(), Ok(year!(-500)));
+    /// assert_eq!("500 BCE".parse(), Ok(year!(-500)));
+    /// assert_eq!("AD 14".parse(), Ok(year!(14)));
+    /// assert_eq!("0".parse::<Year>(), Err(YearParseError::Zero));
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let marked = ERAS.iter().find_map(|(marker, before_common_era)| {
+            let split = input.len().checked_sub(marker.len())?;
+            let (number, suffix) = (input.get(..split)?, input.get(split..)?);
+            let (prefix, rest) = (input.get(..marker.len())?, input.get(marker.len()..)?);
+            if suffix.eq_ignore_ascii_case(marker) {
+                Some((number, *before_common_era))
+            } else if prefix.eq_ignore_ascii_case(marker) {
+                Some((rest, *before_common_era))
+            } else {
+                None
+            }
+        });
+
+        let year = match marked {
+            Some((number, before_common_era)) => {
+                let number = number.trim();
+                if number.starts_with(['+', '-']) {
+                    return Err(errors::YearParseError::Invalid);
+                }
+                let year: i128 = number
+                    .parse()
+                    .map_err(|_| errors::YearParseError::Invalid)?;
+                if before_common_era { -year } else { year }
+            }
+            None => input.parse().map_err(|_| errors::YearParseError::Invalid)?,
+        };
+        let year = Year::try_from(year).map_err(|_| errors::YearParseError::Zero)?;
+        if !(Date::MIN.year()..=Date::MAX.year()).contains(&year) {
+            return Err(errors::YearParseError::OutOfRange);
+        }
+        Ok(year)
+    }
+}
+
+/// Counts the leap years from `first`, inclusive, to `last`, exclusive, skipping the nonexistent year 0.
+///
+/// Nothing is counted if `last` is not after `first`, and counts too large for a [`u64`] saturate at
+/// [`u64::MAX`]. Leap years before the common era follow [`Year::is_leap_year`], so 1 BCE and 401 BCE are both
+/// counted.
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{leap_years_in_range, year};
+///
+/// assert_eq!(leap_years_in_range(year!(2000), year!(2024)), 6);
+/// assert_eq!(leap_years_in_range(year!(2000), year!(2025)), 7);
+/// // 1900 is not a leap year.
+/// assert_eq!(leap_years_in_range(year!(1897), year!(1905)), 1);
+/// // Crossing into the common era: 5 BCE and 1 BCE are leap years, 1 CE is not.
+/// assert_eq!(leap_years_in_range(year!(-5), year!(2)), 2);
+/// assert_eq!(leap_years_in_range(year!(2024), year!(2000)), 0);
+/// ```
+pub fn leap_years_in_range(first: Year, last: Year) -> u64 {
+    u64::try_from(leap_years_between(first, last).max(0)).unwrap_or(u64::MAX)
+}
+
+/// Like [`leap_years_in_range`], but negative if `last` is before `first`, and without saturating.
+fn leap_years_between(first: Year, last: Year) -> i128 {
+    // Leap years in (0, `year`] for positive `year`, or minus those in (`year`, 0] otherwise.
+    fn leap_years_to(year: i128) -> i128 {
+        year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+    }
+    // In astronomical numbering, [first, last) is the same as (first - 1, last - 1].
+    leap_years_to(last.to_astronomical() - 1) - leap_years_to(first.to_astronomical() - 1)
+}
+
+/// Macro for creating a [`Year`] from a literal.
+///
+/// Saves from having to create a [`NonZeroI128`] or using the fallible [`TryFrom`] implementation. The year is
+/// built in a `const`, so it can be used in `const` contexts too.
+///
+/// # Examples
+/// ```
+/// // Works!
+/// use time::date::gregorian;
+///
+/// assert_eq!(gregorian::year!(1), gregorian::Year::try_from(1).unwrap());
+/// assert_eq!(gregorian::year!(2020), gregorian::Year::try_from(2020).unwrap());
+/// assert_eq!(gregorian::year!(1528), gregorian::Year::try_from(1528).unwrap());
+/// assert_eq!(gregorian::year!(-1), gregorian::Year::try_from(-1).unwrap()); // Works with negative years too!
+/// ```
+/// Will not compile if the given year is 0.
+/// ```compile_fail
+/// # use time::date::gregorian;
+/// gregorian::year!(0);
+/// ```
+/// Will not compile if the given argument is not a number.
+/// ```compile_fail
+/// # use time::date::gregorian;
+/// gregorian::year!("hi");
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! year {
+    (0) => {
+        compile_error!("Year provided to this macro must not be zero.").
+    };
+    ($year:literal) => {{
+        const YEAR: $crate::date::gregorian::Year =
+            $crate::date::gregorian::Year::new(match std::num::NonZeroI128::new($year) {
+                Some(year) => year,
+                None => panic!("Year provided to this macro must not be zero."),
+            });
+        YEAR
+    }};
+}
+#[doc(inline)]
+pub use year;
+
+/// Macro for creating a [`Date`] from a literal, written as `year-month-day`.
+///
+/// The date is checked at compile time, like with [`Date::from_parts_const`].
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, date, year};
+///
+/// assert_eq!(date!(2008-04-22), Date::from_parts(year!(2008), Month::April, 22).unwrap());
+/// assert_eq!(date!(-44-03-15), Date::from_parts(year!(-44), Month::March, 15).unwrap());
+///
+/// const EPOCH: Date = date!(1970-01-01);
+/// # let _ = EPOCH;
+/// ```
+/// Will not compile if the date doesn't exist.
+/// ```compile_fail
+/// # use time::date::gregorian;
+/// gregorian::date!(1900-02-29);
+/// ```
+/// ```compile_fail
+/// # use time::date::gregorian;
+/// gregorian::date!(2024-13-01);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! date {
+    ($year:literal - $month:literal - $day:literal) => {{
+        // Months and days are usually written with a leading zero.
+        #[allow(clippy::zero_prefixed_literal)]
+        const DATE: $crate::date::gregorian::Date = $crate::date::gregorian::Date::from_parts_const(
+            $crate::year!($year),
+            match $crate::date::gregorian::Month::from_number($month) {
+                Ok(month) => month,
+                Err(_) => panic!("Month provided to this macro must be from 1 to 12."),
+            },
+            $day,
+        );
+        DATE
+    }};
+}
+#[doc(inline)]
+pub use date;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Month {
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+/// How [`Date::from_parts_clamped`] and [`Date::from_parts_rollover`] changed the day they were given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayAdjustment {
+    /// The day existed in the month, and was kept.
+    Unchanged,
+    /// The day was moved to the first or last day of the month.
+    Clamped,
+    /// The day was counted into another month.
+    RolledOver,
+}
+
+/// An [ISO 8601 week](https://en.wikipedia.org/wiki/ISO_week_date), as given by [`Date::iso_week`].
+///
+/// Its [`Display`](fmt::Display) writes the week like "2023-W41", with the year [astronomical](Year::to_astronomical)
+/// and of at least 4 digits, as in ISO 8601.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IsoWeek {
+    /// The year the week's Thursday is in, which can differ from the year of the days around New Year.
+    pub year: Year,
+    /// The week of the year, from 1 to 52 or 53.
+    pub week: u8,
+}
+
+impl fmt::Display for IsoWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-W{:02}", IsoYear::from(self.year), self.week)
+    }
+}
+
+/// An amount of years, months and days to move a date by, with [`Date::apply_offset`].
+///
+/// Each part can be negative on its own. Its [`Display`](fmt::Display) writes the short form read by
+/// [`parse_offset`](crate::parse::parse_offset), like "+3y2m10d" or "-15d".
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::DateOffset;
+///
+/// assert_eq!(DateOffset { years: 3, months: 2, days: 10 }.to_string(), "+3y2m10d");
+/// assert_eq!(DateOffset { years: 1, months: -2, days: 0 }.to_string(), "+1y-2m");
+/// assert_eq!(DateOffset::default().to_string(), "+0d");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DateOffset {
+    /// Years, applied as 12 months each.
+    pub years: i128,
+    /// Months, applied together with the years before the days; the day is clamped to the month's length.
+    pub months: i128,
+    /// Days, applied after the years and months.
+    pub days: i128,
+}
+
+impl fmt::Display for DateOffset {
+    /// Writes each part that isn't 0 with its unit, `y`, `m` or `d`. A sign is only written where it changes, since
+    /// it applies to the parts after it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut negative = None;
+        for (value, unit) in [(self.years, 'y'), (self.months, 'm'), (self.days, 'd')] {
+            if value == 0 {
+                continue;
+            }
+            if negative != Some(value < 0) {
+                f.write_str(if value < 0 { "-" } else { "+" })?;
+                negative = Some(value < 0);
+            }
+            write!(f, "{}{unit}", value.unsigned_abs())?;
+        }
+        if negative.is_none() {
+            f.write_str("+0d")?;
+        }
+        Ok(())
+    }
+}
+
+/// How far apart two dates can be for [`Date::within`] to consider them the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeTolerance {
+    Days(u64),
+    /// Calendar months, so that a month after January 31st is the last day of February.
+    Months(u64),
+    /// Calendar years, of 12 months each.
+    Years(u64),
+}
+
+/// How [`Date::checked_add_days_with`] handles moving across the year 0, which doesn't exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DateArithmeticPolicy {
+    /// Steps over the year 0, so that December 31st of 1 BCE is followed by January 1st of 1 CE.
+    #[default]
+    SkipYearZero,
+    /// Rejects results on the other side of the year 0 from the start, for data where crossing it is likely a
+    /// mistake, such as mixing astronomical and historical year numbers.
+    Strict,
+}
+
+/// Which occurrence of a weekday inside a month to pick, used by [`Date::nth_weekday_of_month`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occurrence {
+    /// The `n`th occurrence, starting from 1.
+    Nth(u8),
+    /// The last occurrence, whether it is the fourth or the fifth.
+    Last,
+}
+
+impl Month {
+    /// The month numbered `number`, from 1 for January to 12 for December.
+    ///
+    /// This is the same as [`Month::try_from`], but usable in `const` contexts.
+    ///
+    /// # Errors
+    /// [`DateCreationError::InvalidMonth`](errors::DateCreationError::InvalidMonth) if `number` is not from 1 to 12.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::Month;
+    ///
+    /// const APRIL: Month = match Month::from_number(4) {
+    ///     Ok(month) => month,
+    ///     Err(_) => panic!(),
+    /// };
+    /// assert_eq!(APRIL, Month::April);
+    /// assert!(Month::from_number(13).is_err());
+    /// ```
+    pub const fn from_number(number: u8) -> Result<Self, errors::DateCreationError> {
+        Ok(match number {
+            1 => Self::January,
+            2 => Self::February,
+            3 => Self::March,
+            4 => Self::April,
+            5 => Self::May,
+            6 => Self::June,
+            7 => Self::July,
+            8 => Self::August,
+            9 => Self::September,
+            10 => Self::October,
+            11 => Self::November,
+            12 => Self::December,
+            other => return Err(errors::DateCreationError::InvalidMonth(other)),
+        })
+    }
+
+    /// The month at `index` counting from 0 for January, as in tables of months such as [`Month::ALL`].
+    ///
+    /// Returns [`None`] if `index` is 12 or more.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::Month;
+    ///
+    /// assert_eq!(Month::from_index0(0), Some(Month::January));
+    /// assert_eq!(Month::from_index0(12), None);
+    /// ```
+    pub const fn from_index0(index: u8) -> Option<Self> {
+        if index < 12 {
+            Some(Self::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Where this month is in tables of months such as [`Month::ALL`], counting from 0 for January.
+    ///
+    /// Use [`u8::from`] for the month's number, which counts from 1 instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::Month;
+    ///
+    /// assert_eq!(Month::January.index0(), 0);
+    /// assert_eq!(Month::ALL[Month::May.index0()], Month::May);
+    /// ```
+    pub const fn index0(self) -> usize {
+        self as usize - 1
+    }
+
+    /// All months, from January to December.
+    pub const ALL: [Month; 12] = [
+        Self::January,
+        Self::February,
+        Self::March,
+        Self::April,
+        Self::May,
+        Self::June,
+        Self::July,
+        Self::August,
+        Self::September,
+        Self::October,
+        Self::November,
+        Self::December,
+    ];
+
+    /// The English name of this month.
+    pub fn name(self) -> &'static str {
+        self.name_in(Locale::En)
+    }
+
+    /// The English abbreviation of this month's name.
+    pub fn abbreviation(self) -> &'static str {
+        self.abbreviation_in(Locale::En)
+    }
+
+    /// The name of this month in the given `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{date::gregorian::Month, locale::Locale};
+    ///
+    /// assert_eq!(Month::March.name_in(Locale::PtBr), "março");
+    /// assert_eq!(Month::March.name_in(Locale::De), "März");
+    /// ```
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        locale.names().months[self.index0()]
+    }
+
+    /// The abbreviation of this month's name in the given `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{date::gregorian::Month, locale::Locale};
+    ///
+    /// assert_eq!(Month::February.abbreviation_in(Locale::Fr), "févr.");
+    /// ```
+    pub fn abbreviation_in(self, locale: Locale) -> &'static str {
+        locale.names().month_abbreviations[self.index0()]
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = errors::DateCreationError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_number(value)
+    }
+}
+
+/// Like `TryFrom<u8>`, but numbers that don't fit in a [`u8`] are reported as
+/// [`InvalidMonth(u8::MAX)`](errors::DateCreationError::InvalidMonth).
+impl TryFrom<u32> for Month {
+    type Error = errors::DateCreationError;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        u8::try_from(value).map_or(
+            Err(errors::DateCreationError::InvalidMonth(u8::MAX)),
+            Self::from_number,
+        )
+    }
+}
+
+/// Like `TryFrom<u8>`, but numbers that don't fit in a [`u8`] are reported as
+/// [`InvalidMonth(u8::MAX)`](errors::DateCreationError::InvalidMonth).
+impl TryFrom<i32> for Month {
+    type Error = errors::DateCreationError;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        u8::try_from(value).map_or(
+            Err(errors::DateCreationError::InvalidMonth(u8::MAX)),
+            Self::from_number,
+        )
+    }
+}
+
+/// The number of the month, from 1 for January to 12 for December, the reverse of [`Month::from_number`].
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::Month;
+///
+/// assert_eq!(u8::from(Month::January), 1);
+/// assert_eq!(u8::from(Month::December), 12);
+/// ```
+impl From<Month> for u8 {
+    fn from(month: Month) -> Self {
+        month as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Calendar, StandardCalendar, Weekday,
+        date::gregorian::{
+            Date, DateArithmeticPolicy, DateOffset, DayAdjustment, Month, Occurrence,
+            TimeTolerance, Year, errors,
+        },
+        weekday::WeekendDef,
+    };
+
+    use super::errors::DateCreationError;
+
+    #[test]
+    fn days_between() -> Result<(), DateCreationError> {
+        // Months
+        assert_eq!(
+            Date::days_between(
+                &Date::reference_date(),
+                &Date::from_parts(year!(2), Month::February, 1)?
+            ),
+            396
+        );
+
+        // With leap year in between
+        assert_eq!(
+            Date::days_between(
+                &Date::from_parts(year!(2020), Month::February, 22)?,
+                &Date::from_parts(year!(2021), Month::March, 22)?
+            ),
+            394
+        );
+
+        // With years in between
+        assert_eq!(
+            Date::days_between(
+                &Date::from_parts(year!(2019), Month::February, 22)?,
+                &Date::from_parts(year!(2021), Month::March, 22)?
+            ),
+            394 + 365
+        );
+
+        // Negative Years
+        assert_eq!(
+            Date::days_between(
+                &Date::reference_date(),
+                &Date::from_parts(year!(-1), Month::December, 31)?
+            ),
+            1
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn into_standard_calendar() -> Result<(), DateCreationError> {
+        // Day 0
+        assert_eq!(
+            StandardCalendar::from(&Date::from_parts(year!(1), Month::January, 1)?),
+            StandardCalendar::new(0)
+        );
+
+        assert_eq!(
+            StandardCalendar::from(&Date::from_parts(year!(-1), Month::December, 31)?),
+            StandardCalendar::new(-1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_standard_calendar() -> Result<(), DateCreationError> {
+        assert_eq!(Date::from(StandardCalendar::new(0)), Date::reference_date());
+        assert_eq!(
+            Date::from(StandardCalendar::new(-1)),
+            Date::from_parts(year!(-1), Month::December, 31)?
+        );
+        // 1 BCE is a leap year.
+        assert_eq!(
+            Date::from(StandardCalendar::new(-307)),
+            Date::from_parts(year!(-1), Month::February, 29)?
+        );
+        assert_eq!(
+            Date::from(StandardCalendar::new(730_178)),
+            Date::from_parts(year!(2000), Month::February, 29)?
+        );
+
+        // Round trips around the era boundary and across several 400-year cycles.
+        for days in (-1_000..1_000).chain((-800_000..800_000).step_by(997)) {
+            let standard = StandardCalendar::new(days);
+            assert_eq!(StandardCalendar::from(&Date::from(standard)), standard);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn tolerances() {
+        use TimeTolerance::{Days, Months, Years};
+
+        // (first, second, tolerance, within)
+        let cases = [
+            (date!(2024 - 01 - 01), date!(2024 - 01 - 01), Days(0), true),
+            (date!(-1 - 12 - 31), date!(1 - 01 - 01), Days(1), true),
+            (date!(-1 - 12 - 31), date!(1 - 01 - 02), Days(1), false),
+            (
+                date!(2024 - 01 - 15),
+                date!(2024 - 02 - 15),
+                Months(1),
+                true,
+            ),
+            (
+                date!(2024 - 01 - 15),
+                date!(2024 - 02 - 16),
+                Months(1),
+                false,
+            ),
+            // A month from January 31st ends on the last day of February, which is fewer than 30 days.
+            (
+                date!(2023 - 01 - 31),
+                date!(2023 - 02 - 28),
+                Months(1),
+                true,
+            ),
+            (
+                date!(2023 - 01 - 31),
+                date!(2023 - 03 - 01),
+                Months(1),
+                false,
+            ),
+            // Counted from the earlier date, whose month has 30 days.
+            (
+                date!(2024 - 12 - 30),
+                date!(2023 - 11 - 30),
+                Months(13),
+                true,
+            ),
+            (
+                date!(2024 - 12 - 31),
+                date!(2023 - 11 - 30),
+                Months(13),
+                false,
+            ),
+            // There is no year 0, so a year from 1 BCE is 1 CE.
+            (date!(-1 - 06 - 01), date!(1 - 06 - 01), Years(1), true),
+            (date!(-1 - 06 - 01), date!(1 - 06 - 02), Years(1), false),
+            // 2024 is a leap year, and 366 days long.
+            (date!(2024 - 01 - 01), date!(2025 - 01 - 01), Years(1), true),
+            (
+                date!(2024 - 01 - 01),
+                date!(2025 - 01 - 01),
+                Days(365),
+                false,
+            ),
+            (date!(2024 - 02 - 29), date!(2025 - 02 - 28), Years(1), true),
+            (
+                date!(2024 - 02 - 29),
+                date!(2025 - 03 - 01),
+                Years(1),
+                false,
+            ),
+            (Date::MIN, Date::MAX, Years(u64::MAX), false),
+            (Date::MIN, Date::MAX, Days(u64::MAX), false),
+        ];
+        for (first, second, tolerance, within) in cases {
+            assert_eq!(
+                first.within(&second, tolerance),
+                within,
+                "{first:?} {second:?} {tolerance:?}"
+            );
+            assert_eq!(second.within(&first, tolerance), within);
+            if let Days(days) = tolerance {
+                assert_eq!(first.within_days(&second, days), within);
+            }
+        }
+    }
+
+    #[test]
+    fn cumulative_tables() {
+        for (leap, lengths, cumulative) in [
+            (false, Date::REG_DAYS_IN_MONTH, Date::CUMULATIVE_REG),
+            (true, Date::LEAP_DAYS_IN_MONTH, Date::CUMULATIVE_LEAP),
+        ] {
+            for month in 0..=12 {
+                let sum: u16 = lengths[..month].iter().map(|days| u16::from(*days)).sum();
+                assert_eq!(cumulative[month], sum, "{leap} {month}");
+            }
+
+            let mut ordinal = 0;
+            for (month, length) in Month::ALL.into_iter().zip(lengths) {
+                assert_eq!(Date::days_before_month(month, leap), ordinal);
+                for _ in 0..length {
+                    ordinal += 1;
+                    assert_eq!(
+                        Date::month_of_ordinal(ordinal, leap),
+                        Some(month),
+                        "{ordinal}"
+                    );
+                }
+            }
+            assert_eq!(Date::month_of_ordinal(0, leap), None);
+            assert_eq!(Date::month_of_ordinal(ordinal + 1, leap), None);
+            assert_eq!(Date::month_of_ordinal(u16::MAX, leap), None);
+        }
+    }
+
+    #[test]
+    fn checked_add_days() {
+        use DateArithmeticPolicy::{SkipYearZero, Strict};
+
+        // (start, days, result with SkipYearZero, whether Strict rejects it)
+        let cases = [
+            (date!(-1 - 12 - 31), 1, date!(1 - 01 - 01), true),
+            // 1 BCE is a leap year.
+            (date!(-1 - 01 - 01), 366, date!(1 - 01 - 01), true),
+            (date!(-1 - 01 - 01), 365, date!(-1 - 12 - 31), false),
+            (date!(-2 - 12 - 31), 1, date!(-1 - 01 - 01), false),
+            (date!(2024 - 02 - 28), 1, date!(2024 - 02 - 29), false),
+            (date!(2024 - 01 - 01), 0, date!(2024 - 01 - 01), false),
+        ];
+        for (start, days, result, crosses_zero) in cases {
+            assert_eq!(
+                start.checked_add_days_with(days, SkipYearZero),
+                Some(result),
+                "{start:?} + {days}"
+            );
+            assert_eq!(start.checked_add_days(days), Some(result));
+            assert_eq!(
+                start.checked_add_days_with(days, Strict),
+                (!crosses_zero).then_some(result),
+                "{start:?} + {days}"
+            );
+            // Subtracting mirrors adding.
+            assert_eq!(
+                result.checked_add_days_with(-days, SkipYearZero),
+                Some(start),
+                "{result:?} - {days}"
+            );
+            assert_eq!(
+                result.checked_add_days_with(-days, Strict),
+                (!crosses_zero).then_some(start),
+                "{result:?} - {days}"
+            );
+            assert_eq!(start.checked_add_days(days), Some(start + days));
+        }
+
+        for policy in [SkipYearZero, Strict] {
+            assert_eq!(Date::MAX.checked_add_days_with(0, policy), Some(Date::MAX));
+            assert_eq!(Date::MIN.checked_add_days_with(0, policy), Some(Date::MIN));
+            for days in [i128::MIN, i128::MAX] {
+                assert_eq!(Date::MAX.checked_add_days_with(days, policy), None);
+                assert_eq!(Date::MIN.checked_add_days_with(days, policy), None);
+            }
+        }
+        assert_eq!(
+            Date::MIN.checked_add_days(Date::MAX.as_days() - Date::MIN.as_days()),
+            Some(Date::MAX)
+        );
+    }
+
+    #[test]
+    fn add_days() -> Result<(), DateCreationError> {
+        let mut date = Date::from_parts(year!(-1), Month::December, 31)?;
+        date.add_days(1);
+        assert_eq!(date, Date::reference_date());
+        date.add_days(-366);
+        assert_eq!(date, Date::from_parts(year!(-1), Month::January, 1)?);
+
+        let mut date = Date::from_parts(year!(2024), Month::February, 28)?;
+        date.add_days(1);
+        assert_eq!(date, Date::from_parts(year!(2024), Month::February, 29)?);
+        assert_eq!(date.as_days(), 738_944);
+
+        Ok(())
+    }
+
+    #[test]
+    fn age_at() -> Result<(), DateCreationError> {
+        let leap_birth = Date::from_parts(year!(2000), Month::February, 29)?;
+        assert_eq!(leap_birth.age_at(&leap_birth), 0);
+        assert_eq!(
+            leap_birth.age_at(&Date::from_parts(year!(2001), Month::February, 28)?),
+            0
+        );
+        assert_eq!(
+            leap_birth.age_at(&Date::from_parts(year!(2001), Month::March, 1)?),
+            1
+        );
+        assert_eq!(
+            leap_birth.age_at(&Date::from_parts(year!(2004), Month::February, 29)?),
+            4
+        );
+
+        // Across the era boundary.
+        let birth = Date::from_parts(year!(-5), Month::June, 1)?;
+        assert_eq!(
+            birth.age_at(&Date::from_parts(year!(6), Month::June, 1)?),
+            10
+        );
+        assert_eq!(
+            birth.age_at(&Date::from_parts(year!(6), Month::May, 31)?),
+            9
+        );
+        assert_eq!(
+            birth.age_at(&Date::from_parts(year!(-1), Month::June, 1)?),
+            4
+        );
+
+        // Before the date itself.
+        assert_eq!(
+            birth.age_at(&Date::from_parts(year!(-7), Month::June, 2)?),
+            -1
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_anniversary_after() -> Result<(), DateCreationError> {
+        let leap_birth = Date::from_parts(year!(2000), Month::February, 29)?;
+        assert_eq!(
+            leap_birth.next_anniversary_after(&leap_birth),
+            Date::from_parts(year!(2001), Month::March, 1)?
+        );
+        assert_eq!(
+            leap_birth.next_anniversary_after(&Date::from_parts(year!(2003), Month::March, 1)?),
+            Date::from_parts(year!(2004), Month::February, 29)?
+        );
+        let before = Date::from_parts(year!(1999), Month::January, 1)?;
+        assert_eq!(leap_birth.next_anniversary_after(&before), leap_birth);
+
+        // Same-day anniversaries move to the following year, skipping year 0.
+        let date = Date::from_parts(year!(-1), Month::July, 4)?;
+        assert_eq!(
+            date.next_anniversary_after(&date),
+            Date::from_parts(year!(1), Month::July, 4)?
+        );
+
+        Ok(())
     }
-}
 
-/// Macro for creating a [`Year`] from a literal.
-///
-/// Saves from having to create a [`NonZeroI128`] or using the fallible [`TryFrom`] implementation.
-///
-/// # Examples
-/// ```
-/// // Works!
-/// use time::date::gregorian;
-///
-/// assert_eq!(gregorian::year!(1), gregorian::Year::try_from(1).unwrap());
-/// assert_eq!(gregorian::year!(2020), gregorian::Year::try_from(2020).unwrap());
-/// assert_eq!(gregorian::year!(1528), gregorian::Year::try_from(1528).unwrap());
-/// assert_eq!(gregorian::year!(-1), gregorian::Year::try_from(-1).unwrap()); // Works with negative years too!
-/// ```
-/// Will not compile if the given year is 0.
-/// ```compile_fail
-/// # use time::date::gregorian;
-/// gregorian::year!(0);
-/// ```
-/// Will not compile if the given argument is not a number.
-/// ```compile_fail
-/// # use time::date::gregorian;
-/// gregorian::year!("hi");
-/// ```
-#[doc(hidden)]
-#[macro_export]
-macro_rules! year {
-    (0) => {
-        compile_error!("Year provided to this macro must not be zero.").
-    };
-    ($year:literal) => {
-        {let year: i128 = $year;
-            unsafe {$crate::date::gregorian::Year::new(std::num::NonZeroI128::new_unchecked(year))}
+    #[test]
+    fn weekday() -> Result<(), DateCreationError> {
+        assert_eq!(Date::reference_date().weekday(), Weekday::Monday);
+        assert_eq!(
+            Date::from_parts(year!(-1), Month::December, 31)?.weekday(),
+            Weekday::Sunday
+        );
+        assert_eq!(
+            Date::from_parts(year!(-1), Month::December, 25)?.weekday(),
+            Weekday::Monday
+        );
+        assert_eq!(
+            Date::from_parts(year!(2000), Month::February, 29)?.weekday(),
+            Weekday::Tuesday
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn iso_week() {
+        // (date, ISO week), from a Wednesday in 2020's week 53 into 2021.
+        let cases = [
+            (date!(2020 - 12 - 27), "2020-W52"),
+            (date!(2020 - 12 - 30), "2020-W53"),
+            (date!(2021 - 01 - 03), "2020-W53"),
+            (date!(2021 - 01 - 04), "2021-W01"),
+            (date!(2021 - 01 - 10), "2021-W01"),
+            (date!(2023 - 10 - 12), "2023-W41"),
+            // 2026 starts on a Thursday, so it has 53 weeks and the next year starts a week in.
+            (date!(2026 - 01 - 01), "2026-W01"),
+            (date!(2026 - 12 - 31), "2026-W53"),
+            (date!(2027 - 01 - 03), "2026-W53"),
+            (date!(2025 - 12 - 29), "2026-W01"),
+            // Year 1 starts on a Monday, right after the last week of 1 BCE.
+            (date!(1 - 01 - 01), "0001-W01"),
+            (date!(-1 - 12 - 31), "0000-W52"),
+            (date!(-44 - 03 - 15), "-0043-W11"),
+        ];
+        for (date, week) in cases {
+            assert_eq!(date.iso_week().to_string(), week, "{date:?}");
         }
-    };
-}
-#[doc(inline)]
-pub use year;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Month {
-    January = 1,
-    February = 2,
-    March = 3,
-    April = 4,
-    May = 5,
-    June = 6,
-    July = 7,
-    August = 8,
-    September = 9,
-    October = 10,
-    November = 11,
-    December = 12,
-}
+        // Every week has 7 days, all in the same ISO week.
+        let mut day = date!(2019 - 12 - 30);
+        while day < date!(2028 - 01 - 03) {
+            let week = day.iso_week();
+            assert_eq!(day.weekday(), Weekday::Monday);
+            for offset in 1..7 {
+                assert_eq!((day + offset).iso_week(), week);
+            }
+            assert!((day + 7).iso_week() > week);
+            day += 7;
+        }
+    }
 
-impl TryFrom<u8> for Month {
-    type Error = errors::DateCreationError;
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        Ok(match value {
-            1 => Self::January,
-            2 => Self::February,
-            3 => Self::March,
-            4 => Self::April,
-            5 => Self::May,
-            6 => Self::June,
-            7 => Self::July,
-            8 => Self::August,
-            9 => Self::September,
-            10 => Self::October,
-            11 => Self::November,
-            12 => Self::December,
-            other => return Err(errors::DateCreationError::InvalidMonth(other)),
-        })
+    #[test]
+    fn orderings_agree() {
+        let mut random = crate::selfcheck::SplitMix64::default();
+        let mut random = |bound| random.below(bound);
+        let mut random_date = || {
+            // Close years, so that many pairs share their year or month, around year 0.
+            let year = Year::from_astronomical(random(801) as i128 - 400);
+            let month = Month::ALL[random(12) as usize];
+            let day = random(u64::from(*Date::days_of(year, month).end())) as u8 + 1;
+            Date::from_parts(year, month, day).unwrap()
+        };
+
+        let mut dates: Vec<_> = (0..2_000).map(|_| random_date()).collect();
+        dates.extend([
+            Date::MIN,
+            Date::MAX,
+            date!(-1 - 12 - 31),
+            date!(1 - 01 - 01),
+        ]);
+        for first in &dates {
+            for second in dates.iter().step_by(7) {
+                // The day counts are the reference the field-wise comparison must agree with.
+                let order = StandardCalendar::from(first)
+                    .days
+                    .cmp(&StandardCalendar::from(second).days);
+                assert_eq!(first.cmp_days(second), order, "{first:?} {second:?}");
+                assert_eq!(first.cmp(second), order, "{first:?} {second:?}");
+                assert_eq!((*first - *second).cmp(&0), order, "{first:?} {second:?}");
+                assert_eq!(
+                    Date::days_between(first, second),
+                    (*first - *second).abs(),
+                    "{first:?} {second:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn apply_offset() {
+        let offset = |years, months, days| DateOffset {
+            years,
+            months,
+            days,
+        };
+        // (date, offset, result)
+        let cases = [
+            (
+                date!(2024 - 02 - 29),
+                offset(1, 0, 0),
+                date!(2025 - 02 - 28),
+            ),
+            (
+                date!(2024 - 02 - 29),
+                offset(4, 0, 0),
+                date!(2028 - 02 - 29),
+            ),
+            (
+                date!(2024 - 01 - 31),
+                offset(0, 1, 0),
+                date!(2024 - 02 - 29),
+            ),
+            // The days count from the clamped day, not from the 31st.
+            (
+                date!(2024 - 01 - 31),
+                offset(0, 1, 1),
+                date!(2024 - 03 - 01),
+            ),
+            (
+                date!(2024 - 01 - 31),
+                offset(0, 1, -1),
+                date!(2024 - 02 - 28),
+            ),
+            // Years and months are added up before clamping.
+            (
+                date!(2023 - 03 - 31),
+                offset(1, -1, 0),
+                date!(2024 - 02 - 29),
+            ),
+            (
+                date!(2024 - 05 - 15),
+                offset(-1, -17, -10),
+                date!(2021 - 12 - 05),
+            ),
+            (date!(1 - 06 - 15), offset(-1, 0, 0), date!(-1 - 06 - 15)),
+            (date!(-1 - 11 - 30), offset(0, 2, 2), date!(1 - 02 - 01)),
+            (
+                date!(2024 - 05 - 15),
+                offset(0, 0, 0),
+                date!(2024 - 05 - 15),
+            ),
+        ];
+        for (date, offset, expected) in cases {
+            assert_eq!(date.apply_offset(&offset), expected, "{date:?} {offset}");
+        }
     }
-}
 
-mod errors {
-    use crate::calendar::Calendar;
+    #[test]
+    fn nth_weekday_of_month() -> Result<(), DateCreationError> {
+        // August 2024 starts on a Thursday and has five of them.
+        let nth = |n| Date::nth_weekday_of_month(year!(2024), Month::August, Weekday::Thursday, n);
+        assert_eq!(
+            nth(Occurrence::Nth(1))?,
+            Date::from_parts(year!(2024), Month::August, 1)?
+        );
+        assert_eq!(
+            nth(Occurrence::Nth(5))?,
+            Date::from_parts(year!(2024), Month::August, 29)?
+        );
+        assert_eq!(
+            nth(Occurrence::Last)?,
+            Date::from_parts(year!(2024), Month::August, 29)?
+        );
+        assert!(matches!(
+            nth(Occurrence::Nth(0)),
+            Err(DateCreationError::NoSuchOccurrence(0))
+        ));
+        assert!(matches!(
+            nth(Occurrence::Nth(6)),
+            Err(DateCreationError::NoSuchOccurrence(6))
+        ));
+        assert!(nth(Occurrence::Nth(u8::MAX)).is_err());
 
-    use super::Date;
+        // A leap February has exactly one fifth weekday; a regular one has none.
+        assert_eq!(
+            Date::nth_weekday_of_month(
+                year!(2024),
+                Month::February,
+                Weekday::Thursday,
+                Occurrence::Nth(5)
+            )?,
+            Date::from_parts(year!(2024), Month::February, 29)?
+        );
+        assert!(
+            Date::nth_weekday_of_month(
+                year!(2023),
+                Month::February,
+                Weekday::Wednesday,
+                Occurrence::Nth(5)
+            )
+            .is_err()
+        );
+        assert_eq!(
+            Date::nth_weekday_of_month(
+                year!(2023),
+                Month::February,
+                Weekday::Tuesday,
+                Occurrence::Last
+            )?,
+            Date::from_parts(year!(2023), Month::February, 28)?
+        );
 
-    #[derive(Debug, Clone, Copy)]
-    pub enum DateCreationError {
-        InvalidMonth(u8),
-        InvalidDay(<Date as Calendar>::Day),
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        Calendar, StandardCalendar,
-        date::gregorian::{Date, Month},
-    };
+    #[test]
+    fn next_and_previous_weekday() -> Result<(), DateCreationError> {
+        // Crossing the era boundary, where 1 January 1 CE is a Monday.
+        let date = Date::from_parts(year!(-1), Month::December, 30)?;
+        assert_eq!(date.next_weekday(Weekday::Monday), Date::reference_date());
+        assert_eq!(
+            Date::reference_date().previous_weekday(Weekday::Saturday),
+            date
+        );
+        assert_eq!(
+            Date::reference_date().previous_weekday(Weekday::Monday),
+            Date::from_parts(year!(-1), Month::December, 25)?
+        );
+        Ok(())
+    }
 
-    use super::errors::DateCreationError;
+    #[test]
+    fn add_business_days() -> Result<(), DateCreationError> {
+        let weekend = WeekendDef::default();
+        // A Saturday.
+        let saturday = Date::from_parts(year!(2024), Month::June, 1)?;
+        let after = |n| {
+            let mut date = saturday;
+            date.add_business_days(n, &weekend);
+            date
+        };
+        assert_eq!(after(0), saturday);
+        assert_eq!(after(1), Date::from_parts(year!(2024), Month::June, 3)?);
+        assert_eq!(after(5), Date::from_parts(year!(2024), Month::June, 7)?);
+        assert_eq!(after(6), Date::from_parts(year!(2024), Month::June, 10)?);
+        assert_eq!(after(10), Date::from_parts(year!(2024), Month::June, 14)?);
+        assert_eq!(after(-1), Date::from_parts(year!(2024), Month::May, 31)?);
+        assert_eq!(after(-5), Date::from_parts(year!(2024), Month::May, 27)?);
+        assert_eq!(after(-6), Date::from_parts(year!(2024), Month::May, 24)?);
+
+        // Friday and Saturday weekends.
+        let weekend = WeekendDef::new(&[Weekday::Friday, Weekday::Saturday]);
+        let mut date = Date::from_parts(year!(2024), Month::June, 6)?;
+        date.add_business_days(1, &weekend);
+        assert_eq!(date, Date::from_parts(year!(2024), Month::June, 9)?);
+        date.add_business_days(-1, &weekend);
+        assert_eq!(date, Date::from_parts(year!(2024), Month::June, 6)?);
+
+        Ok(())
+    }
 
     #[test]
-    fn days_between() -> Result<(), DateCreationError> {
-        // Months
+    fn business_days_between() -> Result<(), DateCreationError> {
+        let weekend = WeekendDef::default();
+        let saturday = Date::from_parts(year!(2024), Month::June, 1)?;
+        let monday = Date::from_parts(year!(2024), Month::June, 3)?;
+        let next_monday = Date::from_parts(year!(2024), Month::June, 10)?;
+
         assert_eq!(
-            Date::days_between(
-                &Date::reference_date(),
-                &Date::from_parts(year!(2), Month::February, 1)?
+            Date::business_days_between(&monday, &monday, &weekend, &[]),
+            0
+        );
+        assert_eq!(
+            Date::business_days_between(&saturday, &monday, &weekend, &[]),
+            0
+        );
+        assert_eq!(
+            Date::business_days_between(&saturday, &next_monday, &weekend, &[]),
+            5
+        );
+        assert_eq!(
+            Date::business_days_between(&next_monday, &saturday, &weekend, &[]),
+            -5
+        );
+        // June 2024 has 20 business days.
+        assert_eq!(
+            Date::business_days_between(
+                &saturday,
+                &Date::from_parts(year!(2024), Month::July, 1)?,
+                &weekend,
+                &[]
             ),
-            396
+            20
         );
 
-        // With leap year in between
+        // Holidays are only skipped inside the range, once, and not on weekends.
+        let holidays = [
+            Date::from_parts(year!(2024), Month::May, 31)?,
+            Date::from_parts(year!(2024), Month::June, 2)?,
+            monday,
+            monday,
+            Date::from_parts(year!(2024), Month::June, 5)?,
+            next_monday,
+        ];
         assert_eq!(
-            Date::days_between(
-                &Date::from_parts(year!(2020), Month::February, 22)?,
-                &Date::from_parts(year!(2021), Month::March, 22)?
+            Date::business_days_between(&saturday, &next_monday, &weekend, &holidays),
+            3
+        );
+
+        // Adding the count lands back on business-day end points.
+        for n in -30..30 {
+            let mut date = monday;
+            date.add_business_days(n, &weekend);
+            assert_eq!(
+                Date::business_days_between(&monday, &date, &weekend, &[]),
+                n
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn year_buckets() {
+        // (year, decade, century, millennium)
+        let cases = [
+            (year!(1), 1, 1, 1),
+            (year!(-1), -1, -1, -1),
+            (year!(100), 10, 1, 1),
+            (year!(-100), -10, -1, -1),
+            (year!(101), 11, 2, 1),
+            (year!(-101), -11, -2, -1),
+        ];
+        for (year, decade, century, millennium) in cases {
+            assert_eq!(year.decade(), decade, "decade of {year:?}");
+            assert_eq!(year.century(), century, "century of {year:?}");
+            assert_eq!(year.millennium(), millennium, "millennium of {year:?}");
+        }
+
+        // (year, decade start, century start)
+        let cases = [
+            (year!(1), year!(1), year!(1)),
+            (year!(-1), year!(-10), year!(-100)),
+            (year!(100), year!(91), year!(1)),
+            (year!(-100), year!(-100), year!(-100)),
+            (year!(101), year!(101), year!(101)),
+            (year!(-101), year!(-110), year!(-200)),
+        ];
+        for (year, decade_start, century_start) in cases {
+            assert_eq!(
+                year.decade_start(),
+                decade_start,
+                "decade start of {year:?}"
+            );
+            assert_eq!(
+                year.century_start(),
+                century_start,
+                "century start of {year:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn astronomical_ends() {
+        let (min, max) = (
+            Year::try_from(i128::MIN).unwrap(),
+            Year::try_from(i128::MAX).unwrap(),
+        );
+        assert_eq!(min.to_astronomical(), i128::MIN + 1);
+        assert_eq!(Year::from_astronomical(i128::MIN + 1), min);
+        assert_eq!(Year::from_astronomical(i128::MAX), max);
+        assert_eq!(Year::checked_from_astronomical(i128::MIN), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn astronomical_below_min() {
+        Year::from_astronomical(i128::MIN);
+    }
+
+    #[test]
+    fn year_to_and_from_strings() {
+        for (year, display, alternate) in [
+            (year!(2024), "2024", "2024"),
+            (year!(1), "1", "1"),
+            (year!(-1), "1 BCE", "-1"),
+            (year!(-500), "500 BCE", "-500"),
+        ] {
+            assert_eq!(year.to_string(), display);
+            assert_eq!(format!("{year:#}"), alternate);
+            assert_eq!(display.parse(), Ok(year));
+            assert_eq!(alternate.parse(), Ok(year));
+        }
+
+        let cases = [
+            ("+2024", Ok(year!(2024))),
+            ("  2024 ", Ok(year!(2024))),
+            ("500 BC", Ok(year!(-500))),
+            ("500 bce", Ok(year!(-500))),
+            ("500BC", Ok(year!(-500))),
+            ("44 B.C.", Ok(year!(-44))),
+            ("AD 14", Ok(year!(14))),
+            ("14 A.D.", Ok(year!(14))),
+            ("1500 CE", Ok(year!(1500))),
+            ("0", Err(errors::YearParseError::Zero)),
+            ("0 BCE", Err(errors::YearParseError::Zero)),
+            ("", Err(errors::YearParseError::Invalid)),
+            ("soon", Err(errors::YearParseError::Invalid)),
+            ("BCE", Err(errors::YearParseError::Invalid)),
+            ("-500 BCE", Err(errors::YearParseError::Invalid)),
+            ("AD 14 BC", Err(errors::YearParseError::Invalid)),
+            ("2024.5", Err(errors::YearParseError::Invalid)),
+            (
+                "170141183460469231731687303715884105727",
+                Err(errors::YearParseError::OutOfRange),
             ),
-            394
+            (
+                "170141183460469231731687303715884105727 BC",
+                Err(errors::YearParseError::OutOfRange),
+            ),
+        ];
+        for (input, year) in cases {
+            assert_eq!(input.parse::<Year>(), year, "{input}");
+        }
+        for furthest in [Date::MIN.year(), Date::MAX.year()] {
+            assert_eq!(furthest.to_string().parse(), Ok(furthest));
+        }
+    }
+
+    #[test]
+    fn strict_clamped_and_rollover_construction() {
+        use DayAdjustment::*;
+
+        let date = |year, month, day| Date::from_parts(year, month, day).unwrap();
+        let cases = [
+            // (year, month, day), clamped to, rolled over to
+            (
+                (year!(2024), Month::April, 31),
+                30,
+                (year!(2024), Month::May, 1),
+            ),
+            (
+                (year!(1900), Month::February, 29),
+                28,
+                (year!(1900), Month::March, 1),
+            ),
+            (
+                (year!(2023), Month::February, 31),
+                28,
+                (year!(2023), Month::March, 3),
+            ),
+            (
+                (year!(2024), Month::January, 32),
+                31,
+                (year!(2024), Month::February, 1),
+            ),
+            (
+                (year!(2024), Month::March, 0),
+                1,
+                (year!(2024), Month::February, 29),
+            ),
+            (
+                (year!(-1), Month::December, 255),
+                31,
+                (year!(1), Month::August, 12),
+            ),
+        ];
+        for ((year, month, day), clamped, (rolled_year, rolled_month, rolled_day)) in cases {
+            assert_eq!(
+                Date::from_parts(year, month, day),
+                Err(DateCreationError::InvalidDay(day))
+            );
+            assert_eq!(
+                Date::from_parts_clamped(year, month, day),
+                (date(year, month, clamped), Clamped)
+            );
+            assert_eq!(
+                Date::from_parts_rollover(year, month, day),
+                (date(rolled_year, rolled_month, rolled_day), RolledOver)
+            );
+        }
+
+        let valid = date(year!(2000), Month::February, 29);
+        assert_eq!(
+            Date::from_parts_clamped(year!(2000), Month::February, 29),
+            (valid, Unchanged)
         );
+        assert_eq!(
+            Date::from_parts_rollover(year!(2000), Month::February, 29),
+            (valid, Unchanged)
+        );
+    }
 
-        // With years in between
+    #[test]
+    fn supported_range() {
+        assert_eq!(StandardCalendar::from(&Date::MIN), StandardCalendar::MIN);
+        assert_eq!(StandardCalendar::from(&Date::MAX), StandardCalendar::MAX);
+        assert_eq!(Date::from(StandardCalendar::MIN), Date::MIN);
+        assert_eq!(Date::from(StandardCalendar::MAX), Date::MAX);
+
+        assert_eq!(Date::MIN.pred(), None);
+        assert_eq!(Date::MAX.succ(), None);
         assert_eq!(
-            Date::days_between(
-                &Date::from_parts(year!(2019), Month::February, 22)?,
-                &Date::from_parts(year!(2021), Month::March, 22)?
-            ),
-            394 + 365
+            Date::MIN.succ(),
+            Date::from_parts(Date::MIN.year, Month::January, 2).ok()
+        );
+        assert_eq!(
+            Date::MAX.pred(),
+            Date::from_parts(Date::MAX.year, Month::December, 30).ok()
         );
+        assert_eq!(StandardCalendar::MIN.pred(), None);
+        assert_eq!(StandardCalendar::MAX.succ(), None);
 
-        // Negative Years
+        // Dates past the supported range have no neighbours either.
+        let past_max = Date::from_year(Date::MAX.year.next());
+        assert_eq!(past_max.pred(), None);
+        assert_eq!(past_max.succ(), None);
+    }
+
+    #[test]
+    fn min_max_and_clamp() {
+        let before = Date::from_parts(year!(-1), Month::December, 31).unwrap();
+        let after = Date::from_parts(year!(1), Month::January, 1).unwrap();
+
+        assert_eq!(Date::min(before, after), before);
+        assert_eq!(Date::max(before, after), after);
+        assert_eq!(Date::MIN.clamp(before, after), before);
+        assert_eq!(Date::MAX.clamp(before, after), after);
+        assert_eq!(before.clamp(Date::MIN, Date::MAX), before);
+
+        let (before, after) = (
+            StandardCalendar::from(&before),
+            StandardCalendar::from(&after),
+        );
+        assert_eq!(StandardCalendar::min(before, after), before);
+        assert_eq!(StandardCalendar::MAX.clamp(before, after), after);
+    }
+
+    #[test]
+    fn const_construction() {
+        const LEAP_DAY: Date = date!(2000 - 02 - 29);
+        const BCE: Date = date!(-1 - 12 - 31);
         assert_eq!(
-            Date::days_between(
-                &Date::reference_date(),
-                &Date::from_parts(year!(-1), Month::December, 31)?
-            ),
-            1
+            LEAP_DAY,
+            Date::from_parts(year!(2000), Month::February, 29).unwrap()
         );
+        assert_eq!(
+            BCE,
+            Date::from_parts(year!(-1), Month::December, 31).unwrap()
+        );
+        assert_eq!(BCE.succ(), Some(date!(1 - 01 - 01)));
 
-        Ok(())
+        for number in 0..=u8::MAX {
+            assert_eq!(Month::from_number(number), Month::try_from(number));
+        }
+        assert_eq!(
+            Month::from_number(0),
+            Err(DateCreationError::InvalidMonth(0))
+        );
     }
 
     #[test]
-    fn into_standard_calendar() -> Result<(), DateCreationError> {
-        // Day 0
+    fn picker_days() {
+        for year in [
+            year!(-1),
+            year!(1900),
+            year!(2000),
+            year!(2023),
+            year!(2024),
+        ] {
+            let months = Date::months_of(year);
+            assert_eq!(months, Month::ALL);
+            let mut days_in_year = 0;
+            for month in months {
+                let days = Date::days_of(year, month);
+                assert_eq!(*days.start(), 1);
+                for day in days.clone() {
+                    assert!(
+                        Date::from_parts(year, month, day).is_ok(),
+                        "{year} {month:?} {day}"
+                    );
+                    days_in_year += 1;
+                }
+                assert_eq!(
+                    Date::from_parts(year, month, days.end() + 1),
+                    Err(DateCreationError::InvalidDay(days.end() + 1))
+                );
+            }
+            assert_eq!(days_in_year, if year.is_leap_year() { 366 } else { 365 });
+        }
+    }
+
+    #[test]
+    fn month_numbers() {
+        for (index, month) in Month::ALL.into_iter().enumerate() {
+            assert_eq!(month.index0(), index);
+            assert_eq!(Month::from_index0(index as u8), Some(month));
+            assert_eq!(Month::try_from(u8::from(month)), Ok(month));
+            assert_eq!(Month::try_from(index as u32 + 1), Ok(month));
+            assert_eq!(Month::try_from(index as i32 + 1), Ok(month));
+        }
+        assert_eq!(Month::from_index0(12), None);
+
+        let cases = [
+            (13, DateCreationError::InvalidMonth(13)),
+            (0, DateCreationError::InvalidMonth(0)),
+            (256, DateCreationError::InvalidMonth(u8::MAX)),
+            (-1, DateCreationError::InvalidMonth(u8::MAX)),
+            (i32::MIN, DateCreationError::InvalidMonth(u8::MAX)),
+        ];
+        for (number, error) in cases {
+            assert_eq!(Month::try_from(number), Err(error), "{number}");
+        }
         assert_eq!(
-            StandardCalendar::from(&Date::from_parts(year!(1), Month::January, 1)?),
-            StandardCalendar::new(0)
+            Month::try_from(u32::MAX),
+            Err(DateCreationError::InvalidMonth(u8::MAX))
         );
+    }
+
+    #[test]
+    fn leap_years_in_range() {
+        let brute_force = |first: i128, last: i128| {
+            (first..last)
+                .filter(|year| Year::from_astronomical(*year).is_leap_year())
+                .count() as u64
+        };
+        for first in -850..=850 {
+            for last in [first - 1, first, first + 1, first + 4, first + 99, 850] {
+                assert_eq!(
+                    super::leap_years_in_range(
+                        Year::from_astronomical(first),
+                        Year::from_astronomical(last)
+                    ),
+                    brute_force(first, last),
+                    "{first} to {last}"
+                );
+            }
+        }
 
         assert_eq!(
-            StandardCalendar::from(&Date::from_parts(year!(-1), Month::December, 31)?),
-            StandardCalendar::new(-1)
+            super::leap_years_in_range(Date::MIN.year(), Date::MAX.year()),
+            u64::MAX
         );
+    }
 
-        Ok(())
+    #[test]
+    fn operators() {
+        let cases = [
+            (date!(2024 - 02 - 28), date!(2024 - 03 - 01), 2),
+            (date!(2023 - 02 - 28), date!(2023 - 03 - 01), 1),
+            (date!(-1 - 12 - 31), date!(1 - 01 - 01), 1),
+            (date!(-1 - 01 - 01), date!(1 - 01 - 01), 366),
+            (date!(-44 - 03 - 15), date!(2024 - 01 - 01), 754_883),
+            (date!(2024 - 01 - 01), date!(2024 - 01 - 01), 0),
+        ];
+        for (earlier, later, days) in cases {
+            assert_eq!(later - earlier, days, "{later:?} - {earlier:?}");
+            assert_eq!(earlier - later, -days, "{earlier:?} - {later:?}");
+            assert_eq!((later - earlier).cmp(&0), later.cmp(&earlier));
+
+            assert_eq!(earlier + days, later);
+            assert_eq!(later - days, earlier);
+            assert_eq!(later + -days, earlier);
+
+            let mut date = earlier;
+            date += days;
+            assert_eq!(date, later);
+            date -= days;
+            assert_eq!(date, earlier);
+        }
     }
 }
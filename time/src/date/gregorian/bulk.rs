@@ -0,0 +1,244 @@
+//! Conversion of many days at once, between [`StandardCalendar`] day counts and [`Date`]s.
+//!
+//! Days that are close together, such as the labels along a timeline, mostly fall in the same month or year. These
+//! conversions remember the last month and year they saw, and only do the full conversion when a day leaves them.
+//! `cargo bench --bench bulk` compares them with converting each day on its own.
+
+use super::{Date, Month, Year};
+use crate::StandardCalendar;
+
+/// The last month and year a conversion went through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct YearCache {
+    year: Year,
+    /// January 1st of `year`, in [`StandardCalendar`] days.
+    start: i128,
+    /// How many days `year` has.
+    length: i128,
+    month: Month,
+    /// The 1st of `month`, in [`StandardCalendar`] days.
+    month_start: i128,
+    /// How many days `month` has.
+    month_length: i128,
+}
+
+impl YearCache {
+    /// The cache for the year and month of `date`, which is `days` in the [`StandardCalendar`].
+    fn of(date: &Date, days: i128) -> Self {
+        let mut cache = Self {
+            year: date.year,
+            start: days - i128::from(date.ordinal()) + 1,
+            length: i128::from(Date::cumulative_days(date.year.is_leap_year())[12]),
+            month: date.month,
+            month_start: 0,
+            month_length: 0,
+        };
+        cache.enter_month(date, days);
+        cache
+    }
+
+    /// Moves the cached month to the one of `date`, which must be in the cached year.
+    fn enter_month(&mut self, date: &Date, days: i128) {
+        self.month = date.month;
+        self.month_start = days - i128::from(date.day) + 1;
+        self.month_length = i128::from(Date::month_lengths(date.year)[date.month.index0()]);
+    }
+
+    /// The date of `days`, if it is in the cached year.
+    fn date(&mut self, days: i128) -> Option<Date> {
+        let in_month = days - self.month_start;
+        if (0..self.month_length).contains(&in_month) {
+            return Some(Date {
+                year: self.year,
+                month: self.month,
+                day: in_month as u8 + 1,
+            });
+        }
+        if !(self.start..self.start + self.length).contains(&days) {
+            return None;
+        }
+        // In range of the year by the check above.
+        let date = Date::from_ordinal(self.year, (days - self.start + 1) as u16).unwrap();
+        self.enter_month(&date, days);
+        Some(date)
+    }
+}
+
+/// Iterator adapter converting [`StandardCalendar`] day counts into [`Date`]s, created by [`DatesFromDays::new`].
+///
+/// Used by [`dates_from_days`], and useful for streaming days that aren't all in memory.
+#[derive(Debug, Clone)]
+pub struct DatesFromDays<I> {
+    days: I,
+    cache: Option<YearCache>,
+    /// How many days were converted through the cached year.
+    #[cfg(test)]
+    hits: usize,
+}
+
+impl<I: Iterator<Item = i128>> DatesFromDays<I> {
+    /// Converts each of `days` into a [`Date`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{bulk::DatesFromDays, date};
+    ///
+    /// let dates: Vec<_> = DatesFromDays::new(738_885..738_888).collect();
+    /// assert_eq!(dates, [date!(2024-01-01), date!(2024-01-02), date!(2024-01-03)]);
+    /// ```
+    pub fn new(days: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            days: days.into_iter(),
+            cache: None,
+            #[cfg(test)]
+            hits: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = i128>> Iterator for DatesFromDays<I> {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let days = self.days.next()?;
+        if let Some(date) = self.cache.as_mut().and_then(|cache| cache.date(days)) {
+            #[cfg(test)]
+            {
+                self.hits += 1;
+            }
+            #[cfg(feature = "validate")]
+            debug_assert_eq!(
+                date,
+                Date::from(StandardCalendar::new(days)),
+                "{days} from cache"
+            );
+            return Some(date);
+        }
+        let date = Date::from(StandardCalendar::new(days));
+        self.cache = Some(YearCache::of(&date, days));
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.days.size_hint()
+    }
+}
+
+/// Iterator adapter converting [`Date`]s into [`StandardCalendar`] day counts, created by [`DaysFromDates::new`].
+///
+/// The reverse of [`DatesFromDays`], used by [`days_from_dates`].
+#[derive(Debug, Clone)]
+pub struct DaysFromDates<I> {
+    dates: I,
+    cache: Option<YearCache>,
+    /// How many dates were converted through the cached year.
+    #[cfg(test)]
+    hits: usize,
+}
+
+impl<I: Iterator<Item = Date>> DaysFromDates<I> {
+    /// Converts each of `dates` into its [`StandardCalendar`] day count.
+    pub fn new(dates: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            dates: dates.into_iter(),
+            cache: None,
+            #[cfg(test)]
+            hits: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Date>> Iterator for DaysFromDates<I> {
+    type Item = i128;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.dates.next()?;
+        if let Some(cache) = self.cache
+            && cache.year == date.year
+        {
+            #[cfg(test)]
+            {
+                self.hits += 1;
+            }
+            let days = cache.start + i128::from(date.ordinal()) - 1;
+            #[cfg(feature = "validate")]
+            debug_assert_eq!(
+                days,
+                StandardCalendar::from(&date).days,
+                "{date:?} from cache"
+            );
+            return Some(days);
+        }
+        let days = StandardCalendar::from(&date).days;
+        self.cache = Some(YearCache::of(&date, days));
+        Some(days)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.dates.size_hint()
+    }
+}
+
+/// Converts every one of `days` into a [`Date`], giving the same dates as converting each through
+/// [`StandardCalendar`].
+///
+/// This is fastest when consecutive days are in the same year, such as when they are sorted.
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{dates_from_days, date};
+///
+/// assert_eq!(dates_from_days(&[0, 1, -1]), [date!(1-01-01), date!(1-01-02), date!(-1-12-31)]);
+/// ```
+pub fn dates_from_days(days: &[i128]) -> Vec<Date> {
+    DatesFromDays::new(days.iter().copied()).collect()
+}
+
+/// Converts every one of `dates` into its [`StandardCalendar`] day count. The reverse of [`dates_from_days`].
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{days_from_dates, date};
+///
+/// assert_eq!(days_from_dates(&[date!(1-01-01), date!(1970-01-01)]), [0, 719_162]);
+/// ```
+pub fn days_from_dates(dates: &[Date]) -> Vec<i128> {
+    DaysFromDates::new(dates.iter().copied()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Days around the year 0 gap, leap days and the ends of the supported range, in an unsorted order.
+    fn sample_days() -> Vec<i128> {
+        let mut days: Vec<i128> = (-800..800).collect();
+        days.extend((0..2_000).map(|day| 738_000 + day * 37));
+        days.extend((0..500).map(|day| 730_000 - day * 3));
+        days.extend([StandardCalendar::MIN.days, StandardCalendar::MAX.days, 0]);
+        days
+    }
+
+    #[test]
+    fn matches_scalar_conversion() {
+        let days = sample_days();
+        let dates = dates_from_days(&days);
+        for (day, date) in days.iter().zip(&dates) {
+            assert_eq!(*date, Date::from(StandardCalendar::new(*day)), "{day}");
+        }
+        assert_eq!(days_from_dates(&dates), days);
+    }
+
+    #[test]
+    fn sorted_days_use_the_cache() {
+        // Three years and a bit, so only 4 misses: one for each year entered.
+        let days: Vec<i128> = (738_800..738_800 + 3 * 366).collect();
+        let mut dates = DatesFromDays::new(days.iter().copied());
+        let converted: Vec<_> = dates.by_ref().collect();
+        assert_eq!(dates.hits, days.len() - 4);
+
+        let mut back = DaysFromDates::new(converted.iter().copied());
+        assert_eq!(back.by_ref().collect::<Vec<_>>(), days);
+        assert_eq!(back.hits, days.len() - 4);
+    }
+}
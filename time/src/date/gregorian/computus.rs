@@ -0,0 +1,229 @@
+//! Computation of [Easter](https://en.wikipedia.org/wiki/Date_of_Easter) and the feasts that move along with it.
+//!
+//! Dates here follow the Western (Gregorian) computus, and so are the ones observed by the Catholic and most
+//! Protestant churches, except for [`julian_easter`], which is observed by the Orthodox churches.
+
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+use crate::date::historical;
+
+use super::{Date, Month, Year};
+
+/// The date of Easter Sunday in the given `year`.
+///
+/// This uses the [anonymous Gregorian algorithm](https://en.wikipedia.org/wiki/Date_of_Easter#Anonymous_Gregorian_algorithm),
+/// applied proleptically to years before the calendar was adopted.
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, computus, year};
+///
+/// assert_eq!(computus::easter(year!(2024)), Date::from_parts(year!(2024), Month::March, 31).unwrap());
+/// assert_eq!(computus::easter(year!(2000)), Date::from_parts(year!(2000), Month::April, 23).unwrap());
+/// ```
+pub fn easter(year: Year) -> Date {
+    let year_number = year.to_astronomical();
+
+    // The position in the 19-year Metonic cycle.
+    let golden = year_number.rem_euclid(19);
+    let (century, year_of_century) = (year_number.div_euclid(100), year_number.rem_euclid(100));
+    let (leap_centuries, century_rest) = (century.div_euclid(4), century.rem_euclid(4));
+    let moon_correction = (century - (century + 8).div_euclid(25) + 1).div_euclid(3);
+    // Days from the equinox to the paschal full moon.
+    let full_moon = (19 * golden + century - leap_centuries - moon_correction + 15).rem_euclid(30);
+    let (leap_years, year_rest) = (year_of_century.div_euclid(4), year_of_century.rem_euclid(4));
+    // Days from the paschal full moon to the following Sunday.
+    let to_sunday = (32 + 2 * century_rest + 2 * leap_years - full_moon - year_rest).rem_euclid(7);
+    let correction = (golden + 11 * full_moon + 22 * to_sunday).div_euclid(451);
+
+    let offset = full_moon + to_sunday - 7 * correction + 114;
+    // Easter is always in March or April, on a valid day.
+    Date::from_parts(
+        year,
+        Month::try_from((offset / 31) as u8).unwrap(),
+        (offset % 31 + 1) as u8,
+    )
+    .unwrap()
+}
+
+/// The date of Easter Sunday in the given `year` by the Julian computus, as observed by the Orthodox churches.
+///
+/// This uses [Meeus's Julian algorithm](https://en.wikipedia.org/wiki/Date_of_Easter#Meeus's_Julian_algorithm),
+/// which finds the date in the Julian Calendar. It is returned in the Gregorian Calendar, like every other date here.
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, computus, year};
+///
+/// assert_eq!(computus::julian_easter(year!(2024)), Date::from_parts(year!(2024), Month::May, 5).unwrap());
+/// // Both Easters fall on the same day some years.
+/// assert_eq!(computus::julian_easter(year!(2025)), computus::easter(year!(2025)));
+/// ```
+pub fn julian_easter(year: Year) -> Date {
+    let year_number = year.to_astronomical();
+
+    // Days from the equinox to the paschal full moon, which repeats every 19 years.
+    let full_moon = (19 * year_number.rem_euclid(19) + 15).rem_euclid(30);
+    // Days from the paschal full moon to the following Sunday.
+    let to_sunday = (2 * year_number.rem_euclid(4) + 4 * year_number.rem_euclid(7) - full_moon
+        + 34)
+        .rem_euclid(7);
+
+    let offset = full_moon + to_sunday + 114;
+    // Julian Easter is always in March or April, on a valid day.
+    let days = historical::julian_to_days(
+        year,
+        Month::try_from((offset / 31) as u8).unwrap(),
+        (offset % 31 + 1) as u8,
+    );
+    StandardCalendar::new(days).into()
+}
+
+/// The date of Ash Wednesday, the start of Lent, 46 days before [Easter](easter).
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, computus, year};
+///
+/// assert_eq!(computus::ash_wednesday(year!(2024)), Date::from_parts(year!(2024), Month::February, 14).unwrap());
+/// ```
+pub fn ash_wednesday(year: Year) -> Date {
+    from_easter(year, -46)
+}
+
+/// The date of Good Friday, 2 days before [Easter](easter).
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, computus, year};
+///
+/// assert_eq!(computus::good_friday(year!(2024)), Date::from_parts(year!(2024), Month::March, 29).unwrap());
+/// ```
+pub fn good_friday(year: Year) -> Date {
+    from_easter(year, -2)
+}
+
+/// The date of Pentecost, 49 days after [Easter](easter).
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, computus, year};
+///
+/// assert_eq!(computus::pentecost(year!(2024)), Date::from_parts(year!(2024), Month::May, 19).unwrap());
+/// ```
+pub fn pentecost(year: Year) -> Date {
+    from_easter(year, 49)
+}
+
+/// The date `days` days away from [Easter](easter) in `year`.
+///
+/// Useful for any other movable feast, or for events recorded relative to Easter.
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, computus, year};
+///
+/// // Ascension Thursday.
+/// assert_eq!(computus::from_easter(year!(2024), 39), Date::from_parts(year!(2024), Month::May, 9).unwrap());
+/// ```
+pub fn from_easter(year: Year, days: i128) -> Date {
+    let mut date = easter(year);
+    date.add_days(days);
+    date
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Weekday;
+
+    use super::*;
+
+    /// Easter dates from 1900 to 2100, as (month, day).
+    #[rustfmt::skip]
+    const EASTER_1900_TO_2100: [(u8, u8); 201] = [
+            (4, 15), (4, 7), (3, 30), (4, 12), (4, 3), (4, 23),
+            (4, 15), (3, 31), (4, 19), (4, 11), (3, 27), (4, 16),
+            (4, 7), (3, 23), (4, 12), (4, 4), (4, 23), (4, 8),
+            (3, 31), (4, 20), (4, 4), (3, 27), (4, 16), (4, 1),
+            (4, 20), (4, 12), (4, 4), (4, 17), (4, 8), (3, 31),
+            (4, 20), (4, 5), (3, 27), (4, 16), (4, 1), (4, 21),
+            (4, 12), (3, 28), (4, 17), (4, 9), (3, 24), (4, 13),
+            (4, 5), (4, 25), (4, 9), (4, 1), (4, 21), (4, 6),
+            (3, 28), (4, 17), (4, 9), (3, 25), (4, 13), (4, 5),
+            (4, 18), (4, 10), (4, 1), (4, 21), (4, 6), (3, 29),
+            (4, 17), (4, 2), (4, 22), (4, 14), (3, 29), (4, 18),
+            (4, 10), (3, 26), (4, 14), (4, 6), (3, 29), (4, 11),
+            (4, 2), (4, 22), (4, 14), (3, 30), (4, 18), (4, 10),
+            (3, 26), (4, 15), (4, 6), (4, 19), (4, 11), (4, 3),
+            (4, 22), (4, 7), (3, 30), (4, 19), (4, 3), (3, 26),
+            (4, 15), (3, 31), (4, 19), (4, 11), (4, 3), (4, 16),
+            (4, 7), (3, 30), (4, 12), (4, 4), (4, 23), (4, 15),
+            (3, 31), (4, 20), (4, 11), (3, 27), (4, 16), (4, 8),
+            (3, 23), (4, 12), (4, 4), (4, 24), (4, 8), (3, 31),
+            (4, 20), (4, 5), (3, 27), (4, 16), (4, 1), (4, 21),
+            (4, 12), (4, 4), (4, 17), (4, 9), (3, 31), (4, 20),
+            (4, 5), (3, 28), (4, 16), (4, 1), (4, 21), (4, 13),
+            (3, 28), (4, 17), (4, 9), (3, 25), (4, 13), (4, 5),
+            (4, 25), (4, 10), (4, 1), (4, 21), (4, 6), (3, 29),
+            (4, 17), (4, 9), (3, 25), (4, 14), (4, 5), (4, 18),
+            (4, 10), (4, 2), (4, 21), (4, 6), (3, 29), (4, 18),
+            (4, 2), (4, 22), (4, 14), (3, 30), (4, 18), (4, 10),
+            (3, 26), (4, 15), (4, 6), (3, 29), (4, 11), (4, 3),
+            (4, 22), (4, 14), (3, 30), (4, 19), (4, 10), (3, 26),
+            (4, 15), (4, 7), (4, 19), (4, 11), (4, 3), (4, 23),
+            (4, 7), (3, 30), (4, 19), (4, 4), (3, 26), (4, 15),
+            (3, 31), (4, 20), (4, 11), (4, 3), (4, 16), (4, 8),
+            (3, 30), (4, 12), (4, 4), (4, 24), (4, 15), (3, 31),
+            (4, 20), (4, 12), (3, 28),
+    ];
+
+    #[test]
+    fn known_easter_dates() {
+        for (year, (month, day)) in (1900..=2100).zip(EASTER_1900_TO_2100) {
+            let year = Year::try_from(year).unwrap();
+            assert_eq!(
+                easter(year),
+                Date::from_parts(year, Month::try_from(month).unwrap(), day).unwrap(),
+                "Easter of {year:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn known_julian_easter_dates() {
+        // (year, month, day) in the Gregorian Calendar.
+        let cases = [
+            (1990, Month::April, 15),
+            (2000, Month::April, 30),
+            (2010, Month::April, 4),
+            (2019, Month::April, 28),
+            (2020, Month::April, 19),
+            (2021, Month::May, 2),
+            (2022, Month::April, 24),
+            (2023, Month::April, 16),
+            (2024, Month::May, 5),
+            (2025, Month::April, 20),
+            (2026, Month::April, 12),
+        ];
+        for (year, month, day) in cases {
+            let year = Year::try_from(year).unwrap();
+            assert_eq!(
+                julian_easter(year),
+                Date::from_parts(year, month, day).unwrap(),
+                "Julian Easter of {year:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn movable_feasts() {
+        for year in (-2000..2500).filter_map(|year| Year::try_from(year).ok()) {
+            assert_eq!(easter(year).weekday(), Weekday::Sunday);
+            assert_eq!(julian_easter(year).weekday(), Weekday::Sunday);
+            assert_eq!(ash_wednesday(year).weekday(), Weekday::Wednesday);
+            assert_eq!(good_friday(year).weekday(), Weekday::Friday);
+            assert_eq!(pentecost(year).weekday(), Weekday::Sunday);
+            assert_eq!(Date::days_between(&ash_wednesday(year), &easter(year)), 46);
+        }
+    }
+}
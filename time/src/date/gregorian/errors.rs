@@ -0,0 +1,85 @@
+use std::fmt;
+
+use crate::calendar::Calendar;
+
+use super::Date;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateCreationError {
+    InvalidMonth(u8),
+    InvalidDay(<Date as Calendar>::Day),
+    /// The year doesn't have this many days.
+    InvalidOrdinal(u16),
+    /// The month doesn't have this [occurrence](super::Occurrence) of the weekday.
+    NoSuchOccurrence(u8),
+}
+
+impl fmt::Display for DateCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMonth(month) => write!(f, "there is no month {month}"),
+            Self::InvalidDay(day) => write!(f, "the month has no day {day}"),
+            Self::InvalidOrdinal(day) => write!(f, "the year has no day {day}"),
+            Self::NoSuchOccurrence(n) => {
+                write!(f, "the month has no occurrence {n} of the weekday")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateCreationError {}
+
+/// The error type for parsing a [`Year`](super::Year) from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearParseError {
+    /// The input is the year 0, which doesn't exist.
+    Zero,
+    /// The input is not a year, such as "soon" or "-500 BCE".
+    Invalid,
+    /// The year is before [`Date::MIN`](super::Date::MIN) or after [`Date::MAX`](super::Date::MAX).
+    OutOfRange,
+}
+
+impl fmt::Display for YearParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zero => f.write_str("there is no year 0"),
+            Self::Invalid => f.write_str("not a year"),
+            Self::OutOfRange => f.write_str("the year is too far away to be represented"),
+        }
+    }
+}
+
+impl std::error::Error for YearParseError {}
+
+/// The error type for parsing a [`PartialDate`](super::partial::PartialDate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialDateParseError {
+    /// The year couldn't be read.
+    Year(YearParseError),
+    /// The parts read don't make up a valid date, such as "31 February 2024".
+    InvalidDate(DateCreationError),
+}
+
+impl fmt::Display for PartialDateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Year(error) => write!(f, "invalid year: {error}"),
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for PartialDateParseError {}
+
+impl From<YearParseError> for PartialDateParseError {
+    fn from(error: YearParseError) -> Self {
+        Self::Year(error)
+    }
+}
+
+impl From<DateCreationError> for PartialDateParseError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
@@ -0,0 +1,425 @@
+use std::fmt::Write;
+
+use crate::Weekday;
+use crate::calendar::Calendar;
+use crate::format::{FormatError, Item, items};
+use crate::locale::{Locale, match_name};
+
+use super::{Date, Month, Year};
+
+impl Date {
+    /// Formats this date following a format string.
+    ///
+    /// See the [`format`](crate::format) module for the supported directives.
+    ///
+    /// # Errors
+    /// Returns an error if `format` has an unsupported directive.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// let date = Date::from_parts(year!(2008), Month::April, 2).unwrap();
+    /// assert_eq!(date.format("%d/%m/%Y").unwrap(), "02/04/2008");
+    /// assert_eq!(date.format("%A, %B %e, %Y").unwrap(), "Wednesday, April  2, 2008");
+    /// assert_eq!(date.format("%Y-%j").unwrap(), "2008-093");
+    ///
+    /// let ides = Date::from_parts(year!(-44), Month::March, 15).unwrap();
+    /// assert_eq!(ides.format("%a %e %b %Y").unwrap(), "Fri 15 Mar -44");
+    /// assert_eq!(ides.format("%e %b %Y %E").unwrap(), "15 Mar 44 BCE");
+    ///
+    /// assert!(ides.format("%Y %Q").is_err());
+    /// ```
+    pub fn format(&self, format: &str) -> Result<String, FormatError> {
+        self.format_localized(format, Locale::En)
+    }
+
+    /// Formats this date following a format string, writing month and weekday names in the given `locale`.
+    ///
+    /// # Errors
+    /// Returns an error if `format` has an unsupported directive.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{date::gregorian::{Date, Month, year}, locale::Locale};
+    ///
+    /// let date = Date::from_parts(year!(2008), Month::April, 22).unwrap();
+    /// assert_eq!(date.format_localized("%A, %e de %B de %Y", Locale::PtBr).unwrap(), "terça-feira, 22 de abril de 2008");
+    /// assert_eq!(date.format_localized("%a %e %b %Y", Locale::Fr).unwrap(), "mar. 22 avr. 2008");
+    /// ```
+    pub fn format_localized(&self, format: &str, locale: Locale) -> Result<String, FormatError> {
+        let items = items(format)?;
+        // With the era written out, the year is written without a sign.
+        let has_era = items.contains(&Item::Era);
+        let signed = |number: i128| if has_era { number.abs() } else { number };
+
+        let mut formatted = String::new();
+        for item in items {
+            // Writing into a `String` never fails.
+            let _ = match item {
+                Item::Literal(literal) => write!(formatted, "{literal}"),
+                Item::Year => write!(formatted, "{}", signed(self.year.0.get())),
+                Item::Century => write!(formatted, "{}", signed(self.year.century())),
+                Item::Month => write!(formatted, "{:02}", u8::from(self.month)),
+                Item::MonthName => write!(formatted, "{}", self.month.name_in(locale)),
+                Item::MonthAbbreviation => {
+                    write!(formatted, "{}", self.month.abbreviation_in(locale))
+                }
+                Item::Day => write!(formatted, "{:02}", self.day),
+                Item::DaySpacePadded => write!(formatted, "{:2}", self.day),
+                Item::DayOfYear => write!(formatted, "{:03}", self.ordinal()),
+                Item::WeekdayName => write!(formatted, "{}", self.weekday().name_in(locale)),
+                Item::WeekdayAbbreviation => {
+                    write!(formatted, "{}", self.weekday().abbreviation_in(locale))
+                }
+                Item::Era => write!(
+                    formatted,
+                    "{}",
+                    if self.year.0.is_negative() {
+                        "BCE"
+                    } else {
+                        "CE"
+                    }
+                ),
+            };
+        }
+
+        Ok(formatted)
+    }
+
+    /// Parses a date following a format string.
+    ///
+    /// This accepts the same directives as [`Date::format`], so anything it produces can be parsed back, as long as
+    /// the format has enough information to build a date: a year and either a month and day or a day of the year.
+    /// Names are matched ignoring case, numbers may omit their padding, and `%Y` reads as many digits as it can.
+    /// Weekdays and centuries are checked against the resulting date.
+    ///
+    /// # Errors
+    /// Returns an error if `format` has an unsupported directive, if `input` doesn't match it, or if the parsed
+    /// fields don't make up a valid date.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// assert_eq!(
+    ///     Date::parse("%d/%m/%Y", "22/04/2008").unwrap(),
+    ///     Date::from_parts(year!(2008), Month::April, 22).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Date::parse("%e %B %Y %E", "15 march 44 BCE").unwrap(),
+    ///     Date::from_parts(year!(-44), Month::March, 15).unwrap()
+    /// );
+    ///
+    /// // 22 April 2008 was a Tuesday.
+    /// assert!(Date::parse("%A %d/%m/%Y", "Monday 22/04/2008").is_err());
+    /// ```
+    pub fn parse(format: &str, input: &str) -> Result<Self, FormatError> {
+        Self::parse_localized(format, input, Locale::En)
+    }
+
+    /// Parses a date following a format string, reading month and weekday names in the given `locale`.
+    ///
+    /// # Errors
+    /// Returns an error if `format` has an unsupported directive, if `input` doesn't match it, or if the parsed
+    /// fields don't make up a valid date.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{date::gregorian::{Date, Month, year}, locale::Locale};
+    ///
+    /// assert_eq!(
+    ///     Date::parse_localized("%e. %B %Y", "14. Juli 1789", Locale::De).unwrap(),
+    ///     Date::from_parts(year!(1789), Month::July, 14).unwrap()
+    /// );
+    /// ```
+    pub fn parse_localized(format: &str, input: &str, locale: Locale) -> Result<Self, FormatError> {
+        let names = locale.names();
+        let mut fields = Fields::default();
+        let mut rest = input;
+
+        for item in items(format)? {
+            let position = input.len() - rest.len();
+            let mismatch = FormatError::Mismatch { position };
+            let (consumed, field) = match item {
+                Item::Literal(literal) => {
+                    rest = rest.strip_prefix(literal).ok_or(mismatch)?;
+                    continue;
+                }
+                Item::Year => {
+                    number(rest, usize::MAX, true).map(|(len, year)| (len, Field::Year(year)))
+                }
+                Item::Century => number(rest, usize::MAX, true)
+                    .map(|(len, century)| (len, Field::Century(century))),
+                Item::Month => {
+                    number(rest, 2, false).map(|(len, month)| (len, Field::Month(month)))
+                }
+                Item::MonthName => match_name(rest, &names.months)
+                    .map(|(len, month)| (len, Field::Month(month as i128 + 1))),
+                Item::MonthAbbreviation => match_name(rest, &names.month_abbreviations)
+                    .map(|(len, month)| (len, Field::Month(month as i128 + 1))),
+                Item::Day => number(rest, 2, false).map(|(len, day)| (len, Field::Day(day))),
+                Item::DaySpacePadded => {
+                    let padding = usize::from(rest.starts_with(' '));
+                    number(&rest[padding..], 2, false)
+                        .map(|(len, day)| (padding + len, Field::Day(day)))
+                }
+                Item::DayOfYear => {
+                    number(rest, 3, false).map(|(len, day)| (len, Field::DayOfYear(day)))
+                }
+                Item::WeekdayName => match_name(rest, &names.weekdays)
+                    .map(|(len, day)| (len, Field::Weekday(Weekday::ALL[day]))),
+                Item::WeekdayAbbreviation => match_name(rest, &names.weekday_abbreviations)
+                    .map(|(len, day)| (len, Field::Weekday(Weekday::ALL[day]))),
+                Item::Era => match_name(rest, &["BCE", "CE"])
+                    .map(|(len, era)| (len, Field::BeforeCommonEra(era == 0))),
+            }
+            .ok_or(mismatch)?;
+
+            fields.set(field).map_err(|_| mismatch)?;
+            rest = &rest[consumed..];
+        }
+        if !rest.is_empty() {
+            return Err(FormatError::Mismatch {
+                position: input.len() - rest.len(),
+            });
+        }
+
+        fields.into_date()
+    }
+}
+
+/// A single value read from the input.
+enum Field {
+    Year(i128),
+    Century(i128),
+    Month(i128),
+    Day(i128),
+    DayOfYear(i128),
+    Weekday(Weekday),
+    BeforeCommonEra(bool),
+}
+
+/// Every value read from the input so far.
+#[derive(Default)]
+struct Fields {
+    year: Option<i128>,
+    century: Option<i128>,
+    month: Option<i128>,
+    day: Option<i128>,
+    day_of_year: Option<i128>,
+    weekday: Option<Weekday>,
+    before_common_era: Option<bool>,
+}
+
+impl Fields {
+    /// Stores `field`, failing if a different value was already read for it.
+    fn set(&mut self, field: Field) -> Result<(), ()> {
+        fn store<T: PartialEq>(slot: &mut Option<T>, value: T) -> Result<(), ()> {
+            match slot {
+                Some(previous) if *previous != value => Err(()),
+                _ => {
+                    *slot = Some(value);
+                    Ok(())
+                }
+            }
+        }
+
+        match field {
+            Field::Year(year) => store(&mut self.year, year),
+            Field::Century(century) => store(&mut self.century, century),
+            Field::Month(month) => store(&mut self.month, month),
+            Field::Day(day) => store(&mut self.day, day),
+            Field::DayOfYear(day) => store(&mut self.day_of_year, day),
+            Field::Weekday(weekday) => store(&mut self.weekday, weekday),
+            Field::BeforeCommonEra(bce) => store(&mut self.before_common_era, bce),
+        }
+    }
+
+    /// Builds the date described by these fields, checking that they agree with each other.
+    fn into_date(self) -> Result<Date, FormatError> {
+        // With the era written out, numbers are written without a sign.
+        let with_era = |number: i128| match self.before_common_era {
+            Some(true) if number > 0 => Some(-number),
+            Some(_) if number <= 0 => None,
+            _ => Some(number),
+        };
+
+        let year = self.year.ok_or(FormatError::MissingField("year"))?;
+        let year = with_era(year)
+            .and_then(|year| Year::try_from(year).ok())
+            .ok_or(FormatError::Inconsistent("year"))?;
+        if !(Date::MIN.year()..=Date::MAX.year()).contains(&year) {
+            return Err(FormatError::OutOfRange);
+        }
+
+        let date = match (self.month, self.day, self.day_of_year) {
+            (Some(month), Some(day), day_of_year) => {
+                let month = u8::try_from(month)
+                    .map_err(|_| FormatError::Inconsistent("month"))
+                    .and_then(|month| Ok(Month::try_from(month)?))?;
+                let day = u8::try_from(day).map_err(|_| FormatError::Inconsistent("day"))?;
+                let date = Date::from_parts(year, month, day)?;
+                if day_of_year.is_some_and(|day_of_year| day_of_year != i128::from(date.ordinal()))
+                {
+                    return Err(FormatError::Inconsistent("day of the year"));
+                }
+                date
+            }
+            (None, None, Some(day_of_year)) => {
+                let day_of_year = u16::try_from(day_of_year)
+                    .map_err(|_| FormatError::Inconsistent("day of the year"))?;
+                Date::from_ordinal(year, day_of_year)?
+            }
+            (None, _, None) => return Err(FormatError::MissingField("month")),
+            _ => return Err(FormatError::MissingField("day")),
+        };
+
+        if self
+            .weekday
+            .is_some_and(|weekday| weekday != date.weekday())
+        {
+            return Err(FormatError::Inconsistent("weekday"));
+        }
+        if let Some(century) = self.century
+            && with_era(century) != Some(date.year().century())
+        {
+            return Err(FormatError::Inconsistent("century"));
+        }
+
+        Ok(date)
+    }
+}
+
+/// Reads a decimal number of at most `max_digits` digits from the start of `input`, returning how many bytes it took.
+fn number(input: &str, max_digits: usize, allow_sign: bool) -> Option<(usize, i128)> {
+    let sign = usize::from(allow_sign && input.starts_with('-'));
+    let digits = input[sign..]
+        .bytes()
+        .take(max_digits)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    let len = sign + digits;
+    input[..len].parse().ok().map(|number| (len, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::date::gregorian::{Date, Month, year};
+    use crate::format::FormatError;
+    use crate::locale::Locale;
+
+    #[test]
+    fn round_trip() {
+        let dates = [
+            Date::from_parts(year!(2008), Month::April, 22).unwrap(),
+            Date::from_parts(year!(2000), Month::February, 29).unwrap(),
+            Date::from_parts(year!(1), Month::January, 1).unwrap(),
+            Date::from_parts(year!(-1), Month::December, 31).unwrap(),
+            Date::from_parts(year!(-753), Month::April, 21).unwrap(),
+            Date::from_parts(year!(-44), Month::March, 15).unwrap(),
+        ];
+        let formats = [
+            "%Y-%m-%d",
+            "%d/%m/%Y",
+            "%A, %B %e, %Y",
+            "%a %e %b %Y %E",
+            "%Y.%j",
+            "%C: %e.%m.%Y",
+            "%E %Y, day %j (%A)",
+            "100%% %d %B %Y",
+        ];
+
+        for date in dates {
+            for format in formats {
+                let formatted = date.format(format).unwrap();
+                assert_eq!(Date::parse(format, &formatted), Ok(date), "{formatted}");
+            }
+        }
+    }
+
+    #[test]
+    fn localized_round_trip() {
+        let date = Date::from_parts(year!(-44), Month::March, 15).unwrap();
+        for locale in [Locale::En, Locale::PtBr, Locale::Es, Locale::Fr, Locale::De] {
+            for format in ["%A %e %B %Y %E", "%a %d %b %Y"] {
+                let formatted = date.format_localized(format, locale).unwrap();
+                assert_eq!(
+                    Date::parse_localized(format, &formatted, locale),
+                    Ok(date),
+                    "{formatted}"
+                );
+            }
+        }
+
+        assert_eq!(
+            date.format_localized("%A %e %B", Locale::De).unwrap(),
+            "Freitag 15 März"
+        );
+        assert_eq!(
+            Date::parse_localized("%e %B %Y", "15 MARÇO 2024", Locale::PtBr),
+            Ok(Date::from_parts(year!(2024), Month::March, 15).unwrap())
+        );
+        assert!(Date::parse_localized("%e %B %Y", "15 March 2024", Locale::PtBr).is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Date::parse("%d/%m/%Y", "22-04-2008"),
+            Err(FormatError::Mismatch { position: 2 })
+        );
+        assert_eq!(
+            Date::parse("%d/%m/%Y", "22/04/2008 "),
+            Err(FormatError::Mismatch { position: 10 })
+        );
+        assert_eq!(
+            Date::parse("%d/%m", "22/04"),
+            Err(FormatError::MissingField("year"))
+        );
+        assert_eq!(
+            Date::parse("%Y-%d", "2008-22"),
+            Err(FormatError::MissingField("month"))
+        );
+        assert_eq!(
+            Date::parse("%Y-%m-%d %C", "2008-01-01 20"),
+            Err(FormatError::Inconsistent("century"))
+        );
+        assert_eq!(
+            Date::parse("%Y %E", "-44 BCE"),
+            Err(FormatError::Inconsistent("year"))
+        );
+        assert_eq!(
+            Date::parse("%Y %m %m", "2008 04 05"),
+            Err(FormatError::Mismatch { position: 8 })
+        );
+        assert!(matches!(
+            Date::parse("%Y-%m-%d", "1900-02-29"),
+            Err(FormatError::InvalidDate(_))
+        ));
+        assert!(matches!(
+            Date::parse("%Y-%j", "1900-366"),
+            Err(FormatError::InvalidDate(_))
+        ));
+        assert_eq!(
+            Date::parse("%Y-%m-%d %q", "1900-02-28 q"),
+            Err(FormatError::UnknownDirective {
+                position: 9,
+                directive: 'q'
+            })
+        );
+        for input in [
+            "Monday 170141183460469231731687303715884105727-12-31",
+            "Monday -170141183460469231731687303715884105727-01-01",
+        ] {
+            assert_eq!(
+                Date::parse("%A %Y-%m-%d", input),
+                Err(FormatError::OutOfRange),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,398 @@
+//! Iteration over ranges of [`Date`]s and [`Year`]s.
+
+use super::{Date, Year};
+use crate::StandardCalendar;
+
+/// Iterator over every day from one [`Date`] to another, both inclusive, created by [`Date::iter_to`].
+///
+/// If there are more days left than fit in a [`usize`], [`len`](ExactSizeIterator::len) saturates at
+/// [`usize::MAX`] and [`size_hint`](Iterator::size_hint) has no upper bound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateIter {
+    /// The next day to yield from the front, in [`StandardCalendar`] days.
+    front: i128,
+    /// The next day to yield from the back, in [`StandardCalendar`] days.
+    back: i128,
+}
+
+impl DateIter {
+    /// Turns this iterator into one over the same day of every `months`th month, starting from its next day and
+    /// up to its last one.
+    ///
+    /// Days that don't exist in a month are clamped to its last day, without affecting the following months: from
+    /// January 31st, a step of 1 month gives February 28th (or 29th), then March 31st.
+    ///
+    /// # Panics
+    /// If `months` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Calendar, date::gregorian::{Date, Month, year}};
+    ///
+    /// let start = Date::from_parts(year!(2024), Month::January, 31).unwrap();
+    /// let end = Date::from_parts(year!(2024), Month::April, 30).unwrap();
+    /// let days: Vec<_> = start.iter_to(&end).step_by_months(1).map(|date| (date.month(), date.day())).collect();
+    /// assert_eq!(
+    ///     days,
+    ///     [(Month::January, 31), (Month::February, 29), (Month::March, 31), (Month::April, 30)]
+    /// );
+    /// ```
+    pub fn step_by_months(self, months: u32) -> MonthSteps {
+        self.steps(i128::from(months))
+    }
+
+    /// Turns this iterator into one over the same day of every `years`th year, starting from its next day and up
+    /// to its last one.
+    ///
+    /// February 29th is clamped to February 28th in common years, like in [`DateIter::step_by_months`].
+    ///
+    /// # Panics
+    /// If `years` is 0.
+    pub fn step_by_years(self, years: u32) -> MonthSteps {
+        self.steps(i128::from(years) * 12)
+    }
+
+    fn steps(self, months: i128) -> MonthSteps {
+        assert!(months > 0, "the step must be positive");
+        MonthSteps {
+            start: StandardCalendar::new(self.front).into(),
+            end: StandardCalendar::new(self.back).into(),
+            months,
+            index: 0,
+        }
+    }
+
+    /// How many days are left, if it fits in a [`usize`].
+    fn remaining(&self) -> Option<usize> {
+        let remaining = self
+            .back
+            .saturating_sub(self.front)
+            .saturating_add(1)
+            .max(0);
+        usize::try_from(remaining).ok()
+    }
+}
+
+impl Iterator for DateIter {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let day = self.front.checked_add(n as i128)?;
+        if day > self.back {
+            self.front = self.back + 1;
+            return None;
+        }
+        self.front = day + 1;
+        Some(StandardCalendar::new(day).into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedIterator for DateIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(StandardCalendar::new(self.back + 1).into())
+    }
+}
+
+impl ExactSizeIterator for DateIter {
+    fn len(&self) -> usize {
+        self.remaining().unwrap_or(usize::MAX)
+    }
+}
+
+/// Iterator over the same day of evenly spaced months, created by [`DateIter::step_by_months`] and
+/// [`DateIter::step_by_years`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonthSteps {
+    start: Date,
+    /// The last day that may be yielded.
+    end: Date,
+    months: i128,
+    /// How many steps have been taken from `start`.
+    index: i128,
+}
+
+impl Iterator for MonthSteps {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.start.months_after(self.index * self.months);
+        if date > self.end {
+            return None;
+        }
+        self.index += 1;
+        Some(date)
+    }
+}
+
+/// Iterator over every year from one [`Year`] to another, both inclusive, created by [`Year::iter_to`].
+///
+/// Sizes saturate like those of [`DateIter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearIter {
+    /// The next year to yield from the front, as an [astronomical](Year::to_astronomical) year.
+    front: i128,
+    /// The next year to yield from the back, as an [astronomical](Year::to_astronomical) year.
+    back: i128,
+}
+
+impl YearIter {
+    fn remaining(&self) -> Option<usize> {
+        let remaining = self
+            .back
+            .saturating_sub(self.front)
+            .saturating_add(1)
+            .max(0);
+        usize::try_from(remaining).ok()
+    }
+}
+
+impl Iterator for YearIter {
+    type Item = Year;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let year = self.front;
+        if year > self.back {
+            return None;
+        }
+        if year == self.back {
+            // The front can't move past `i128::MAX`, but the back is never below the smallest year.
+            self.back -= 1;
+        } else {
+            self.front += 1;
+        }
+        Some(Year::from_astronomical(year))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedIterator for YearIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(Year::from_astronomical(self.back + 1))
+    }
+}
+
+impl ExactSizeIterator for YearIter {
+    fn len(&self) -> usize {
+        self.remaining().unwrap_or(usize::MAX)
+    }
+}
+
+impl Date {
+    /// Iterates over every day from this date to `end`, both inclusive.
+    ///
+    /// If `end` is before this date, nothing is yielded.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// let start = Date::from_parts(year!(2024), Month::February, 28).unwrap();
+    /// let end = Date::from_parts(year!(2024), Month::March, 1).unwrap();
+    /// let mut days = start.iter_to(&end);
+    ///
+    /// assert_eq!(days.len(), 3);
+    /// assert_eq!(days.next(), Some(start));
+    /// assert_eq!(days.next_back(), Some(end));
+    /// assert_eq!(days.next(), Date::from_parts(year!(2024), Month::February, 29).ok());
+    /// assert_eq!(days.next(), None);
+    /// ```
+    pub fn iter_to(&self, end: &Date) -> DateIter {
+        DateIter {
+            front: StandardCalendar::from(self).days,
+            back: StandardCalendar::from(end).days,
+        }
+    }
+}
+
+impl Year {
+    /// Iterates over every year from this one to `end`, both inclusive, skipping the nonexistent year 0.
+    ///
+    /// If `end` is before this year, nothing is yielded.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::year;
+    ///
+    /// let years: Vec<_> = year!(-2).iter_to(year!(2)).collect();
+    /// assert_eq!(years, [year!(-2), year!(-1), year!(1), year!(2)]);
+    /// ```
+    pub fn iter_to(self, end: Year) -> YearIter {
+        YearIter {
+            front: self.to_astronomical(),
+            back: end.to_astronomical(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{Month, year};
+
+    fn date(year: Year, month: Month, day: u8) -> Date {
+        Date::from_parts(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn days() {
+        let leap_february: Vec<_> = date(year!(2024), Month::February, 27)
+            .iter_to(&date(year!(2024), Month::March, 1))
+            .collect();
+        assert_eq!(
+            leap_february,
+            [
+                date(year!(2024), Month::February, 27),
+                date(year!(2024), Month::February, 28),
+                date(year!(2024), Month::February, 29),
+                date(year!(2024), Month::March, 1),
+            ]
+        );
+
+        let era_boundary: Vec<_> = date(year!(-1), Month::December, 30)
+            .iter_to(&date(year!(1), Month::January, 2))
+            .rev()
+            .collect();
+        assert_eq!(
+            era_boundary,
+            [
+                date(year!(1), Month::January, 2),
+                date(year!(1), Month::January, 1),
+                date(year!(-1), Month::December, 31),
+                date(year!(-1), Month::December, 30),
+            ]
+        );
+
+        let mut backwards =
+            date(year!(2024), Month::March, 1).iter_to(&date(year!(2024), Month::February, 1));
+        assert_eq!(backwards.len(), 0);
+        assert_eq!(backwards.next(), None);
+        assert_eq!(backwards.next_back(), None);
+
+        let mut year =
+            date(year!(2023), Month::January, 1).iter_to(&date(year!(2023), Month::December, 31));
+        assert_eq!(year.len(), 365);
+        assert_eq!(year.nth(364), Some(date(year!(2023), Month::December, 31)));
+        assert_eq!(year.len(), 0);
+    }
+
+    #[test]
+    fn sizes_saturate() {
+        // Far too many days to build from dates in a test.
+        let days = DateIter {
+            front: i128::MIN,
+            back: 0,
+        };
+        assert_eq!(days.len(), usize::MAX);
+        assert_eq!(days.size_hint(), (usize::MAX, None));
+
+        let years = year!(-170_141_183_460_469_231_731_687_303_715_884_105_727).iter_to(year!(1));
+        assert_eq!(years.len(), usize::MAX);
+    }
+
+    #[test]
+    fn month_and_year_steps() {
+        let monthly: Vec<_> = date(year!(2024), Month::January, 31)
+            .iter_to(&date(year!(2024), Month::June, 30))
+            .step_by_months(1)
+            .collect();
+        assert_eq!(
+            monthly,
+            [
+                date(year!(2024), Month::January, 31),
+                date(year!(2024), Month::February, 29),
+                date(year!(2024), Month::March, 31),
+                date(year!(2024), Month::April, 30),
+                date(year!(2024), Month::May, 31),
+                date(year!(2024), Month::June, 30),
+            ]
+        );
+
+        let quarterly: Vec<_> = date(year!(-1), Month::November, 15)
+            .iter_to(&date(year!(1), Month::August, 14))
+            .step_by_months(3)
+            .collect();
+        assert_eq!(
+            quarterly,
+            [
+                date(year!(-1), Month::November, 15),
+                date(year!(1), Month::February, 15),
+                date(year!(1), Month::May, 15),
+            ]
+        );
+
+        let yearly: Vec<_> = date(year!(2024), Month::February, 29)
+            .iter_to(&date(year!(2028), Month::February, 29))
+            .step_by_years(2)
+            .collect();
+        assert_eq!(
+            yearly,
+            [
+                date(year!(2024), Month::February, 29),
+                date(year!(2026), Month::February, 28),
+                date(year!(2028), Month::February, 29),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_step() {
+        let day = date(year!(2024), Month::January, 1);
+        day.iter_to(&day).step_by_months(0);
+    }
+
+    #[test]
+    fn years() {
+        let mut years = year!(-2).iter_to(year!(2));
+        assert_eq!(years.len(), 4);
+        assert_eq!(years.next_back(), Some(year!(2)));
+        assert_eq!(years.next(), Some(year!(-2)));
+        assert_eq!(years.collect::<Vec<_>>(), [year!(-1), year!(1)]);
+
+        assert_eq!(year!(5).iter_to(year!(4)).next(), None);
+
+        // The furthest years, whose astronomical numbers are the ends of `i128`.
+        let (first, last) = (
+            Year::from_astronomical(i128::MIN + 1),
+            Year::from_astronomical(i128::MAX),
+        );
+        let mut years = last.iter_to(last);
+        assert_eq!(years.next(), Some(last));
+        assert_eq!(years.next(), None);
+        assert_eq!(years.next_back(), None);
+        let mut years = first.iter_to(first);
+        assert_eq!(years.next_back(), Some(first));
+        assert_eq!(years.next(), None);
+        assert_eq!(years.next_back(), None);
+        let before_last = Year::from_astronomical(i128::MAX - 1);
+        let last_two = before_last.iter_to(last);
+        assert_eq!(last_two.collect::<Vec<_>>(), [before_last, last]);
+        let first_two = first.iter_to(Year::from_astronomical(i128::MIN + 2));
+        assert_eq!(first_two.rev().count(), 2);
+    }
+}
@@ -0,0 +1,264 @@
+//! Dates of which only some parts are known, such as "1914" or "July 1969".
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use super::errors::PartialDateParseError;
+use super::{Date, Month, Year};
+use crate::approximate::{ApproximateDate, Precision};
+use crate::calendar::Calendar;
+
+/// A date that may be missing its day, or both its day and month.
+///
+/// Partial dates are ordered by their parts, with a missing part sorting before any value of it: "1969" is before
+/// "January 1969", which is before "1 January 1969".
+///
+/// # Examples
+/// ```
+/// use time::date::gregorian::{Date, Month, partial::PartialDate, year};
+///
+/// let landing: PartialDate = "July 1969".parse().unwrap();
+/// assert_eq!(landing, PartialDate::YearMonth(year!(1969), Month::July));
+/// assert_eq!(
+///     landing.range(),
+///     (
+///         Date::from_parts(year!(1969), Month::July, 1).unwrap(),
+///         Date::from_parts(year!(1969), Month::July, 31).unwrap()
+///     )
+/// );
+///
+/// assert!(PartialDate::Year(year!(1969)) < landing);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialDate {
+    Year(Year),
+    YearMonth(Year, Month),
+    Full(Date),
+}
+
+impl PartialDate {
+    /// The year, which is always known.
+    pub fn year(&self) -> Year {
+        match self {
+            Self::Year(year) | Self::YearMonth(year, _) => *year,
+            Self::Full(date) => date.year(),
+        }
+    }
+
+    /// The month, if it is known.
+    pub fn month(&self) -> Option<Month> {
+        match self {
+            Self::Year(_) => None,
+            Self::YearMonth(_, month) => Some(*month),
+            Self::Full(date) => Some(date.month()),
+        }
+    }
+
+    /// The day of the month, if it is known.
+    pub fn day(&self) -> Option<u8> {
+        match self {
+            Self::Full(date) => Some(date.day()),
+            _ => None,
+        }
+    }
+
+    /// The earliest and latest dates this could be, both inclusive.
+    pub fn range(&self) -> (Date, Date) {
+        let date = ApproximateDate::from(*self);
+        (date.start(), date.end())
+    }
+}
+
+impl From<Date> for PartialDate {
+    fn from(date: Date) -> Self {
+        Self::Full(date)
+    }
+}
+
+impl From<PartialDate> for ApproximateDate {
+    /// A [`PartialDate`] is known up to its most precise part.
+    fn from(date: PartialDate) -> Self {
+        match date {
+            PartialDate::Year(year) => Self::new(Date::from_year(year), Precision::Year),
+            // The 1st is valid in every month.
+            PartialDate::YearMonth(year, month) => {
+                Self::new(Date::from_parts(year, month, 1).unwrap(), Precision::Month)
+            }
+            PartialDate::Full(date) => Self::from(date),
+        }
+    }
+}
+
+impl PartialOrd for PartialDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PartialDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `None` sorts before any `Some`, which puts missing parts first.
+        (self.year(), self.month(), self.day()).cmp(&(other.year(), other.month(), other.day()))
+    }
+}
+
+impl fmt::Display for PartialDate {
+    /// Writes the known parts with the month name in English, like "1914", "July 1969" or "20 July 1969".
+    ///
+    /// The year follows [`Year`]'s [`Display`](fmt::Display), including its alternate form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day() {
+            write!(f, "{day} ")?;
+        }
+        if let Some(month) = self.month() {
+            write!(f, "{} ", month.name())?;
+        }
+        fmt::Display::fmt(&self.year(), f)
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = PartialDateParseError;
+
+    /// Reads the forms written by [`Display`](fmt::Display), with the month either in full or abbreviated, in any
+    /// case. The year can be written in any form [`Year`] parses.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, partial::PartialDate, year};
+    ///
+    /// assert_eq!("1914".parse(), Ok(PartialDate::Year(year!(1914))));
+    /// assert_eq!("mar 44 BC".parse(), Ok(PartialDate::YearMonth(year!(-44), Month::March)));
+    /// assert_eq!(
+    ///     "20 July 1969".parse(),
+    ///     Ok(PartialDate::Full(Date::from_parts(year!(1969), Month::July, 20).unwrap()))
+    /// );
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let month = |word: &str| {
+            Month::ALL.into_iter().find(|month| {
+                month.name().eq_ignore_ascii_case(word)
+                    || month.abbreviation().eq_ignore_ascii_case(word)
+            })
+        };
+        let mut words = input.splitn(3, char::is_whitespace);
+        let (first, second) = (
+            words.next().unwrap_or_default(),
+            words.next().unwrap_or_default(),
+        );
+
+        if let Some(month) = month(first) {
+            let year = input[first.len()..].parse()?;
+            return Ok(Self::YearMonth(year, month));
+        }
+        if let Some(month) = month(second)
+            && let Ok(day) = first.parse()
+        {
+            let year = words.next().unwrap_or_default().parse()?;
+            return Ok(Self::Full(Date::from_parts(year, month, day)?));
+        }
+        Ok(Self::Year(input.parse()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{
+        errors::{DateCreationError, YearParseError},
+        year,
+    };
+
+    fn date(year: Year, month: Month, day: u8) -> Date {
+        Date::from_parts(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn ordering() {
+        let sorted = [
+            PartialDate::Year(year!(-44)),
+            PartialDate::Full(date(year!(-44), Month::March, 15)),
+            PartialDate::Year(year!(1969)),
+            PartialDate::YearMonth(year!(1969), Month::January),
+            PartialDate::Full(date(year!(1969), Month::January, 1)),
+            PartialDate::Full(date(year!(1969), Month::January, 2)),
+            PartialDate::YearMonth(year!(1969), Month::July),
+            PartialDate::Full(date(year!(1969), Month::July, 20)),
+            PartialDate::Year(year!(1970)),
+        ];
+        for pair in sorted.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            PartialDate::Year(year!(-1)).range(),
+            (
+                date(year!(-1), Month::January, 1),
+                date(year!(-1), Month::December, 31)
+            )
+        );
+        assert_eq!(
+            PartialDate::YearMonth(year!(2024), Month::February).range(),
+            (
+                date(year!(2024), Month::February, 1),
+                date(year!(2024), Month::February, 29)
+            )
+        );
+        let day = date(year!(1969), Month::July, 20);
+        assert_eq!(PartialDate::Full(day).range(), (day, day));
+    }
+
+    #[test]
+    fn round_trip() {
+        let cases = [
+            (PartialDate::Year(year!(1914)), "1914"),
+            (PartialDate::Year(year!(-500)), "500 BCE"),
+            (
+                PartialDate::YearMonth(year!(1969), Month::July),
+                "July 1969",
+            ),
+            (
+                PartialDate::YearMonth(year!(-44), Month::March),
+                "March 44 BCE",
+            ),
+            (
+                PartialDate::Full(date(year!(1969), Month::July, 20)),
+                "20 July 1969",
+            ),
+            (
+                PartialDate::Full(date(year!(-44), Month::March, 15)),
+                "15 March 44 BCE",
+            ),
+        ];
+        for (date, text) in cases {
+            assert_eq!(date.to_string(), text);
+            assert_eq!(text.parse(), Ok(date), "{text}");
+            assert_eq!(format!("{date:#}").parse(), Ok(date), "{date:#}");
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let cases = [
+            ("", PartialDateParseError::Year(YearParseError::Invalid)),
+            ("July", PartialDateParseError::Year(YearParseError::Invalid)),
+            ("July 0", PartialDateParseError::Year(YearParseError::Zero)),
+            (
+                "20 July",
+                PartialDateParseError::Year(YearParseError::Invalid),
+            ),
+            (
+                "30 February 2024",
+                PartialDateParseError::InvalidDate(DateCreationError::InvalidDay(30)),
+            ),
+        ];
+        for (text, error) in cases {
+            assert_eq!(text.parse::<PartialDate>(), Err(error), "{text}");
+        }
+    }
+}
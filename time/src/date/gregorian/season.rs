@@ -0,0 +1,240 @@
+//! Seasons of the year, for each [`Hemisphere`].
+
+use super::{Date, Month, Year};
+
+/// One of the four seasons of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// Which half of the Earth a date refers to, since seasons are flipped between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Hemisphere {
+    #[default]
+    Northern,
+    Southern,
+}
+
+/// Where the boundaries between seasons are placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SeasonRule {
+    /// Seasons start on the first day of March, June, September and December.
+    #[default]
+    Meteorological,
+    /// Seasons start on the (approximate) equinoxes and solstices: March 20th, June 21st, September 22nd and
+    /// December 21st.
+    ///
+    /// The actual dates move by a day or so between years, which is not taken into account.
+    Astronomical,
+}
+
+impl SeasonRule {
+    /// The first day of spring, summer, autumn and winter in the northern hemisphere.
+    fn boundaries(self) -> [(Month, u8); 4] {
+        match self {
+            Self::Meteorological => [
+                (Month::March, 1),
+                (Month::June, 1),
+                (Month::September, 1),
+                (Month::December, 1),
+            ],
+            Self::Astronomical => [
+                (Month::March, 20),
+                (Month::June, 21),
+                (Month::September, 22),
+                (Month::December, 21),
+            ],
+        }
+    }
+}
+
+impl Season {
+    /// Spring, summer, autumn and winter, in order.
+    const ALL: [Season; 4] = [Self::Spring, Self::Summer, Self::Autumn, Self::Winter];
+
+    /// The season happening in the other hemisphere at the same time.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::season::Season;
+    ///
+    /// assert_eq!(Season::Summer.opposite(), Season::Winter);
+    /// assert_eq!(Season::Autumn.opposite(), Season::Spring);
+    /// ```
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Spring => Self::Autumn,
+            Self::Summer => Self::Winter,
+            Self::Autumn => Self::Spring,
+            Self::Winter => Self::Summer,
+        }
+    }
+
+    /// The dates of the season that starts in `year`, from its first day (inclusive) to the first day of the next
+    /// season (exclusive).
+    ///
+    /// Seasons that cross the new year (like winter in the northern hemisphere) end in the following year.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, season::{Hemisphere, Season, SeasonRule}, year};
+    ///
+    /// assert_eq!(
+    ///     Season::Winter.date_range(year!(2023), Hemisphere::Northern, SeasonRule::Meteorological),
+    ///     (
+    ///         Date::from_parts(year!(2023), Month::December, 1).unwrap(),
+    ///         Date::from_parts(year!(2024), Month::March, 1).unwrap()
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     Season::Winter.date_range(year!(2024), Hemisphere::Southern, SeasonRule::Astronomical),
+    ///     (
+    ///         Date::from_parts(year!(2024), Month::June, 21).unwrap(),
+    ///         Date::from_parts(year!(2024), Month::September, 22).unwrap()
+    ///     )
+    /// );
+    /// ```
+    pub fn date_range(self, year: Year, hemisphere: Hemisphere, rule: SeasonRule) -> (Date, Date) {
+        // Boundaries are stored for the northern hemisphere, where the southern seasons are flipped.
+        let northern = match hemisphere {
+            Hemisphere::Northern => self,
+            Hemisphere::Southern => self.opposite(),
+        };
+        let boundaries = rule.boundaries();
+        let index = northern as usize;
+        let (start_month, start_day) = boundaries[index];
+        let (end_year, (end_month, end_day)) = match boundaries.get(index + 1) {
+            Some(end) => (year, *end),
+            None => (year.next(), boundaries[0]),
+        };
+
+        // All boundaries are valid days in every year.
+        (
+            Date::from_parts(year, start_month, start_day).unwrap(),
+            Date::from_parts(end_year, end_month, end_day).unwrap(),
+        )
+    }
+}
+
+impl Date {
+    /// Which season this date is in, using [meteorological](SeasonRule::Meteorological) boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, season::{Hemisphere, Season}, year};
+    ///
+    /// let date = Date::from_parts(year!(1969), Month::July, 20).unwrap();
+    /// assert_eq!(date.season(Hemisphere::Northern), Season::Summer);
+    /// assert_eq!(date.season(Hemisphere::Southern), Season::Winter);
+    /// ```
+    pub fn season(&self, hemisphere: Hemisphere) -> Season {
+        self.season_by(hemisphere, SeasonRule::Meteorological)
+    }
+
+    /// Which season this date is in, using the boundaries given by `rule`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, season::{Hemisphere, Season, SeasonRule}, year};
+    ///
+    /// let date = Date::from_parts(year!(2024), Month::March, 10).unwrap();
+    /// assert_eq!(date.season_by(Hemisphere::Northern, SeasonRule::Meteorological), Season::Spring);
+    /// assert_eq!(date.season_by(Hemisphere::Northern, SeasonRule::Astronomical), Season::Winter);
+    /// ```
+    pub fn season_by(&self, hemisphere: Hemisphere, rule: SeasonRule) -> Season {
+        let boundaries = rule.boundaries();
+        // The last boundary that isn't after this date. Before the first one, it's still last year's winter.
+        let northern = boundaries
+            .iter()
+            .rposition(|start| *start <= (self.month, self.day))
+            .map_or(Season::Winter, |index| Season::ALL[index]);
+
+        match hemisphere {
+            Hemisphere::Northern => northern,
+            Hemisphere::Southern => northern.opposite(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::year;
+
+    #[test]
+    fn boundary_days() {
+        use Month::*;
+        use Season::*;
+
+        let meteorological = [
+            (February, 28, Winter),
+            (March, 1, Spring),
+            (May, 31, Spring),
+            (June, 1, Summer),
+            (August, 31, Summer),
+            (September, 1, Autumn),
+            (November, 30, Autumn),
+            (December, 1, Winter),
+            (January, 1, Winter),
+        ];
+        let astronomical = [
+            (March, 19, Winter),
+            (March, 20, Spring),
+            (June, 20, Spring),
+            (June, 21, Summer),
+            (September, 21, Summer),
+            (September, 22, Autumn),
+            (December, 20, Autumn),
+            (December, 21, Winter),
+        ];
+
+        for (rule, cases) in [
+            (SeasonRule::Meteorological, &meteorological[..]),
+            (SeasonRule::Astronomical, &astronomical[..]),
+        ] {
+            for (month, day, northern) in cases {
+                let date = Date::from_parts(year!(2023), *month, *day).unwrap();
+                assert_eq!(
+                    date.season_by(Hemisphere::Northern, rule),
+                    *northern,
+                    "{date:?}"
+                );
+                assert_eq!(
+                    date.season_by(Hemisphere::Southern, rule),
+                    northern.opposite(),
+                    "{date:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn date_ranges_agree_with_season() {
+        for rule in [SeasonRule::Meteorological, SeasonRule::Astronomical] {
+            for hemisphere in [Hemisphere::Northern, Hemisphere::Southern] {
+                for season in Season::ALL {
+                    let (start, end) = season.date_range(year!(2024), hemisphere, rule);
+                    let mut before_end = end;
+                    crate::Calendar::add_days(&mut before_end, -1);
+
+                    assert_eq!(start.season_by(hemisphere, rule), season);
+                    assert_eq!(before_end.season_by(hemisphere, rule), season);
+                    assert_ne!(end.season_by(hemisphere, rule), season);
+                }
+            }
+        }
+
+        // Southern summer crosses the new year.
+        assert_eq!(
+            Season::Summer.date_range(year!(-1), Hemisphere::Southern, SeasonRule::Meteorological),
+            (
+                Date::from_parts(year!(-1), Month::December, 1).unwrap(),
+                Date::from_parts(year!(1), Month::March, 1).unwrap()
+            )
+        );
+    }
+}
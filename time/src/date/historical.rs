@@ -0,0 +1,482 @@
+//! Dates as they were recorded: in the [Julian Calendar](https://en.wikipedia.org/wiki/Julian_calendar) before a
+//! region adopted the Gregorian Calendar, and in the [Gregorian Calendar](super::gregorian) after.
+//!
+//! Adopting the Gregorian Calendar skipped the days the Julian Calendar had drifted by, which never existed in that
+//! region: in Rome, October 4th of 1582 was followed by October 15th. Regions adopted it at different times, so
+//! [`Date`] is parameterized by a [`Switchover`].
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use super::gregorian::{self, Month, Year, date, errors::DateCreationError};
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+
+/// When a region switched from the Julian to the Gregorian Calendar.
+///
+/// Implement this on your own type for regions not covered by [`Catholic`], [`Britain`] and [`Russia`].
+///
+/// # Examples
+/// ```
+/// use time::date::{gregorian::{self, date}, historical::Switchover};
+///
+/// /// Sweden, after its return to the Julian Calendar.
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Sweden;
+///
+/// impl Switchover for Sweden {
+///     const FIRST_GREGORIAN: gregorian::Date = date!(1753-03-01);
+/// }
+/// ```
+pub trait Switchover {
+    /// The first day on the Gregorian Calendar. The day before it is the last one on the Julian Calendar.
+    ///
+    /// This must be after the 3rd century, while the Julian Calendar is behind the Gregorian one, so that the
+    /// switch skips days instead of repeating them.
+    const FIRST_GREGORIAN: gregorian::Date;
+}
+
+/// The switch made by the Papal States, Spain, Portugal and Poland, after Thursday, October 4th of 1582.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Catholic;
+
+impl Switchover for Catholic {
+    const FIRST_GREGORIAN: gregorian::Date = date!(1582 - 10 - 15);
+}
+
+/// The switch made by Great Britain and its colonies, after Wednesday, September 2nd of 1752.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Britain;
+
+impl Switchover for Britain {
+    const FIRST_GREGORIAN: gregorian::Date = date!(1752 - 09 - 14);
+}
+
+/// The switch made by Soviet Russia, after Wednesday, January 31st of 1918.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Russia;
+
+impl Switchover for Russia {
+    const FIRST_GREGORIAN: gregorian::Date = date!(1918 - 02 - 14);
+}
+
+/// The error type for creating a historical [`Date`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoricalDateError {
+    /// The date doesn't exist in the calendar in use at the time, such as February 29th of a common year.
+    InvalidDate(DateCreationError),
+    /// The date was skipped by the switch to the Gregorian Calendar.
+    Skipped,
+}
+
+impl fmt::Display for HistoricalDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+            Self::Skipped => {
+                f.write_str("the date was skipped by the switch to the Gregorian Calendar")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoricalDateError {}
+
+impl From<DateCreationError> for HistoricalDateError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
+
+/// A date in the calendar in use at the time, which switched from Julian to Gregorian as given by `S`.
+///
+/// Years are numbered from January 1st, as is usual today, even where the year started on another day at the time.
+///
+/// # Examples
+/// ```
+/// use time::{calendar::ConvertCalendar, date::{gregorian::{self, Month, year}, historical::{Britain, Date}}};
+///
+/// // George Washington's birthday, as recorded at the time and in today's calendar.
+/// let birth = Date::<Britain>::from_parts(year!(1732), Month::February, 11).unwrap();
+/// assert!(!birth.is_gregorian());
+/// assert_eq!(birth.convert_to::<gregorian::Date>(), gregorian::date!(1732-02-22));
+/// ```
+pub struct Date<S> {
+    year: Year,
+    month: Month,
+    day: u8,
+    switchover: PhantomData<S>,
+}
+
+impl<S: Switchover> Date<S> {
+    /// Creates a date from the day, month and year, in the calendar in use at the time.
+    ///
+    /// # Errors
+    /// * [`HistoricalDateError::InvalidDate`] if the day doesn't exist in the month, in the calendar in use.
+    /// * [`HistoricalDateError::Skipped`] for days skipped by the switch.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::{gregorian::{Month, year}, historical::{Catholic, Date, HistoricalDateError}};
+    ///
+    /// assert!(Date::<Catholic>::from_parts(year!(1582), Month::October, 4).is_ok());
+    /// assert_eq!(Date::<Catholic>::from_parts(year!(1582), Month::October, 10), Err(HistoricalDateError::Skipped));
+    /// assert!(Date::<Catholic>::from_parts(year!(1582), Month::October, 15).is_ok());
+    ///
+    /// // A leap day in the Julian Calendar, but not in the Gregorian one.
+    /// assert!(Date::<Catholic>::from_parts(year!(1500), Month::February, 29).is_ok());
+    /// assert!(Date::<Catholic>::from_parts(year!(1700), Month::February, 29).is_err());
+    /// ```
+    pub fn from_parts(year: Year, month: Month, day: u8) -> Result<Self, HistoricalDateError> {
+        let date = Self {
+            year,
+            month,
+            day,
+            switchover: PhantomData,
+        };
+        if date.is_gregorian() {
+            gregorian::Date::from_parts(year, month, day)?;
+            return Ok(date);
+        }
+        if day < 1 || day > julian_month_length(year, month) {
+            return Err(DateCreationError::InvalidDay(day).into());
+        }
+        if julian_to_days(year, month, day) >= StandardCalendar::from(&S::FIRST_GREGORIAN).days {
+            return Err(HistoricalDateError::Skipped);
+        }
+        Ok(date)
+    }
+
+    /// Whether this date is on the Gregorian Calendar, rather than the Julian one.
+    pub fn is_gregorian(&self) -> bool {
+        // The switch skips days, so every Julian date before it has smaller parts than the first Gregorian one.
+        (self.year, self.month, self.day)
+            >= (
+                S::FIRST_GREGORIAN.year(),
+                S::FIRST_GREGORIAN.month(),
+                S::FIRST_GREGORIAN.day(),
+            )
+    }
+}
+
+impl<S: Switchover> From<&Date<S>> for StandardCalendar {
+    fn from(date: &Date<S>) -> Self {
+        if date.is_gregorian() {
+            // Valid in the Gregorian Calendar, as checked when the date was created.
+            let gregorian = gregorian::Date::from_parts(date.year, date.month, date.day).unwrap();
+            return StandardCalendar::from(&gregorian);
+        }
+        StandardCalendar::new(julian_to_days(date.year, date.month, date.day))
+    }
+}
+
+impl<S: Switchover> From<StandardCalendar> for Date<S> {
+    fn from(standard: StandardCalendar) -> Self {
+        let (year, month, day) = if standard >= StandardCalendar::from(&S::FIRST_GREGORIAN) {
+            let gregorian = gregorian::Date::from(standard);
+            (gregorian.year(), gregorian.month(), gregorian.day())
+        } else {
+            julian_from_days(standard.days)
+        };
+        Self {
+            year,
+            month,
+            day,
+            switchover: PhantomData,
+        }
+    }
+}
+
+impl<S: Switchover> Calendar for Date<S> {
+    type Month = Month;
+    type Day = u8;
+    type Year = Year;
+
+    fn day(&self) -> Self::Day {
+        self.day
+    }
+
+    fn month(&self) -> Self::Month {
+        self.month
+    }
+
+    fn year(&self) -> Self::Year {
+        self.year
+    }
+
+    /// January 1st of year 1 in the Gregorian Calendar, which is January 3rd of year 1 in the Julian one.
+    fn reference_date() -> Self {
+        StandardCalendar::new(0).into()
+    }
+
+    fn add_days(&mut self, days: i128) {
+        *self = StandardCalendar::new(self.as_days() + days).into();
+    }
+
+    fn as_days(&self) -> i128 {
+        StandardCalendar::from(self).days
+    }
+
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        (second.as_days() - first.as_days()).abs()
+    }
+
+    /// Whether the year has a February 29th, in the calendar in use at the time.
+    fn is_leap_year(year: Self::Year) -> bool {
+        Self::from_parts(year, Month::February, 29).is_ok()
+    }
+
+    fn months_of(_: Self::Year) -> Vec<Self::Month> {
+        Month::ALL.to_vec()
+    }
+
+    /// The days of `month` in `year`, in the calendar in use at the time.
+    ///
+    /// The month of the switch ends on the Gregorian Calendar, so it has the Gregorian length, and the days skipped
+    /// in it give [`HistoricalDateError::Skipped`] from [`Date::from_parts`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Calendar, date::{gregorian::{Month, year}, historical::{Catholic, Date}}};
+    ///
+    /// assert_eq!(Date::<Catholic>::days_of(year!(1500), Month::February), 1..=29);
+    /// assert_eq!(Date::<Catholic>::days_of(year!(1582), Month::October), 1..=31);
+    /// assert_eq!(Date::<Catholic>::days_of(year!(1700), Month::February), 1..=28);
+    /// ```
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day> {
+        let last = if (year, month) >= (S::FIRST_GREGORIAN.year(), S::FIRST_GREGORIAN.month()) {
+            *gregorian::Date::days_of(year, month).end()
+        } else {
+            julian_month_length(year, month)
+        };
+        1..=last
+    }
+}
+
+// Dates are ordered chronologically, which is the same as by their parts, since the switch skips days.
+marker_date_traits!(Date<S> { year, month, day });
+
+/// How many days `month` has in the Julian Calendar, in which every fourth year is a leap year.
+fn julian_month_length(year: Year, month: Month) -> u8 {
+    match month {
+        Month::February if year.to_astronomical().rem_euclid(4) == 0 => 29,
+        Month::February => 28,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        _ => 31,
+    }
+}
+
+/// The Julian Calendar repeats itself every 4 years, which always have this many days.
+const DAYS_IN_4_YEARS: i128 = 1_461;
+/// How many days March 1st of year 1 BCE in the Julian Calendar is before _day 0_.
+const JULIAN_SHIFT: i128 = 308;
+
+/// The [`StandardCalendar`] days of a Julian date, which must be valid.
+pub(crate) fn julian_to_days(year: Year, month: Month, day: u8) -> i128 {
+    // Like the Gregorian conversion, with years shifted to start on March 1st so leap days end them.
+    let month = i128::from(u8::from(month));
+    let year = year.to_astronomical() - i128::from(month <= 2);
+    let (cycle, year_of_cycle) = (year.div_euclid(4), year.rem_euclid(4));
+    let month_from_march = (month + 9) % 12; // [0, 11]
+    let day_of_year = (153 * month_from_march + 2) / 5 + i128::from(day) - 1; // [0, 365]
+    cycle * DAYS_IN_4_YEARS + 365 * year_of_cycle + day_of_year - JULIAN_SHIFT
+}
+
+/// The Julian date of the given [`StandardCalendar`] days.
+fn julian_from_days(days: i128) -> (Year, Month, u8) {
+    let shifted = days + JULIAN_SHIFT;
+    let (cycle, day_of_cycle) = (
+        shifted.div_euclid(DAYS_IN_4_YEARS),
+        shifted.rem_euclid(DAYS_IN_4_YEARS),
+    );
+    // The leap day is the last day of the cycle, which would otherwise start a fifth year.
+    let year_of_cycle = (day_of_cycle - day_of_cycle / 1_460) / 365; // [0, 3]
+    let day_of_year = day_of_cycle - 365 * year_of_cycle; // [0, 365]
+    let month_from_march = (5 * day_of_year + 2) / 153; // [0, 11]
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = cycle * 4 + year_of_cycle + i128::from(month <= 2);
+    (
+        Year::from_astronomical(year),
+        // Both are in range by construction.
+        Month::try_from(month as u8).unwrap(),
+        day as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::ConvertCalendar;
+    use crate::date::gregorian::year;
+
+    fn historical<S: Switchover>(year: Year, month: Month, day: u8) -> Date<S> {
+        Date::from_parts(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn switchovers() {
+        fn check<S: Switchover>(last_julian: Date<S>, first_gregorian: Date<S>) {
+            assert!(!last_julian.is_gregorian());
+            assert!(first_gregorian.is_gregorian());
+            assert_eq!(first_gregorian.as_days() - last_julian.as_days(), 1);
+            assert_eq!(
+                Date::<S>::from(StandardCalendar::new(last_julian.as_days() + 1)),
+                first_gregorian
+            );
+            assert_eq!(
+                first_gregorian.convert_to::<gregorian::Date>(),
+                S::FIRST_GREGORIAN
+            );
+
+            let mut day = last_julian;
+            day.add_days(1);
+            assert_eq!(day, first_gregorian);
+            day.add_days(-1);
+            assert_eq!(day, last_julian);
+        }
+        check::<Catholic>(
+            historical(year!(1582), Month::October, 4),
+            historical(year!(1582), Month::October, 15),
+        );
+        check::<Britain>(
+            historical(year!(1752), Month::September, 2),
+            historical(year!(1752), Month::September, 14),
+        );
+        check::<Russia>(
+            historical(year!(1918), Month::January, 31),
+            historical(year!(1918), Month::February, 14),
+        );
+
+        for day in 5..=14 {
+            assert_eq!(
+                Date::<Catholic>::from_parts(year!(1582), Month::October, day),
+                Err(HistoricalDateError::Skipped),
+                "{day}"
+            );
+        }
+        for day in 1..=13 {
+            assert_eq!(
+                Date::<Russia>::from_parts(year!(1918), Month::February, day),
+                Err(HistoricalDateError::Skipped),
+                "{day}"
+            );
+        }
+    }
+
+    #[test]
+    fn washingtons_birthday() {
+        let gregorian = gregorian::date!(1732 - 02 - 22);
+        let recorded = historical::<Britain>(year!(1732), Month::February, 11);
+        assert_eq!(recorded.convert_to::<gregorian::Date>(), gregorian);
+        assert_eq!(gregorian.convert_to::<Date<Britain>>(), recorded);
+
+        // On the continent, the Gregorian Calendar was already in use.
+        let continental = historical::<Catholic>(year!(1732), Month::February, 22);
+        assert_eq!(recorded.convert_to::<Date<Catholic>>(), continental);
+    }
+
+    #[test]
+    fn julian_dates() {
+        // (Julian date, Gregorian date) pairs.
+        let cases = [
+            // The reference date.
+            ((year!(1), Month::January, 3), gregorian::date!(1 - 01 - 01)),
+            (
+                (year!(-1), Month::February, 29),
+                gregorian::date!(-1 - 02 - 27),
+            ),
+            (
+                (year!(-45), Month::January, 1),
+                gregorian::date!(-46 - 12 - 30),
+            ),
+            // Both calendars agree through the 3rd century.
+            (
+                (year!(200), Month::March, 1),
+                gregorian::date!(200 - 03 - 01),
+            ),
+            (
+                (year!(1500), Month::February, 29),
+                gregorian::date!(1500 - 03 - 10),
+            ),
+        ];
+        for ((year, month, day), gregorian) in cases {
+            let julian = historical::<Catholic>(year, month, day);
+            assert_eq!(
+                julian.convert_to::<gregorian::Date>(),
+                gregorian,
+                "{julian:?}"
+            );
+            assert_eq!(
+                gregorian.convert_to::<Date<Catholic>>(),
+                julian,
+                "{gregorian:?}"
+            );
+        }
+
+        // Every Julian day converts back to itself.
+        for days in -2_000..2_000 {
+            let date = Date::<Russia>::from(StandardCalendar::new(days));
+            assert_eq!(date.as_days(), days);
+            assert_eq!(
+                Date::<Russia>::from_parts(date.year(), date.month(), date.day()),
+                Ok(date)
+            );
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        // Julian leap years, including centuries, until the switch.
+        assert!(Date::<Britain>::is_leap_year(year!(1700)));
+        assert!(!Date::<Catholic>::is_leap_year(year!(1700)));
+        assert!(Date::<Russia>::is_leap_year(year!(1900)));
+        assert!(!Date::<Britain>::is_leap_year(year!(1900)));
+        assert!(Date::<Catholic>::is_leap_year(year!(-1)));
+        assert!(!Date::<Catholic>::is_leap_year(year!(-2)));
+        assert_eq!(
+            Date::<Catholic>::from_parts(year!(1582), Month::February, 30),
+            Err(HistoricalDateError::InvalidDate(
+                DateCreationError::InvalidDay(30)
+            ))
+        );
+    }
+
+    #[test]
+    fn picker_days() {
+        fn check<S: Switchover>(year: Year) {
+            for month in Date::<S>::months_of(year) {
+                let days = Date::<S>::days_of(year, month);
+                for day in days.clone() {
+                    match Date::<S>::from_parts(year, month, day) {
+                        Ok(_) | Err(HistoricalDateError::Skipped) => {}
+                        Err(error) => panic!("{year} {month:?} {day}: {error:?}"),
+                    }
+                }
+                assert!(Date::<S>::from_parts(year, month, days.end() + 1).is_err());
+            }
+        }
+        for year in [
+            year!(-1),
+            year!(1582),
+            year!(1700),
+            year!(1752),
+            year!(1900),
+            year!(1918),
+        ] {
+            check::<Catholic>(year);
+            check::<Britain>(year);
+            check::<Russia>(year);
+        }
+        // Only the days skipped by the switch are missing.
+        let skipped = Date::<Britain>::days_of(year!(1752), Month::September)
+            .filter(|day| Date::<Britain>::from_parts(year!(1752), Month::September, *day).is_err())
+            .count();
+        assert_eq!(skipped, 11);
+    }
+}
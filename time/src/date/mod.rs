@@ -1 +1,51 @@
+/// Implements `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Debug` for a date generic over a marker
+/// type, comparing and writing the fields listed, in order.
+///
+/// Derives would require the marker to implement these traits too, even though it is never stored.
+macro_rules! marker_date_traits {
+    ($date:ident<$marker:ident> { $($field:ident),+ $(,)? }) => {
+        impl<$marker> Clone for $date<$marker> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<$marker> Copy for $date<$marker> {}
+
+        impl<$marker> PartialEq for $date<$marker> {
+            fn eq(&self, other: &Self) -> bool {
+                ($(&self.$field,)+) == ($(&other.$field,)+)
+            }
+        }
+
+        impl<$marker> Eq for $date<$marker> {}
+
+        impl<$marker> PartialOrd for $date<$marker> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$marker> Ord for $date<$marker> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                ($(&self.$field,)+).cmp(&($(&other.$field,)+))
+            }
+        }
+
+        impl<$marker> std::fmt::Debug for $date<$marker> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($date))
+                    $(.field(stringify!($field), &self.$field))+
+                    .finish()
+            }
+        }
+    };
+}
+
+pub mod chinese;
+pub mod coptic;
 pub mod gregorian;
+pub mod historical;
+pub mod offset;
+pub mod ordinal;
+pub mod shifted;
@@ -0,0 +1,452 @@
+//! Calendars that are Gregorian, but number their years from another era, such as the Thai Buddhist Era.
+//!
+//! An [`OffsetYearCalendar`] has the Gregorian months and days, and years that are a [`YearOffset`] away from the
+//! Gregorian ones. Neither calendar has a year 0, so years before an era starts are negative: the year before 1 is
+//! -1, whichever era it is in.
+//!
+//! New eras can be declared with [`offset_calendar!`]. Years that start on another month than January are
+//! [shifted years](super::shifted) instead, which the macro can declare too.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::num::{IntErrorKind, ParseIntError};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::gregorian::{
+    self, Month, Year,
+    errors::{DateCreationError, PartialDateParseError, YearParseError},
+};
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+
+/// How far an [`OffsetYearCalendar`]'s years are from the Gregorian ones, and what its era is called.
+///
+/// [`offset_calendar!`] implements it on new marker types, along with an alias for their calendar.
+///
+/// # Examples
+/// ```
+/// use time::date::{gregorian::date, offset::{OffsetYearCalendar, YearOffset}};
+///
+/// /// The Japanese Reiwa era, which started in 2019.
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Reiwa;
+///
+/// impl YearOffset for Reiwa {
+///     const OFFSET: i128 = -2018;
+///     const ERA: &'static str = "Reiwa";
+/// }
+///
+/// assert_eq!(OffsetYearCalendar::<Reiwa>::from(date!(2024-01-01)).to_string(), "1 January Reiwa 6");
+/// ```
+pub trait YearOffset {
+    /// How many years to add to a Gregorian year to get the year in this era, as if both had a year 0.
+    const OFFSET: i128;
+    /// The era's name or abbreviation, written before the year.
+    const ERA: &'static str;
+}
+
+/// Declares marker types for calendars that are Gregorian apart from their years, each with an alias for its calendar.
+///
+/// Each marker is written as a unit struct followed by how its years differ, and then the alias:
+/// * `offset` and `era` implement [`YearOffset`], for an [`OffsetYearCalendar`].
+/// * `start` and `label` implement [`YearStart`](super::shifted::YearStart), for a
+///   [`ShiftedYearCalendar`](super::shifted::ShiftedYearCalendar).
+///
+/// Doc comments and attributes are kept on both. The markers derive `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`
+/// and `Hash`.
+///
+/// # Examples
+/// ```
+/// use time::{Calendar, date::{gregorian::{Month, date, year}, offset::offset_calendar, shifted::YearLabel}};
+///
+/// offset_calendar! {
+///     /// The Japanese Reiwa era, which started in 2019.
+///     pub struct Reiwa(offset: -2018, era: "Reiwa");
+///     /// A date with years of the Reiwa era.
+///     pub type ReiwaDate;
+///
+///     /// The Australian financial year.
+///     pub struct July(start: Month::July, label: YearLabel::Span("FY"));
+///     /// A date in the Australian financial year.
+///     pub type AustralianFiscalDate;
+/// }
+///
+/// assert_eq!(ReiwaDate::from(date!(2024-01-01)).to_string(), "1 January Reiwa 6");
+/// assert_eq!("1 January Reiwa 6".parse(), Ok(ReiwaDate::from(date!(2024-01-01))));
+/// assert_eq!(AustralianFiscalDate::from(date!(2024-07-01)).year(), year!(2024));
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! offset_calendar {
+    () => {};
+    (
+        $(#[$marker_attr:meta])*
+        $marker_vis:vis struct $marker:ident(offset: $offset:expr, era: $era:expr $(,)?);
+        $(#[$alias_attr:meta])*
+        $alias_vis:vis type $alias:ident;
+        $($rest:tt)*
+    ) => {
+        $(#[$marker_attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $marker_vis struct $marker;
+
+        impl $crate::date::offset::YearOffset for $marker {
+            const OFFSET: i128 = $offset;
+            const ERA: &'static str = $era;
+        }
+
+        $(#[$alias_attr])*
+        $alias_vis type $alias = $crate::date::offset::OffsetYearCalendar<$marker>;
+
+        $crate::offset_calendar! { $($rest)* }
+    };
+    (
+        $(#[$marker_attr:meta])*
+        $marker_vis:vis struct $marker:ident(start: $start:expr, label: $label:expr $(,)?);
+        $(#[$alias_attr:meta])*
+        $alias_vis:vis type $alias:ident;
+        $($rest:tt)*
+    ) => {
+        $(#[$marker_attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $marker_vis struct $marker;
+
+        impl $crate::date::shifted::YearStart for $marker {
+            const MONTH: $crate::date::gregorian::Month = $start;
+            const LABEL: $crate::date::shifted::YearLabel = $label;
+        }
+
+        $(#[$alias_attr])*
+        $alias_vis type $alias = $crate::date::shifted::ShiftedYearCalendar<$marker>;
+
+        $crate::offset_calendar! { $($rest)* }
+    };
+}
+#[doc(inline)]
+pub use offset_calendar;
+
+offset_calendar! {
+    /// The Thai Buddhist Era, counting from the year the Buddha is held to have died, 543 BCE.
+    pub struct Buddhist(offset: 543, era: "BE");
+    /// A date in the Thai solar calendar, with years of the [`Buddhist`] Era.
+    pub type BuddhistDate;
+
+    /// The Minguo era of the Republic of China, used in Taiwan, counting from the founding of the Republic in 1912.
+    pub struct Minguo(offset: -1911, era: "ROC");
+    /// A date in the Minguo calendar, with years of the [`Minguo`] era.
+    pub type MinguoDate;
+
+    /// The [Holocene Era](https://en.wikipedia.org/wiki/Holocene_calendar), which adds 10,000 years to the common
+    /// era, so that year 1 is 10,000 BCE.
+    pub struct Holocene(offset: 10_000, era: "HE");
+    /// A date with years of the [`Holocene`] Era.
+    pub type HoloceneDate;
+}
+
+/// A Gregorian date, with years counted in the era given by `O`.
+///
+/// # Examples
+/// ```
+/// use time::{Calendar, date::{gregorian::{Month, date, year}, offset::{BuddhistDate, MinguoDate}}};
+///
+/// let buddhist = BuddhistDate::from(date!(2024-06-15));
+/// assert_eq!(buddhist.year(), year!(2567));
+/// assert_eq!(buddhist.to_string(), "15 June BE 2567");
+///
+/// let minguo = MinguoDate::from_parts(year!(113), Month::June, 15).unwrap();
+/// assert_eq!(minguo.date(), date!(2024-06-15));
+/// assert_eq!("15 June ROC 113".parse(), Ok(minguo));
+/// ```
+pub struct OffsetYearCalendar<O> {
+    date: gregorian::Date,
+    era: PhantomData<O>,
+}
+
+impl<O: YearOffset> OffsetYearCalendar<O> {
+    /// Creates a date from the day, month and year of the era.
+    ///
+    /// # Errors
+    /// [`DateCreationError::InvalidDay`] if the day doesn't exist in the month, in that year.
+    pub fn from_parts(year: Year, month: Month, day: u8) -> Result<Self, DateCreationError> {
+        gregorian::Date::from_parts(Self::gregorian_year(year), month, day).map(Self::from)
+    }
+
+    /// The Gregorian date of this day.
+    pub fn date(&self) -> gregorian::Date {
+        self.date
+    }
+
+    /// The Gregorian year of `year` of the era.
+    fn gregorian_year(year: Year) -> Year {
+        Year::from_astronomical(year.to_astronomical() - O::OFFSET)
+    }
+}
+
+impl<O> From<gregorian::Date> for OffsetYearCalendar<O> {
+    fn from(date: gregorian::Date) -> Self {
+        Self {
+            date,
+            era: PhantomData,
+        }
+    }
+}
+
+impl<O> From<&OffsetYearCalendar<O>> for StandardCalendar {
+    fn from(date: &OffsetYearCalendar<O>) -> Self {
+        StandardCalendar::from(&date.date)
+    }
+}
+
+impl<O> From<StandardCalendar> for OffsetYearCalendar<O> {
+    fn from(standard: StandardCalendar) -> Self {
+        gregorian::Date::from(standard).into()
+    }
+}
+
+impl<O: YearOffset> Calendar for OffsetYearCalendar<O> {
+    type Month = Month;
+    type Day = u8;
+    type Year = Year;
+
+    fn day(&self) -> Self::Day {
+        self.date.day()
+    }
+
+    fn month(&self) -> Self::Month {
+        self.date.month()
+    }
+
+    /// The year of the era.
+    fn year(&self) -> Self::Year {
+        // Astronomical years have a year 0, so the offset is the same on both sides of it.
+        Year::from_astronomical(self.date.year().to_astronomical() + O::OFFSET)
+    }
+
+    fn reference_date() -> Self {
+        gregorian::Date::reference_date().into()
+    }
+
+    fn add_days(&mut self, days: i128) {
+        self.date.add_days(days);
+    }
+
+    fn as_days(&self) -> i128 {
+        self.date.as_days()
+    }
+
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        gregorian::Date::days_between(&first.date, &second.date)
+    }
+
+    fn is_leap_year(year: Self::Year) -> bool {
+        Self::gregorian_year(year).is_leap_year()
+    }
+
+    fn months_of(_: Self::Year) -> Vec<Self::Month> {
+        Month::ALL.to_vec()
+    }
+
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day> {
+        gregorian::Date::days_of(Self::gregorian_year(year), month)
+    }
+}
+
+impl<O: YearOffset> fmt::Display for OffsetYearCalendar<O> {
+    /// Writes the date with the month name in English and the era before the year, like "15 June BE 2567". Years
+    /// before the era starts are negative.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {:#}",
+            self.day(),
+            self.month().name(),
+            O::ERA,
+            self.year()
+        )
+    }
+}
+
+impl<O: YearOffset> FromStr for OffsetYearCalendar<O> {
+    type Err = PartialDateParseError;
+
+    /// Reads the form written by [`Display`](fmt::Display), with the month either in full or abbreviated, in any
+    /// case.
+    ///
+    /// # Errors
+    /// * [`PartialDateParseError::Year`] if the era is missing, the year is not a number other than 0, or the year is
+    ///   before [`Date::MIN`](gregorian::Date::MIN) or after [`Date::MAX`](gregorian::Date::MAX).
+    /// * [`PartialDateParseError::InvalidDate`] if the date doesn't exist.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words = input.split_whitespace();
+        let (Some(day), Some(month), Some(era), Some(year), None) = (
+            words.next(),
+            words.next(),
+            words.next(),
+            words.next(),
+            words.next(),
+        ) else {
+            return Err(YearParseError::Invalid.into());
+        };
+        if !era.eq_ignore_ascii_case(O::ERA) {
+            return Err(YearParseError::Invalid.into());
+        }
+        // A bare number, since Gregorian eras like "BCE" don't apply.
+        let year: i128 = year
+            .parse()
+            .map_err(|error: ParseIntError| match error.kind() {
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => YearParseError::OutOfRange,
+                _ => YearParseError::Invalid,
+            })?;
+        let year = Year::try_from(year).map_err(|_| YearParseError::Zero)?;
+        // The year must also be in range once moved to the Gregorian count.
+        let (min, max) = (gregorian::Date::MIN.year(), gregorian::Date::MAX.year());
+        if !year
+            .to_astronomical()
+            .checked_sub(O::OFFSET)
+            .is_some_and(|year| (min.to_astronomical()..=max.to_astronomical()).contains(&year))
+        {
+            return Err(YearParseError::OutOfRange.into());
+        }
+        let month = Month::ALL
+            .into_iter()
+            .find(|other| {
+                other.name().eq_ignore_ascii_case(month)
+                    || other.abbreviation().eq_ignore_ascii_case(month)
+            })
+            .ok_or(DateCreationError::InvalidMonth(0))?;
+        let day = day.parse().map_err(|_| DateCreationError::InvalidDay(0))?;
+        Ok(Self::from_parts(year, month, day)?)
+    }
+}
+
+marker_date_traits!(OffsetYearCalendar<O> { date });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::ConvertCalendar;
+    use crate::date::gregorian::{date, year};
+
+    #[test]
+    fn years() {
+        // (Gregorian year, Buddhist year, Minguo year)
+        let cases = [
+            (year!(2024), year!(2567), year!(113)),
+            (year!(1912), year!(2455), year!(1)),
+            // The year before 1 is -1 in every era.
+            (year!(1911), year!(2454), year!(-1)),
+            (year!(1910), year!(2453), year!(-2)),
+            (year!(1), year!(544), year!(-1911)),
+            (year!(-1), year!(543), year!(-1912)),
+            (year!(-543), year!(1), year!(-2454)),
+            (year!(-544), year!(-1), year!(-2455)),
+        ];
+        for (gregorian, buddhist, minguo) in cases {
+            let date = gregorian::Date::from_year(gregorian);
+            assert_eq!(BuddhistDate::from(date).year(), buddhist, "{gregorian}");
+            assert_eq!(MinguoDate::from(date).year(), minguo, "{gregorian}");
+            assert_eq!(
+                BuddhistDate::from_parts(buddhist, Month::January, 1).map(|date| date.date()),
+                Ok(date)
+            );
+            assert_eq!(
+                MinguoDate::from_parts(minguo, Month::January, 1)
+                    .unwrap()
+                    .convert_to::<gregorian::Date>(),
+                date
+            );
+        }
+        // (Gregorian year, Holocene year)
+        let holocene = [
+            (year!(2024), year!(12024)),
+            (year!(1), year!(10001)),
+            (year!(-1), year!(10000)),
+            (year!(-10000), year!(1)),
+            (year!(-10001), year!(-1)),
+        ];
+        for (gregorian, year) in holocene {
+            let date = HoloceneDate::from(gregorian::Date::from_year(gregorian));
+            assert_eq!(date.year(), year, "{gregorian}");
+            assert_eq!(HoloceneDate::from_parts(year, Month::January, 1), Ok(date));
+        }
+        assert_eq!(
+            HoloceneDate::from(date!(2024 - 06 - 15)).to_string(),
+            "15 June HE 12024"
+        );
+
+        // 2567 BE is 2024, a leap year.
+        assert!(BuddhistDate::is_leap_year(year!(2567)));
+        assert_eq!(BuddhistDate::days_of(year!(2566), Month::February), 1..=28);
+    }
+
+    #[test]
+    fn parsing() {
+        let cases = [
+            (date!(2024 - 06 - 15), "15 June ROC 113"),
+            (date!(1911 - 10 - 10), "10 October ROC -1"),
+            (date!(2024 - 02 - 29), "29 February ROC 113"),
+        ];
+        for (date, text) in cases {
+            let minguo = MinguoDate::from(date);
+            assert_eq!(minguo.to_string(), text);
+            assert_eq!(text.parse(), Ok(minguo));
+        }
+        assert_eq!(
+            "1 jan be 2567".parse(),
+            Ok(BuddhistDate::from(date!(2024 - 01 - 01)))
+        );
+
+        let errors = [
+            (
+                "29 February ROC 112",
+                DateCreationError::InvalidDay(29).into(),
+            ),
+            ("1 January ROC 0", YearParseError::Zero.into()),
+            ("1 January BE 2567", YearParseError::Invalid.into()),
+            ("1 January ROC 5 BCE", YearParseError::Invalid.into()),
+            ("1 January 2024", YearParseError::Invalid.into()),
+            (
+                "1 Smarch ROC 113",
+                DateCreationError::InvalidMonth(0).into(),
+            ),
+            (
+                "1 January ROC 170141183460469231731687303715884105727",
+                YearParseError::OutOfRange.into(),
+            ),
+            (
+                "1 January ROC 170141183460469231731687303715884105728",
+                YearParseError::OutOfRange.into(),
+            ),
+        ];
+        for (text, error) in errors {
+            assert_eq!(text.parse::<MinguoDate>(), Err(error), "{text}");
+        }
+        let errors = [
+            (
+                "1 January BE -170141183460469231731687303715884105727",
+                YearParseError::OutOfRange.into(),
+            ),
+            // Past Date::MAX, but still a valid i128.
+            (
+                "1 January BE 100000000000000000000000000000000000000",
+                YearParseError::OutOfRange.into(),
+            ),
+            (
+                "1 January BE -100000000000000000000000000000000000000",
+                YearParseError::OutOfRange.into(),
+            ),
+        ];
+        for (text, error) in errors {
+            assert_eq!(text.parse::<BuddhistDate>(), Err(error), "{text}");
+        }
+        // The last year in range in both counts.
+        let max = gregorian::Date::MAX;
+        let text = BuddhistDate::from(max).to_string();
+        assert_eq!(
+            text.parse::<BuddhistDate>().map(|date| date.date()),
+            Ok(max),
+            "{text}"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! [Ordinal dates](https://en.wikipedia.org/wiki/Ordinal_date), which give the day of the year instead of a month
+//! and a day, as in ISO 8601's `YYYY-DDD` form.
+//!
+//! They are the [Gregorian](super::gregorian) dates, numbered from 1 on January 1st to 365 or 366 on December 31st.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::gregorian::{
+    self, IsoYear, Year,
+    errors::{DateCreationError, PartialDateParseError, YearParseError},
+};
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+
+/// A Gregorian date, as its year and its day of the year.
+///
+/// # Examples
+/// ```
+/// use time::date::{gregorian::{self, date, year}, ordinal};
+///
+/// let date = ordinal::Date::from_parts(year!(2024), 123).unwrap();
+/// assert_eq!(gregorian::Date::from(date), date!(2024-05-02));
+/// assert_eq!(date.to_string(), "2024-123");
+/// assert_eq!("2024-123".parse(), Ok(date));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: Year,
+    day: u16,
+}
+
+impl Date {
+    /// Creates a date from its year and its day of the year, starting from 1 on January 1st.
+    ///
+    /// # Errors
+    /// [`DateCreationError::InvalidOrdinal`] if `year` doesn't have `day`, such as day 366 of a common year.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::{gregorian::{errors::DateCreationError, year}, ordinal::Date};
+    ///
+    /// assert!(Date::from_parts(year!(2024), 366).is_ok());
+    /// assert_eq!(Date::from_parts(year!(2023), 366), Err(DateCreationError::InvalidOrdinal(366)));
+    /// assert_eq!(Date::from_parts(year!(2023), 0), Err(DateCreationError::InvalidOrdinal(0)));
+    /// ```
+    pub fn from_parts(year: Year, day: u16) -> Result<Self, DateCreationError> {
+        if !Self::days_of(year, ()).contains(&day) {
+            return Err(DateCreationError::InvalidOrdinal(day));
+        }
+        Ok(Self { year, day })
+    }
+}
+
+impl From<gregorian::Date> for Date {
+    fn from(date: gregorian::Date) -> Self {
+        Self {
+            year: date.year(),
+            day: date.ordinal(),
+        }
+    }
+}
+
+impl From<Date> for gregorian::Date {
+    fn from(date: Date) -> Self {
+        // In the year, as checked when the date was created.
+        gregorian::Date::from_ordinal(date.year, date.day).unwrap()
+    }
+}
+
+impl From<&Date> for StandardCalendar {
+    fn from(date: &Date) -> Self {
+        let new_year = StandardCalendar::from(&gregorian::Date::from_year(date.year));
+        StandardCalendar::new(new_year.days + i128::from(date.day) - 1)
+    }
+}
+
+impl From<StandardCalendar> for Date {
+    fn from(standard: StandardCalendar) -> Self {
+        gregorian::Date::from(standard).into()
+    }
+}
+
+impl Calendar for Date {
+    /// Ordinal dates have no months.
+    type Month = ();
+    /// The day of the year.
+    type Day = u16;
+    type Year = Year;
+
+    fn day(&self) -> Self::Day {
+        self.day
+    }
+
+    fn month(&self) -> Self::Month {}
+
+    fn year(&self) -> Self::Year {
+        self.year
+    }
+
+    fn reference_date() -> Self {
+        gregorian::Date::reference_date().into()
+    }
+
+    fn add_days(&mut self, days: i128) {
+        *self = StandardCalendar::new(self.as_days() + days).into();
+    }
+
+    fn as_days(&self) -> i128 {
+        StandardCalendar::from(self).days
+    }
+
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        (second.as_days() - first.as_days()).abs()
+    }
+
+    fn is_leap_year(year: Self::Year) -> bool {
+        year.is_leap_year()
+    }
+
+    /// A single month, `()`, which is the whole year.
+    fn months_of(_: Self::Year) -> Vec<Self::Month> {
+        vec![()]
+    }
+
+    fn days_of(year: Self::Year, _: Self::Month) -> RangeInclusive<Self::Day> {
+        if year.is_leap_year() {
+            1..=366
+        } else {
+            1..=365
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    /// Writes the date as in ISO 8601, with the year of at least 4 digits and the day of the year with 3, like
+    /// "2024-005". Years are [astronomical](Year::to_astronomical), so 44 BCE is written "-0043-074".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:03}", IsoYear::from(self.year), self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = PartialDateParseError;
+
+    /// Reads the form written by [`Display`](fmt::Display), where a year written as a number is astronomical. The
+    /// year can also be written with an era, in any form [`Year`] parses, like "44 BC-074".
+    ///
+    /// # Errors
+    /// * [`PartialDateParseError::Year`] if the year can't be read, or there is no `-` before the day.
+    /// * [`PartialDateParseError::InvalidDate`] if the day isn't a number, or the year doesn't have it.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (year, day) = input
+            .trim()
+            .rsplit_once('-')
+            .ok_or(YearParseError::Invalid)?;
+        let year = astronomical_year(year)?;
+        let day = day
+            .parse()
+            .map_err(|_| DateCreationError::InvalidOrdinal(0))?;
+        Ok(Self::from_parts(year, day)?)
+    }
+}
+
+/// Reads `input` as an astronomical year if it is a number, or as a [`Year`] with an era otherwise.
+fn astronomical_year(input: &str) -> Result<Year, YearParseError> {
+    let digits = input.strip_prefix(['+', '-']).unwrap_or(input);
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return input.parse();
+    }
+    // Only the digits were checked, so this fails on overflow.
+    let year: i128 = input.parse().map_err(|_| YearParseError::OutOfRange)?;
+    let (min, max) = (gregorian::Date::MIN.year(), gregorian::Date::MAX.year());
+    if !(min.to_astronomical()..=max.to_astronomical()).contains(&year) {
+        return Err(YearParseError::OutOfRange);
+    }
+    Ok(Year::from_astronomical(year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::ConvertCalendar;
+    use crate::date::gregorian::{date, year};
+
+    #[test]
+    fn matches_gregorian() {
+        for days in -1_000..1_000 {
+            let standard = StandardCalendar::new(days);
+            let date = Date::from(standard);
+            let gregorian = gregorian::Date::from(standard);
+            assert_eq!(date, Date::from(gregorian), "{days}");
+            assert_eq!(gregorian::Date::from(date), gregorian);
+            assert_eq!(date.to_standard(), standard);
+        }
+        let mut date = Date::from_parts(year!(-1), 366).unwrap();
+        date.add_days(1);
+        assert_eq!(date, Date::from_parts(year!(1), 1).unwrap());
+    }
+
+    #[test]
+    fn text() {
+        let cases = [
+            (date!(2024 - 01 - 05), "2024-005"),
+            (date!(2024 - 12 - 31), "2024-366"),
+            (date!(-44 - 03 - 15), "-0043-074"),
+            (date!(-1 - 12 - 31), "0000-366"),
+            (date!(1 - 01 - 01), "0001-001"),
+            (date!(12345 - 01 - 01), "12345-001"),
+        ];
+        for (gregorian, text) in cases {
+            let date = Date::from(gregorian);
+            assert_eq!(date.to_string(), text);
+            assert_eq!(text.parse(), Ok(date));
+        }
+        assert_eq!("44 BC-074".parse(), Ok(Date::from(date!(-44 - 03 - 15))));
+        assert_eq!("+2024-005".parse(), Ok(Date::from(date!(2024 - 01 - 05))));
+
+        let errors = [
+            ("2023-366", DateCreationError::InvalidOrdinal(366).into()),
+            ("2023-", DateCreationError::InvalidOrdinal(0).into()),
+            ("0 BC-001", YearParseError::Zero.into()),
+            ("2023", YearParseError::Invalid.into()),
+            ("--0043-074", YearParseError::Invalid.into()),
+            (
+                "-170141183460469231731687303715884105728-001",
+                YearParseError::OutOfRange.into(),
+            ),
+            (
+                "170141183460469231731687303715884105728-001",
+                YearParseError::OutOfRange.into(),
+            ),
+        ];
+        for (text, error) in errors {
+            assert_eq!(text.parse::<Date>(), Err(error), "{text}");
+        }
+    }
+}
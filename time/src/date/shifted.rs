@@ -0,0 +1,405 @@
+//! Years that start on another month than January, such as fiscal and academic years.
+//!
+//! A [`ShiftedYearCalendar`] is a [Gregorian](super::gregorian) date whose year starts on the 1st of the month given
+//! by a [`YearStart`]. Its months and days are the Gregorian ones, only the [`year`](Calendar::year) changes.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::gregorian::{
+    self, Month, Year,
+    errors::{DateCreationError, PartialDateParseError, YearParseError},
+};
+use super::offset::offset_calendar;
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+
+/// The month a [`ShiftedYearCalendar`]'s year starts in, and how the year is written.
+///
+/// Years are named after the Gregorian year they start in. Implement this on your own type for other starts, or
+/// declare it with [`offset_calendar!`].
+///
+/// # Examples
+/// ```
+/// use time::date::{gregorian::Month, shifted::{YearLabel, YearStart}};
+///
+/// /// The Australian financial year.
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct July;
+///
+/// impl YearStart for July {
+///     const MONTH: Month = Month::July;
+///     const LABEL: YearLabel = YearLabel::Span("FY");
+/// }
+/// ```
+pub trait YearStart {
+    /// The month the year starts in, on its 1st.
+    const MONTH: Month;
+    /// How the year is written.
+    const LABEL: YearLabel;
+}
+
+/// How a [`ShiftedYearCalendar`] writes its year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearLabel {
+    /// The prefix and the year it starts in, such as "FY2024".
+    Single(&'static str),
+    /// The prefix and both Gregorian years the year covers, such as "AY2023–24".
+    ///
+    /// The year it ends in is shortened to its last two digits, unless the year starts before the common era.
+    Span(&'static str),
+}
+
+offset_calendar! {
+    /// A fiscal year starting on April 1st, as in Japan and India.
+    pub struct AprilFiscal(start: Month::April, label: YearLabel::Single("FY"));
+    /// A date in the fiscal year starting on April 1st.
+    pub type AprilFiscalDate;
+
+    /// An academic year starting on September 1st.
+    pub struct SeptemberAcademic(start: Month::September, label: YearLabel::Span("AY"));
+    /// A date in the academic year starting on September 1st.
+    pub type SeptemberAcademicDate;
+}
+
+/// A Gregorian date, counted in years starting on the month given by `S`.
+///
+/// # Examples
+/// ```
+/// use time::{Calendar, date::{gregorian::{Month, date, year}, shifted::{AprilFiscal, ShiftedYearCalendar}}};
+///
+/// let date = ShiftedYearCalendar::<AprilFiscal>::from(date!(2024-03-15));
+/// assert_eq!(date.year(), year!(2023));
+/// assert_eq!(date.to_string(), "15 March FY2023");
+/// assert_eq!("15 March FY2023".parse(), Ok(date));
+///
+/// // March of fiscal year 2023 is in 2024.
+/// let from_parts = ShiftedYearCalendar::<AprilFiscal>::from_parts(year!(2023), Month::March, 15).unwrap();
+/// assert_eq!(from_parts.date(), date!(2024-03-15));
+/// ```
+pub struct ShiftedYearCalendar<S> {
+    date: gregorian::Date,
+    start: PhantomData<S>,
+}
+
+impl<S: YearStart> ShiftedYearCalendar<S> {
+    /// Creates a date from the day, month and shifted year.
+    ///
+    /// # Errors
+    /// [`DateCreationError::InvalidDay`] if the day doesn't exist in the month, in the Gregorian year it falls in.
+    pub fn from_parts(year: Year, month: Month, day: u8) -> Result<Self, DateCreationError> {
+        gregorian::Date::from_parts(Self::gregorian_year(year, month), month, day).map(Self::from)
+    }
+
+    /// The Gregorian date of this day.
+    pub fn date(&self) -> gregorian::Date {
+        self.date
+    }
+
+    /// The Gregorian year `month` of the shifted `year` falls in.
+    fn gregorian_year(year: Year, month: Month) -> Year {
+        if month < S::MONTH { year.next() } else { year }
+    }
+}
+
+impl<S> From<gregorian::Date> for ShiftedYearCalendar<S> {
+    fn from(date: gregorian::Date) -> Self {
+        Self {
+            date,
+            start: PhantomData,
+        }
+    }
+}
+
+impl<S> From<&ShiftedYearCalendar<S>> for StandardCalendar {
+    fn from(date: &ShiftedYearCalendar<S>) -> Self {
+        StandardCalendar::from(&date.date)
+    }
+}
+
+impl<S> From<StandardCalendar> for ShiftedYearCalendar<S> {
+    fn from(standard: StandardCalendar) -> Self {
+        gregorian::Date::from(standard).into()
+    }
+}
+
+impl<S: YearStart> Calendar for ShiftedYearCalendar<S> {
+    type Month = Month;
+    type Day = u8;
+    type Year = Year;
+
+    fn day(&self) -> Self::Day {
+        self.date.day()
+    }
+
+    fn month(&self) -> Self::Month {
+        self.date.month()
+    }
+
+    /// The shifted year, named after the Gregorian year it starts in.
+    fn year(&self) -> Self::Year {
+        let year = self.date.year();
+        if self.date.month() < S::MONTH {
+            Year::from_astronomical(year.to_astronomical() - 1)
+        } else {
+            year
+        }
+    }
+
+    fn reference_date() -> Self {
+        gregorian::Date::reference_date().into()
+    }
+
+    fn add_days(&mut self, days: i128) {
+        self.date.add_days(days);
+    }
+
+    fn as_days(&self) -> i128 {
+        self.date.as_days()
+    }
+
+    fn days_between(first: &Self, second: &Self) -> i128 {
+        gregorian::Date::days_between(&first.date, &second.date)
+    }
+
+    /// Whether the shifted year has a February 29th.
+    fn is_leap_year(year: Self::Year) -> bool {
+        Self::from_parts(year, Month::February, 29).is_ok()
+    }
+
+    /// The months of `year`, starting from [`YearStart::MONTH`].
+    fn months_of(_: Self::Year) -> Vec<Self::Month> {
+        (0..12)
+            // Below 12 by the remainder.
+            .map(|months| Month::from_index0(((S::MONTH.index0() + months) % 12) as u8).unwrap())
+            .collect()
+    }
+
+    fn days_of(year: Self::Year, month: Self::Month) -> RangeInclusive<Self::Day> {
+        gregorian::Date::days_of(Self::gregorian_year(year, month), month)
+    }
+}
+
+impl<S: YearStart> ShiftedYearCalendar<S> {
+    /// Writes `year` as given by [`YearStart::LABEL`], like "FY2023" or "AY2023–24".
+    fn write_label(year: Year, f: &mut impl fmt::Write) -> fmt::Result {
+        match S::LABEL {
+            YearLabel::Single(prefix) => write!(f, "{prefix}{year}"),
+            YearLabel::Span(prefix) => {
+                let end = year.next();
+                if year.to_astronomical() > 0 {
+                    write!(f, "{prefix}{year}–{:02}", end.to_astronomical() % 100)
+                } else {
+                    write!(f, "{prefix}{year}–{end}")
+                }
+            }
+        }
+    }
+
+    /// Reads a year label written by [`write_label`](Self::write_label), in any case.
+    fn parse_label(label: &str) -> Result<Year, YearParseError> {
+        let prefix = match S::LABEL {
+            YearLabel::Single(prefix) | YearLabel::Span(prefix) => prefix,
+        };
+        let rest = label
+            .get(..prefix.len())
+            .filter(|start| start.eq_ignore_ascii_case(prefix))
+            .and_then(|_| label.get(prefix.len()..))
+            .ok_or(YearParseError::Invalid)?;
+        let year: Year = match S::LABEL {
+            YearLabel::Single(_) => rest.parse()?,
+            YearLabel::Span(_) => rest
+                .split_once('–')
+                .ok_or(YearParseError::Invalid)?
+                .0
+                .parse()?,
+        };
+        // The end of a span has to be the one written for its start.
+        let mut written = String::new();
+        Self::write_label(year, &mut written).map_err(|_| YearParseError::Invalid)?;
+        if !written.eq_ignore_ascii_case(label) {
+            return Err(YearParseError::Invalid);
+        }
+        Ok(year)
+    }
+}
+
+impl<S: YearStart> fmt::Display for ShiftedYearCalendar<S> {
+    /// Writes the date with the month name in English and the shifted year as given by [`YearStart::LABEL`], like
+    /// "15 March FY2023" or "15 March AY2023–24".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} ", self.day(), self.month().name())?;
+        Self::write_label(self.year(), f)
+    }
+}
+
+impl<S: YearStart> FromStr for ShiftedYearCalendar<S> {
+    type Err = PartialDateParseError;
+
+    /// Reads the form written by [`Display`](fmt::Display), with the month either in full or abbreviated, in any
+    /// case.
+    ///
+    /// # Errors
+    /// * [`PartialDateParseError::Year`] if the year label can't be read, or the date is before
+    ///   [`Date::MIN`](gregorian::Date::MIN) or after [`Date::MAX`](gregorian::Date::MAX).
+    /// * [`PartialDateParseError::InvalidDate`] if the date doesn't exist.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words = input.trim().splitn(3, char::is_whitespace);
+        let (Some(day), Some(month), Some(label)) = (words.next(), words.next(), words.next())
+        else {
+            return Err(YearParseError::Invalid.into());
+        };
+        let year = Self::parse_label(label.trim_start())?;
+        let month = Month::ALL
+            .into_iter()
+            .find(|other| {
+                other.name().eq_ignore_ascii_case(month)
+                    || other.abbreviation().eq_ignore_ascii_case(month)
+            })
+            .ok_or(DateCreationError::InvalidMonth(0))?;
+        // The last months of the latest year are in the Gregorian year after it.
+        if Self::gregorian_year(year, month) > gregorian::Date::MAX.year() {
+            return Err(YearParseError::OutOfRange.into());
+        }
+        let day = day.parse().map_err(|_| DateCreationError::InvalidDay(0))?;
+        Ok(Self::from_parts(year, month, day)?)
+    }
+}
+
+marker_date_traits!(ShiftedYearCalendar<S> { date });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::ConvertCalendar;
+    use crate::date::gregorian::{date, year};
+
+    #[test]
+    fn boundary_months() {
+        // (date, fiscal year starting in April, academic year starting in September)
+        let cases = [
+            (date!(2024 - 03 - 31), year!(2023), year!(2023)),
+            (date!(2024 - 04 - 01), year!(2024), year!(2023)),
+            (date!(2024 - 08 - 31), year!(2024), year!(2023)),
+            (date!(2024 - 09 - 01), year!(2024), year!(2024)),
+            (date!(2024 - 12 - 31), year!(2024), year!(2024)),
+            (date!(2025 - 01 - 01), year!(2024), year!(2024)),
+            // No year 0 between them.
+            (date!(1 - 03 - 31), year!(-1), year!(-1)),
+            (date!(1 - 04 - 01), year!(1), year!(-1)),
+        ];
+        for (date, fiscal, academic) in cases {
+            let fiscal_date = ShiftedYearCalendar::<AprilFiscal>::from(date);
+            assert_eq!(fiscal_date.year(), fiscal, "{date:?}");
+            assert_eq!(
+                ShiftedYearCalendar::<AprilFiscal>::from_parts(fiscal, date.month(), date.day()),
+                Ok(fiscal_date)
+            );
+
+            let academic_date = ShiftedYearCalendar::<SeptemberAcademic>::from(date);
+            assert_eq!(academic_date.year(), academic, "{date:?}");
+            assert_eq!(
+                ShiftedYearCalendar::<SeptemberAcademic>::from_parts(
+                    academic,
+                    date.month(),
+                    date.day()
+                ),
+                Ok(academic_date)
+            );
+            assert_eq!(academic_date.convert_to::<gregorian::Date>(), date);
+        }
+    }
+
+    #[test]
+    fn leap_years_and_months() {
+        // February of fiscal year 2023 is in 2024.
+        assert!(ShiftedYearCalendar::<AprilFiscal>::is_leap_year(year!(
+            2023
+        )));
+        assert!(!ShiftedYearCalendar::<AprilFiscal>::is_leap_year(year!(
+            2024
+        )));
+        assert_eq!(
+            ShiftedYearCalendar::<AprilFiscal>::from_parts(year!(2024), Month::February, 29),
+            Err(DateCreationError::InvalidDay(29))
+        );
+        assert_eq!(
+            ShiftedYearCalendar::<AprilFiscal>::days_of(year!(2023), Month::February),
+            1..=29
+        );
+
+        let months = ShiftedYearCalendar::<SeptemberAcademic>::months_of(year!(2024));
+        assert_eq!(months[0], Month::September);
+        assert_eq!(months[4], Month::January);
+        assert_eq!(months[11], Month::August);
+    }
+
+    #[test]
+    fn labels() {
+        let cases = [
+            (
+                date!(2024 - 03 - 15),
+                "15 March FY2023",
+                "15 March AY2023–24",
+            ),
+            (
+                date!(2024 - 10 - 01),
+                "1 October FY2024",
+                "1 October AY2024–25",
+            ),
+            (date!(2000 - 06 - 01), "1 June FY2000", "1 June AY1999–00"),
+            (
+                date!(1 - 01 - 01),
+                "1 January FY1 BCE",
+                "1 January AY1 BCE–1",
+            ),
+            (
+                date!(-1 - 01 - 01),
+                "1 January FY2 BCE",
+                "1 January AY2 BCE–1 BCE",
+            ),
+        ];
+        for (date, fiscal, academic) in cases {
+            let fiscal_date = ShiftedYearCalendar::<AprilFiscal>::from(date);
+            assert_eq!(fiscal_date.to_string(), fiscal);
+            assert_eq!(fiscal.parse(), Ok(fiscal_date));
+            let academic_date = ShiftedYearCalendar::<SeptemberAcademic>::from(date);
+            assert_eq!(academic_date.to_string(), academic);
+            assert_eq!(academic.parse(), Ok(academic_date));
+        }
+        assert_eq!(
+            "15 mar fy2023".parse(),
+            Ok(AprilFiscalDate::from(date!(2024 - 03 - 15)))
+        );
+
+        let errors = [
+            (
+                "29 February FY2024",
+                DateCreationError::InvalidDay(29).into(),
+            ),
+            ("1 March FY0", YearParseError::Zero.into()),
+            ("1 March AY2023", YearParseError::Invalid.into()),
+            ("1 March FY2023–24", YearParseError::Invalid.into()),
+            ("1 Smarch FY2023", DateCreationError::InvalidMonth(0).into()),
+            ("1 March 2023", YearParseError::Invalid.into()),
+            ("1 March", YearParseError::Invalid.into()),
+            (
+                "1 March FY100000000000000000000000000000000000",
+                YearParseError::OutOfRange.into(),
+            ),
+            (
+                "1 March FY170141183460469231731687303715884105727",
+                YearParseError::OutOfRange.into(),
+            ),
+        ];
+        for (text, error) in errors {
+            assert_eq!(text.parse::<AprilFiscalDate>(), Err(error), "{text}");
+        }
+        assert_eq!(
+            "1 March AY2023–25".parse::<SeptemberAcademicDate>(),
+            Err(YearParseError::Invalid.into())
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Day counts from other systems' reference dates, for interchange with them.
+//!
+//! [`StandardCalendar`] counts from January 1st of year 1, but other systems and literature count whole days from
+//! other dates. An [`Epoch`] converts between those counts and the [`StandardCalendar`].
+
+use crate::StandardCalendar;
+use crate::date::gregorian::Date;
+
+/// A published reference date that days are counted from.
+///
+/// # Examples
+/// ```
+/// use time::{StandardCalendar, date::gregorian::date, epoch::Epoch};
+///
+/// let millennium = StandardCalendar::from(&date!(2000-01-01));
+/// assert_eq!(millennium.to_epoch(Epoch::Unix), 10_957);
+/// assert_eq!(millennium.to_epoch(Epoch::ModifiedJulian), 51_544);
+/// assert_eq!(millennium.to_epoch(Epoch::RataDie), 730_120);
+/// assert_eq!(StandardCalendar::from_epoch(Epoch::ModifiedJulian, 51_544), millennium);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Epoch {
+    /// The Unix epoch, January 1st of 1970, as day 0.
+    Unix,
+    /// The Modified Julian Date epoch, November 17th of 1858, as day 0.
+    ///
+    /// Modified Julian Dates count from midnight, so whole days line up with calendar days.
+    ModifiedJulian,
+    /// Rata Die, from _Calendrical Calculations_, which counts January 1st of year 1 as day **1**.
+    ///
+    /// This makes it the [`StandardCalendar`] plus one.
+    RataDie,
+}
+
+impl Epoch {
+    /// The [`StandardCalendar`] day this epoch counts as day 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{StandardCalendar, date::gregorian::date, epoch::Epoch};
+    ///
+    /// assert_eq!(Epoch::Unix.day_zero(), StandardCalendar::from(&date!(1970-01-01)));
+    /// // Rata Die 0 is the last day before the common era.
+    /// assert_eq!(Epoch::RataDie.day_zero(), StandardCalendar::from(&date!(-1-12-31)));
+    /// ```
+    pub const fn day_zero(self) -> StandardCalendar {
+        StandardCalendar::new(match self {
+            Self::Unix => 719_162,
+            Self::ModifiedJulian => 678_575,
+            Self::RataDie => -1,
+        })
+    }
+}
+
+impl StandardCalendar {
+    /// How many days this is after `epoch`'s [day 0](Epoch::day_zero), or before it if negative.
+    pub fn to_epoch(&self, epoch: Epoch) -> i128 {
+        self.days - epoch.day_zero().days
+    }
+
+    /// The day that is `days` days after `epoch`'s [day 0](Epoch::day_zero), or before it if negative.
+    pub fn from_epoch(epoch: Epoch, days: i128) -> Self {
+        Self::new(epoch.day_zero().days + days)
+    }
+}
+
+impl Date {
+    /// This date's Modified Julian Date: the days since November 17th of 1858.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::date;
+    ///
+    /// assert_eq!(date!(2000-01-01).to_mjd(), 51_544);
+    /// assert_eq!(date!(1858-11-17).to_mjd(), 0);
+    /// assert_eq!(date!(1858-11-16).to_mjd(), -1);
+    /// ```
+    pub fn to_mjd(&self) -> i128 {
+        StandardCalendar::from(self).to_epoch(Epoch::ModifiedJulian)
+    }
+
+    /// The date with the given Modified Julian Date. See [`Date::to_mjd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, date};
+    ///
+    /// assert_eq!(Date::from_mjd(51_544), date!(2000-01-01));
+    /// assert_eq!(Date::from_mjd(0), date!(1858-11-17));
+    /// ```
+    pub fn from_mjd(mjd: i128) -> Self {
+        StandardCalendar::from_epoch(Epoch::ModifiedJulian, mjd).into()
+    }
+
+    /// This date's Rata Die: the day count of _Calendrical Calculations_, in which January 1st of year 1 is day 1.
+    ///
+    /// This is one more than the date's [`StandardCalendar`] days, which start from 0 on the same date.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Calendar, date::gregorian::date};
+    ///
+    /// assert_eq!(date!(1-01-01).to_rata_die(), 1);
+    /// assert_eq!(date!(1-01-01).as_days(), 0);
+    /// assert_eq!(date!(-1-12-31).to_rata_die(), 0);
+    /// assert_eq!(date!(1945-11-12).to_rata_die(), 710_347);
+    /// ```
+    pub fn to_rata_die(&self) -> i128 {
+        StandardCalendar::from(self).to_epoch(Epoch::RataDie)
+    }
+
+    /// The date with the given Rata Die. See [`Date::to_rata_die`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, date};
+    ///
+    /// assert_eq!(Date::from_rata_die(1), date!(1-01-01));
+    /// assert_eq!(Date::from_rata_die(730_120), date!(2000-01-01));
+    /// ```
+    pub fn from_rata_die(rata_die: i128) -> Self {
+        StandardCalendar::from_epoch(Epoch::RataDie, rata_die).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Calendar;
+    use crate::date::gregorian::date;
+
+    #[test]
+    fn correspondences() {
+        let cases = [
+            (Epoch::Unix, date!(1970 - 01 - 01), 0),
+            (Epoch::Unix, date!(1969 - 12 - 31), -1),
+            (Epoch::Unix, date!(2000 - 01 - 01), 10_957),
+            (Epoch::ModifiedJulian, date!(1858 - 11 - 17), 0),
+            (Epoch::ModifiedJulian, date!(1858 - 11 - 16), -1),
+            (Epoch::ModifiedJulian, date!(2000 - 01 - 01), 51_544),
+            (Epoch::ModifiedJulian, date!(1970 - 01 - 01), 40_587),
+            (Epoch::RataDie, date!(1 - 01 - 01), 1),
+            (Epoch::RataDie, date!(-1 - 12 - 31), 0),
+            // The example date of Calendrical Calculations.
+            (Epoch::RataDie, date!(1945 - 11 - 12), 710_347),
+        ];
+        for (epoch, date, days) in cases {
+            let standard = StandardCalendar::from(&date);
+            assert_eq!(standard.to_epoch(epoch), days, "{date:?} in {epoch:?}");
+            assert_eq!(
+                Date::from(StandardCalendar::from_epoch(epoch, days)),
+                date,
+                "{days} in {epoch:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn date_day_numbers() {
+        let cases = [
+            (date!(-1 - 12 - 31), 0, -678_576),
+            (date!(1 - 01 - 01), 1, -678_575),
+            (date!(1 - 01 - 02), 2, -678_574),
+            (date!(1858 - 11 - 16), 678_575, -1),
+            (date!(1858 - 11 - 17), 678_576, 0),
+            (date!(1858 - 11 - 18), 678_577, 1),
+            (date!(2000 - 01 - 01), 730_120, 51_544),
+        ];
+        for (date, rata_die, mjd) in cases {
+            assert_eq!(date.to_rata_die(), rata_die, "{date:?}");
+            assert_eq!(date.to_rata_die(), date.as_days() + 1, "{date:?}");
+            assert_eq!(date.to_mjd(), mjd, "{date:?}");
+            assert_eq!(Date::from_rata_die(rata_die), date);
+            assert_eq!(Date::from_mjd(mjd), date);
+        }
+    }
+}
@@ -0,0 +1,353 @@
+//! The crate-wide [`Error`], which every specific error type converts into.
+//!
+//! Fallible functions return their own error type, such as [`FuzzyParseError`], which says exactly what went wrong.
+//! [`Error`] sorts those by the kind of failure, so code calling several of them can use `?` with a single error
+//! type, and still match on the specific error when it needs to.
+//!
+//! # Examples
+//! ```
+//! use time::{Error, date::gregorian::{Date, Month, Year}, parse::parse_offset};
+//!
+//! fn anniversary(year: &str, offset: &str) -> Result<Date, Error> {
+//!     let start = Date::from_parts(year.parse::<Year>()?, Month::July, 20)?;
+//!     Ok(start.apply_offset(&parse_offset(offset)?))
+//! }
+//!
+//! assert!(anniversary("1969", "+50y").is_ok());
+//! let error = anniversary("0", "+50y").unwrap_err();
+//! assert!(matches!(error, Error::Parse(_)));
+//! assert_eq!(error.to_string(), "there is no year 0");
+//! ```
+
+use std::fmt;
+
+use crate::approximate::edtf::EdtfParseError;
+use crate::calendar::OutOfCalendarRange;
+use crate::date::chinese::ChineseDateError;
+use crate::date::gregorian::errors::{DateCreationError, PartialDateParseError, YearParseError};
+use crate::date::historical::HistoricalDateError;
+use crate::format::FormatError;
+use crate::instant::InstantParseError;
+#[cfg(feature = "wasm")]
+use crate::js::JsTimeError;
+use crate::parse::{FuzzyParseError, OffsetParseError, WikidataParseError};
+
+/// Any error from this crate, by the kind of failure.
+///
+/// Its [`Display`](fmt::Display) writes the message of the specific error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The parts given don't make up a date in their calendar.
+    DateCreation(CreationError),
+    /// Text couldn't be read as a date, a time, an offset or a format.
+    Parse(ParseError),
+    /// A value is too far away to be represented.
+    Overflow {
+        /// What was too far away, such as "the JavaScript timestamp".
+        what: &'static str,
+    },
+    /// A value can't be represented in the calendar or format it is converted to.
+    UnsupportedConversion {
+        /// Why it can't be converted, such as "the date is outside of the Chinese calendar's table of years".
+        reason: &'static str,
+    },
+    /// A day is outside of the range the calendar it is converted to supports.
+    OutOfCalendarRange(OutOfCalendarRange),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DateCreation(error) => error.fmt(f),
+            Self::Parse(error) => error.fmt(f),
+            Self::Overflow { what } => write!(f, "{what} is too far away to be represented"),
+            Self::UnsupportedConversion { reason } => f.write_str(reason),
+            Self::OutOfCalendarRange(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    /// The specific error, for the kinds that wrap one.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::DateCreation(error) => Some(error.specific()),
+            Self::Parse(error) => Some(error.specific()),
+            Self::OutOfCalendarRange(error) => Some(error),
+            Self::Overflow { .. } | Self::UnsupportedConversion { .. } => None,
+        }
+    }
+}
+
+/// The specific error of an [`Error::DateCreation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreationError {
+    Gregorian(DateCreationError),
+    Historical(HistoricalDateError),
+    Chinese(ChineseDateError),
+}
+
+impl CreationError {
+    /// The error this wraps.
+    fn specific(&self) -> &(dyn std::error::Error + 'static) {
+        match self {
+            Self::Gregorian(error) => error,
+            Self::Historical(error) => error,
+            Self::Chinese(error) => error,
+        }
+    }
+}
+
+impl fmt::Display for CreationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.specific(), f)
+    }
+}
+
+/// The specific error of an [`Error::Parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    Year(YearParseError),
+    PartialDate(PartialDateParseError),
+    Format(FormatError),
+    Fuzzy(FuzzyParseError),
+    Wikidata(WikidataParseError),
+    Offset(OffsetParseError),
+    Instant(InstantParseError),
+    Edtf(EdtfParseError),
+}
+
+impl ParseError {
+    /// The error this wraps.
+    fn specific(&self) -> &(dyn std::error::Error + 'static) {
+        match self {
+            Self::Year(error) => error,
+            Self::PartialDate(error) => error,
+            Self::Format(error) => error,
+            Self::Fuzzy(error) => error,
+            Self::Wikidata(error) => error,
+            Self::Offset(error) => error,
+            Self::Instant(error) => error,
+            Self::Edtf(error) => error,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.specific(), f)
+    }
+}
+
+/// Implements [`From`] for [`Error`] from each error type, through the variant of the outer type wrapping it.
+macro_rules! from_specific {
+    ($($error:ty => $variant:ident($inner:ident::$kind:ident)),* $(,)?) => {
+        $(
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Self::$variant($inner::$kind(error))
+                }
+            }
+        )*
+    };
+}
+
+from_specific! {
+    DateCreationError => DateCreation(CreationError::Gregorian),
+    HistoricalDateError => DateCreation(CreationError::Historical),
+    YearParseError => Parse(ParseError::Year),
+    PartialDateParseError => Parse(ParseError::PartialDate),
+    FormatError => Parse(ParseError::Format),
+    FuzzyParseError => Parse(ParseError::Fuzzy),
+    WikidataParseError => Parse(ParseError::Wikidata),
+    OffsetParseError => Parse(ParseError::Offset),
+    InstantParseError => Parse(ParseError::Instant),
+    EdtfParseError => Parse(ParseError::Edtf),
+}
+
+impl From<ChineseDateError> for Error {
+    /// Dates outside of the table of years can't be converted into the Chinese calendar. Other errors are about the
+    /// parts given.
+    fn from(error: ChineseDateError) -> Self {
+        match error {
+            ChineseDateError::OutOfRange => Self::UnsupportedConversion {
+                reason: "the date is outside of the Chinese calendar's table of years, from 1900 to 2100",
+            },
+            error => Self::DateCreation(CreationError::Chinese(error)),
+        }
+    }
+}
+
+impl From<OutOfCalendarRange> for Error {
+    fn from(error: OutOfCalendarRange) -> Self {
+        Self::OutOfCalendarRange(error)
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl From<JsTimeError> for Error {
+    fn from(error: JsTimeError) -> Self {
+        match error {
+            JsTimeError::NotFinite => Self::UnsupportedConversion {
+                reason: "the JavaScript timestamp is NaN or infinite",
+            },
+            JsTimeError::OutOfRange => Self::Overflow {
+                what: "the JavaScript timestamp",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::chinese;
+
+    #[test]
+    fn messages() {
+        let cases: [(Error, &str); 18] = [
+            (
+                DateCreationError::InvalidMonth(13).into(),
+                "there is no month 13",
+            ),
+            (
+                DateCreationError::InvalidDay(30).into(),
+                "the month has no day 30",
+            ),
+            (
+                DateCreationError::InvalidOrdinal(366).into(),
+                "the year has no day 366",
+            ),
+            (
+                DateCreationError::NoSuchOccurrence(5).into(),
+                "the month has no occurrence 5 of the weekday",
+            ),
+            (
+                HistoricalDateError::Skipped.into(),
+                "the date was skipped by the switch to the Gregorian Calendar",
+            ),
+            (
+                ChineseDateError::InvalidMonth(chinese::Month::leap(3)).into(),
+                "the year has no leap month 3",
+            ),
+            (
+                ChineseDateError::OutOfRange.into(),
+                "the date is outside of the Chinese calendar's table of years, from 1900 to 2100",
+            ),
+            (YearParseError::Zero.into(), "there is no year 0"),
+            (
+                PartialDateParseError::Year(YearParseError::Invalid).into(),
+                "invalid year: not a year",
+            ),
+            (
+                FormatError::UnknownDirective {
+                    position: 3,
+                    directive: 'q',
+                }
+                .into(),
+                "unknown directive `%q` at byte 3",
+            ),
+            (
+                FormatError::MissingField("year").into(),
+                "the format has no year",
+            ),
+            (
+                FuzzyParseError::InvalidDate(DateCreationError::InvalidDay(31)).into(),
+                "invalid date: the month has no day 31",
+            ),
+            (
+                WikidataParseError::UnsupportedPrecision(12).into(),
+                "unsupported precision 12, only 6 (millennium) to 11 (day) are",
+            ),
+            (
+                OffsetParseError::Malformed { position: 2 }.into(),
+                "not an offset from byte 2",
+            ),
+            (
+                InstantParseError::Malformed { position: 19 }.into(),
+                "not an RFC 3339 timestamp from byte 19",
+            ),
+            (
+                EdtfParseError::Unrepresentable { position: 5 }.into(),
+                "the EDTF date from byte 5 has no approximate date",
+            ),
+            (
+                OutOfCalendarRange {
+                    day: crate::StandardCalendar::new(-1),
+                }
+                .into(),
+                "day -1 (0001-12-31 BCE) is outside of the calendar's supported range",
+            ),
+            (
+                Error::Overflow {
+                    what: "the JavaScript timestamp",
+                },
+                "the JavaScript timestamp is too far away to be represented",
+            ),
+        ];
+        for (error, message) in cases {
+            assert_eq!(error.to_string(), message, "{error:?}");
+        }
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!(
+            Error::from(DateCreationError::InvalidDay(30)),
+            Error::DateCreation(CreationError::Gregorian(DateCreationError::InvalidDay(30)))
+        );
+        assert_eq!(
+            Error::from(ChineseDateError::InvalidDay(30)),
+            Error::DateCreation(CreationError::Chinese(ChineseDateError::InvalidDay(30)))
+        );
+        assert!(matches!(
+            Error::from(ChineseDateError::OutOfRange),
+            Error::UnsupportedConversion { .. }
+        ));
+        let out_of_range = OutOfCalendarRange {
+            day: crate::StandardCalendar::new(0),
+        };
+        assert_eq!(
+            Error::from(out_of_range),
+            Error::OutOfCalendarRange(out_of_range)
+        );
+        assert_eq!(
+            Error::from(OffsetParseError::Empty),
+            Error::Parse(ParseError::Offset(OffsetParseError::Empty))
+        );
+
+        #[cfg(feature = "wasm")]
+        assert_eq!(
+            Error::from(JsTimeError::OutOfRange),
+            Error::Overflow {
+                what: "the JavaScript timestamp"
+            }
+        );
+
+        let boxed: Box<dyn std::error::Error> = Box::new(Error::from(YearParseError::Zero));
+        assert_eq!(boxed.to_string(), "there is no year 0");
+    }
+
+    #[test]
+    fn sources() {
+        use std::error::Error as _;
+
+        let error = Error::from(HistoricalDateError::Skipped);
+        let source = error.source().and_then(|source| source.downcast_ref());
+        assert_eq!(source, Some(&HistoricalDateError::Skipped));
+
+        let error = Error::from(OffsetParseError::Empty);
+        let source = error.source().and_then(|source| source.downcast_ref());
+        assert_eq!(source, Some(&OffsetParseError::Empty));
+
+        let out_of_range = OutOfCalendarRange {
+            day: crate::StandardCalendar::new(0),
+        };
+        let error = Error::from(out_of_range);
+        let source = error.source().and_then(|source| source.downcast_ref());
+        assert_eq!(source, Some(&out_of_range));
+
+        let error = Error::from(ChineseDateError::OutOfRange);
+        assert!(error.source().is_none());
+    }
+}
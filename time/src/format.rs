@@ -0,0 +1,369 @@
+//! Formatting and parsing of dates with [strftime](https://en.cppreference.com/w/c/chrono/strftime)-style format strings.
+//!
+//! The supported directives are:
+//!
+//! | Directive | Meaning                                                             | Example   |
+//! |-----------|---------------------------------------------------------------------|-----------|
+//! | `%Y`      | The year. Negative before the common era, unless `%E` is also used. | `2024`    |
+//! | `%C`      | The [century](crate::date::gregorian::Year::century), following the same sign rule as `%Y`. | `21` |
+//! | `%m`      | The month, zero-padded to 2 digits.                                 | `04`      |
+//! | `%B`      | The full month name.                                                | `April`   |
+//! | `%b`      | The abbreviated month name.                                         | `Apr`     |
+//! | `%d`      | The day of the month, zero-padded to 2 digits.                      | `02`      |
+//! | `%e`      | The day of the month, space-padded to 2 digits.                     | ` 2`      |
+//! | `%j`      | The day of the year, zero-padded to 3 digits.                       | `093`     |
+//! | `%A`      | The full weekday name.                                              | `Tuesday` |
+//! | `%a`      | The abbreviated weekday name.                                       | `Tue`     |
+//! | `%E`      | The era, either `BCE` or `CE`.                                      | `CE`      |
+//! | `%%`      | A literal `%`.                                                      | `%`       |
+//!
+//! Any other directive is rejected as soon as the format string is read, before any formatting or parsing is done.
+//!
+//! Names are written in English, unless a [`Locale`](crate::locale::Locale) is given, such as through
+//! [`Date::format_localized`](crate::date::gregorian::Date::format_localized).
+//!
+//! Note that `%C` uses the historical convention for centuries (2024 is in the 21st century), unlike C's `strftime`.
+
+use std::fmt;
+
+use crate::StandardCalendar;
+use crate::date::gregorian::errors::DateCreationError;
+
+/// The error type for formatting and parsing dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// The format string has an unsupported `directive` at byte `position`.
+    UnknownDirective { position: usize, directive: char },
+    /// The format string ends with a lone `%` at byte `position`.
+    IncompleteDirective { position: usize },
+    /// The input stops matching the format string at byte `position` of the input.
+    Mismatch { position: usize },
+    /// The format string lacks the directives needed to build a date, such as a year.
+    MissingField(&'static str),
+    /// Parsed fields contradict each other, such as a weekday that doesn't match the date.
+    Inconsistent(&'static str),
+    /// The parsed fields don't make up a valid date.
+    InvalidDate(DateCreationError),
+    /// The parsed year is too far away to be represented.
+    OutOfRange,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownDirective {
+                position,
+                directive,
+            } => write!(f, "unknown directive `%{directive}` at byte {position}"),
+            Self::IncompleteDirective { position } => write!(f, "lone `%` at byte {position}"),
+            Self::Mismatch { position } => {
+                write!(f, "the input doesn't match the format from byte {position}")
+            }
+            Self::MissingField(field) => write!(f, "the format has no {field}"),
+            Self::Inconsistent(field) => write!(f, "the {field} contradicts the other fields"),
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+            Self::OutOfRange => f.write_str("the year is too far away to be represented"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<DateCreationError> for FormatError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
+
+/// Describes how far `to` is from `from` in words, such as "2,000 years ago" or "in 3 months".
+///
+/// This is [`relative_with_precision`] keeping 3 significant digits.
+///
+/// # Examples
+/// ```
+/// use time::{StandardCalendar, format};
+///
+/// let now = StandardCalendar::new(738_000);
+/// assert_eq!(format::relative(&now, &StandardCalendar::new(738_000 - 730_485)), "2,000 years ago");
+/// assert_eq!(format::relative(&now, &StandardCalendar::new(738_000 + 91)), "in 3 months");
+/// assert_eq!(format::relative(&now, &StandardCalendar::new(738_000 - 1)), "1 day ago");
+/// assert_eq!(format::relative(&now, &now), "today");
+/// ```
+pub fn relative(from: &StandardCalendar, to: &StandardCalendar) -> String {
+    relative_with_precision(from, to, 3)
+}
+
+/// Describes how far `to` is from `from` in words, such as "about 66 million years ago" or "in 3 months".
+///
+/// The unit is the largest one that still reads sensibly:
+/// - Under 60 days, the distance is given in days.
+/// - Under 24 months, it's given in months of 30.436875 days (the average Gregorian month).
+/// - Under 10,000 years, it's given in years of 365.2425 days (the average Gregorian year).
+/// - Larger distances are given in thousands, millions or billions of years.
+///
+/// Days, months and years are rounded to the nearest whole number, with halves rounding up. Thousands, millions and
+/// billions of years are rounded to `significant_digits` significant digits (at least 1), and are prefixed with
+/// "about" when that rounding drops any digits. Rounded values keep their trailing zeros, while exact ones don't.
+///
+/// # Examples
+/// ```
+/// use time::{StandardCalendar, format};
+///
+/// let now = StandardCalendar::new(0);
+/// let dinosaurs = StandardCalendar::new(-24_106_059_096);
+/// assert_eq!(format::relative_with_precision(&now, &dinosaurs, 2), "about 66 million years ago");
+/// assert_eq!(format::relative_with_precision(&now, &dinosaurs, 4), "about 66.00 million years ago");
+/// assert_eq!(format::relative_with_precision(&now, &StandardCalendar::new(547_863_750), 2), "in 1.5 million years");
+/// ```
+pub fn relative_with_precision(
+    from: &StandardCalendar,
+    to: &StandardCalendar,
+    significant_digits: u32,
+) -> String {
+    // Average Gregorian lengths, from the 146097 days in 400 years.
+    const DAYS_IN_400_YEARS: u128 = 146_097;
+    const MONTHS_IN_400_YEARS: u128 = 4_800;
+
+    let (past, days) = match to.days.checked_sub(from.days) {
+        Some(difference) => (difference < 0, difference.unsigned_abs()),
+        // The difference doesn't fit an `i128`, but always fits an `u128`.
+        None => (to.days < from.days, to.days.abs_diff(from.days)),
+    };
+    if days == 0 {
+        return "today".to_string();
+    }
+
+    // Divide first so that deep time can't overflow.
+    let in_average = |per_400_years: u128| {
+        let (cycles, rest) = (days / DAYS_IN_400_YEARS, days % DAYS_IN_400_YEARS);
+        cycles * per_400_years + (rest * per_400_years + DAYS_IN_400_YEARS / 2) / DAYS_IN_400_YEARS
+    };
+    let plural = |amount: u128, unit: &str| {
+        if amount == 1 {
+            format!("1 {unit}")
+        } else {
+            format!("{} {unit}s", with_separators(amount))
+        }
+    };
+
+    let months = in_average(MONTHS_IN_400_YEARS);
+    let years = in_average(400);
+    let distance = if days < 60 {
+        plural(days, "day")
+    } else if months < 24 {
+        plural(months, "month")
+    } else if years < 10_000 {
+        plural(years, "year")
+    } else {
+        let (rounded, kept_digits) = round_significant(years, significant_digits.max(1));
+        let about = if rounded == years { "" } else { "about " };
+        // Picked after rounding, so that 999,999 years can become "1 million".
+        let (scale_digits, unit) = match rounded.ilog10() {
+            ..6 => (3, "thousand"),
+            6..9 => (6, "million"),
+            _ => (9, "billion"),
+        };
+        // Show as many decimals as there are significant digits left after the whole part.
+        let whole_digits = rounded.ilog10() + 1 - scale_digits;
+        let mut decimals = kept_digits.saturating_sub(whole_digits).min(scale_digits);
+        let shown = rounded / 10u128.pow(scale_digits - decimals);
+        let (whole, mut fraction) = (shown / 10u128.pow(decimals), shown % 10u128.pow(decimals));
+        // Exact values don't need trailing zeros to show their precision.
+        while about.is_empty() && decimals > 0 && fraction % 10 == 0 {
+            decimals -= 1;
+            fraction /= 10;
+        }
+
+        if decimals == 0 {
+            format!("{about}{} {unit} years", with_separators(whole))
+        } else {
+            format!(
+                "{about}{}.{fraction:0width$} {unit} years",
+                with_separators(whole),
+                width = decimals as usize
+            )
+        }
+    };
+
+    if past {
+        format!("{distance} ago")
+    } else {
+        format!("in {distance}")
+    }
+}
+
+/// Rounds `number` to `digits` significant digits, with halves rounding up.
+///
+/// Also returns how many significant digits are kept, which may be fewer than `digits` for short numbers.
+fn round_significant(number: u128, digits: u32) -> (u128, u32) {
+    let length = number.ilog10() + 1;
+    if length <= digits {
+        return (number, length);
+    }
+    let divisor = 10u128.pow(length - digits);
+    ((number + divisor / 2) / divisor * divisor, digits)
+}
+
+/// Writes `number` with commas separating each group of 3 digits.
+fn with_separators(number: u128) -> String {
+    let digits = number.to_string();
+    let mut separated = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index != 0 && (digits.len() - index).is_multiple_of(3) {
+            separated.push(',');
+        }
+        separated.push(digit);
+    }
+    separated
+}
+
+/// A piece of a format string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Item<'a> {
+    Literal(&'a str),
+    /// `%Y`
+    Year,
+    /// `%C`
+    Century,
+    /// `%m`
+    Month,
+    /// `%B`
+    MonthName,
+    /// `%b`
+    MonthAbbreviation,
+    /// `%d`
+    Day,
+    /// `%e`
+    DaySpacePadded,
+    /// `%j`
+    DayOfYear,
+    /// `%A`
+    WeekdayName,
+    /// `%a`
+    WeekdayAbbreviation,
+    /// `%E`
+    Era,
+}
+
+/// Splits a format string into its literal parts and directives.
+pub(crate) fn items(format: &str) -> Result<Vec<Item<'_>>, FormatError> {
+    let mut items = Vec::new();
+    let mut literal_start = 0;
+    let mut chars = format.char_indices();
+
+    while let Some((position, char)) = chars.next() {
+        if char != '%' {
+            continue;
+        }
+        if literal_start < position {
+            items.push(Item::Literal(&format[literal_start..position]));
+        }
+
+        let (directive_position, directive) = chars
+            .next()
+            .ok_or(FormatError::IncompleteDirective { position })?;
+        items.push(match directive {
+            'Y' => Item::Year,
+            'C' => Item::Century,
+            'm' => Item::Month,
+            'B' => Item::MonthName,
+            'b' => Item::MonthAbbreviation,
+            'd' => Item::Day,
+            'e' => Item::DaySpacePadded,
+            'j' => Item::DayOfYear,
+            'A' => Item::WeekdayName,
+            'a' => Item::WeekdayAbbreviation,
+            'E' => Item::Era,
+            '%' => Item::Literal("%"),
+            directive => {
+                return Err(FormatError::UnknownDirective {
+                    position,
+                    directive,
+                });
+            }
+        });
+        literal_start = directive_position + directive.len_utf8();
+    }
+    if literal_start < format.len() {
+        items.push(Item::Literal(&format[literal_start..]));
+    }
+
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_items() {
+        assert_eq!(
+            items("%d/%m/%Y %% é%E"),
+            Ok(vec![
+                Item::Day,
+                Item::Literal("/"),
+                Item::Month,
+                Item::Literal("/"),
+                Item::Year,
+                Item::Literal(" "),
+                Item::Literal("%"),
+                Item::Literal(" é"),
+                Item::Era,
+            ])
+        );
+        assert_eq!(items(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn relative_units() {
+        let now = StandardCalendar::new(0);
+        let at = |days| relative(&now, &StandardCalendar::new(days));
+
+        assert_eq!(at(1), "in 1 day");
+        assert_eq!(at(-59), "59 days ago");
+        assert_eq!(at(60), "in 2 months");
+        assert_eq!(at(-700), "23 months ago");
+        assert_eq!(at(731), "in 2 years");
+        assert_eq!(at(-365), "12 months ago");
+        assert_eq!(at(-731), "2 years ago");
+        // 9,999 and 10,000 years.
+        assert_eq!(at(-3_652_059), "9,999 years ago");
+        assert_eq!(at(3_652_425), "in 10 thousand years");
+        assert_eq!(at(-3_652_790), "about 10.0 thousand years ago");
+        // Rounding up into the next unit.
+        assert_eq!(at(-365_242_134), "about 1.00 million years ago");
+        assert_eq!(at(365_242_500_000), "in 1 billion years");
+    }
+
+    #[test]
+    fn relative_deep_time() {
+        // Must not overflow, even for the largest distances.
+        let (min, max) = (
+            StandardCalendar::new(i128::MIN),
+            StandardCalendar::new(i128::MAX),
+        );
+        assert_eq!(
+            relative_with_precision(&max, &min, 2),
+            "about 930,000,000,000,000,000,000,000,000 billion years ago"
+        );
+        assert_eq!(
+            relative_with_precision(&min, &max, 1),
+            "in about 900,000,000,000,000,000,000,000,000 billion years"
+        );
+    }
+
+    #[test]
+    fn invalid_format_strings() {
+        assert_eq!(
+            items("%Y-%q"),
+            Err(FormatError::UnknownDirective {
+                position: 3,
+                directive: 'q'
+            })
+        );
+        assert_eq!(
+            items("%Y %"),
+            Err(FormatError::IncompleteDirective { position: 3 })
+        );
+    }
+}
@@ -0,0 +1,312 @@
+//! Holidays, defined by rules that give their date in each year.
+//!
+//! A [`HolidaySet`] holds named [`HolidayRule`]s. Build your own, or start from one of the example sets.
+
+use crate::Calendar;
+use crate::Weekday;
+use crate::date::gregorian::{Date, Month, Occurrence, Year, computus};
+
+/// How to find the date of a holiday in a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolidayRule {
+    /// The same day every year, such as December 25th.
+    Fixed { month: Month, day: u8 },
+    /// A weekday of a month, such as the fourth Thursday of November. See [`Date::nth_weekday_of_month`].
+    NthWeekday {
+        month: Month,
+        weekday: Weekday,
+        occurrence: Occurrence,
+    },
+    /// This many days from Easter Sunday, such as -2 for Good Friday. See [`computus::from_easter`].
+    FromEaster(i128),
+}
+
+impl HolidayRule {
+    /// The date this rule gives in `year`.
+    ///
+    /// Returns [`None`] for years in which the date doesn't exist, like February 29th in a common year or a fifth
+    /// weekday in a month that only has four.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Weekday, date::gregorian::{Month, Occurrence, date, year}, holidays::HolidayRule};
+    ///
+    /// let thanksgiving = HolidayRule::NthWeekday {
+    ///     month: Month::November,
+    ///     weekday: Weekday::Thursday,
+    ///     occurrence: Occurrence::Nth(4),
+    /// };
+    /// assert_eq!(thanksgiving.date_in(year!(2024)), Some(date!(2024-11-28)));
+    ///
+    /// let leap_day = HolidayRule::Fixed { month: Month::February, day: 29 };
+    /// assert_eq!(leap_day.date_in(year!(2023)), None);
+    /// ```
+    pub fn date_in(&self, year: Year) -> Option<Date> {
+        match *self {
+            Self::Fixed { month, day } => Date::from_parts(year, month, day).ok(),
+            Self::NthWeekday {
+                month,
+                weekday,
+                occurrence,
+            } => Date::nth_weekday_of_month(year, month, weekday, occurrence).ok(),
+            Self::FromEaster(days) => Some(computus::from_easter(year, days)),
+        }
+    }
+}
+
+/// A holiday: a name, and the rule for its date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    pub name: String,
+    pub rule: HolidayRule,
+}
+
+/// A set of [`Holiday`]s, such as those observed in a country.
+///
+/// # Examples
+/// ```
+/// use time::{date::gregorian::{Month, date, year}, holidays::{HolidayRule, HolidaySet}};
+///
+/// let holidays = HolidaySet::new()
+///     .with("Easter", HolidayRule::FromEaster(0))
+///     .with("Good Friday", HolidayRule::FromEaster(-2))
+///     .with("Tiradentes", HolidayRule::Fixed { month: Month::April, day: 21 });
+///
+/// assert_eq!(
+///     holidays.dates_in(year!(2024)),
+///     [
+///         (date!(2024-03-29), "Good Friday"),
+///         (date!(2024-03-31), "Easter"),
+///         (date!(2024-04-21), "Tiradentes"),
+///     ]
+/// );
+/// assert_eq!(holidays.holiday_on(&date!(2025-04-18)), Some("Good Friday"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HolidaySet {
+    holidays: Vec<Holiday>,
+}
+
+impl HolidaySet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a holiday called `name` to this set.
+    pub fn with(mut self, name: impl Into<String>, rule: HolidayRule) -> Self {
+        self.holidays.push(Holiday {
+            name: name.into(),
+            rule,
+        });
+        self
+    }
+
+    /// The holidays in this set, in the order they were added.
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+
+    /// The date of every holiday in `year` with its name, sorted by date.
+    ///
+    /// Holidays whose rule gives no date in `year` are left out. Holidays on the same date keep the order they were
+    /// added in.
+    pub fn dates_in(&self, year: Year) -> Vec<(Date, &str)> {
+        let mut dates: Vec<_> = self
+            .holidays
+            .iter()
+            .filter_map(|holiday| Some((holiday.rule.date_in(year)?, holiday.name.as_str())))
+            .collect();
+        dates.sort_by_key(|(date, _)| *date);
+        dates
+    }
+
+    /// The name of the first holiday in this set that falls on `date`, if any.
+    pub fn holiday_on(&self, date: &Date) -> Option<&str> {
+        self.holidays
+            .iter()
+            .find(|holiday| holiday.rule.date_in(date.year()) == Some(*date))
+            .map(|holiday| holiday.name.as_str())
+    }
+
+    /// The federal holidays of the United States, as listed in 5 U.S.C. § 6103.
+    ///
+    /// These are the current holidays on their actual dates, without moving those on weekends to the observed day,
+    /// and they are given for every year, including ones before each holiday was established.
+    pub fn us_federal() -> Self {
+        use Month::*;
+        use Weekday::{Monday, Thursday};
+
+        let nth = |month, weekday, n| HolidayRule::NthWeekday {
+            month,
+            weekday,
+            occurrence: Occurrence::Nth(n),
+        };
+        let last = |month, weekday| HolidayRule::NthWeekday {
+            month,
+            weekday,
+            occurrence: Occurrence::Last,
+        };
+        Self::new()
+            .with("New Year's Day", fixed(January, 1))
+            .with(
+                "Birthday of Martin Luther King, Jr.",
+                nth(January, Monday, 3),
+            )
+            .with("Washington's Birthday", nth(February, Monday, 3))
+            .with("Memorial Day", last(May, Monday))
+            .with("Juneteenth National Independence Day", fixed(June, 19))
+            .with("Independence Day", fixed(July, 4))
+            .with("Labor Day", nth(September, Monday, 1))
+            .with("Columbus Day", nth(October, Monday, 2))
+            .with("Veterans Day", fixed(November, 11))
+            .with("Thanksgiving Day", nth(November, Thursday, 4))
+            .with("Christmas Day", fixed(December, 25))
+    }
+
+    /// Days observed internationally on a fixed date, most of them proclaimed by the United Nations.
+    pub fn international() -> Self {
+        use Month::*;
+
+        Self::new()
+            .with("New Year's Day", fixed(January, 1))
+            .with("International Women's Day", fixed(March, 8))
+            .with("International Workers' Day", fixed(May, 1))
+            .with("United Nations Day", fixed(October, 24))
+            .with("Human Rights Day", fixed(December, 10))
+    }
+}
+
+/// Shorthand for [`HolidayRule::Fixed`] in the example sets.
+fn fixed(month: Month, day: u8) -> HolidayRule {
+    HolidayRule::Fixed { month, day }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{date, year};
+
+    #[test]
+    fn nth_weekday_rules() {
+        let last_monday_of_may = HolidayRule::NthWeekday {
+            month: Month::May,
+            weekday: Weekday::Monday,
+            occurrence: Occurrence::Last,
+        };
+        let fifth_friday_of_march = HolidayRule::NthWeekday {
+            month: Month::March,
+            weekday: Weekday::Friday,
+            occurrence: Occurrence::Nth(5),
+        };
+        let cases = [
+            (year!(2021), Some(date!(2021 - 05 - 31)), None),
+            (year!(2022), Some(date!(2022 - 05 - 30)), None),
+            (
+                year!(2023),
+                Some(date!(2023 - 05 - 29)),
+                Some(date!(2023 - 03 - 31)),
+            ),
+            (
+                year!(2024),
+                Some(date!(2024 - 05 - 27)),
+                Some(date!(2024 - 03 - 29)),
+            ),
+            (year!(2025), Some(date!(2025 - 05 - 26)), None),
+        ];
+        for (year, memorial_day, fifth_friday) in cases {
+            assert_eq!(last_monday_of_may.date_in(year), memorial_day, "{year}");
+            assert_eq!(fifth_friday_of_march.date_in(year), fifth_friday, "{year}");
+        }
+    }
+
+    #[test]
+    fn easter_rules() {
+        let ascension = HolidayRule::FromEaster(39);
+        let carnival = HolidayRule::FromEaster(-47);
+        let cases = [
+            (year!(2019), date!(2019 - 05 - 30), date!(2019 - 03 - 05)),
+            (year!(2024), date!(2024 - 05 - 09), date!(2024 - 02 - 13)),
+            (year!(2025), date!(2025 - 05 - 29), date!(2025 - 03 - 04)),
+            (year!(2038), date!(2038 - 06 - 03), date!(2038 - 03 - 09)),
+        ];
+        for (year, ascension_day, carnival_day) in cases {
+            assert_eq!(ascension.date_in(year), Some(ascension_day), "{year}");
+            assert_eq!(carnival.date_in(year), Some(carnival_day), "{year}");
+        }
+    }
+
+    #[test]
+    fn example_sets() {
+        let us_2024: Vec<_> = HolidaySet::us_federal()
+            .dates_in(year!(2024))
+            .into_iter()
+            .map(|(date, _)| date)
+            .collect();
+        assert_eq!(
+            us_2024,
+            [
+                date!(2024 - 01 - 01),
+                date!(2024 - 01 - 15),
+                date!(2024 - 02 - 19),
+                date!(2024 - 05 - 27),
+                date!(2024 - 06 - 19),
+                date!(2024 - 07 - 04),
+                date!(2024 - 09 - 02),
+                date!(2024 - 10 - 14),
+                date!(2024 - 11 - 11),
+                date!(2024 - 11 - 28),
+                date!(2024 - 12 - 25),
+            ]
+        );
+
+        let international = HolidaySet::international();
+        assert_eq!(international.dates_in(year!(-44)).len(), 5);
+        assert_eq!(
+            international.holiday_on(&date!(1948 - 12 - 10)),
+            Some("Human Rights Day")
+        );
+        assert_eq!(international.holiday_on(&date!(1948 - 12 - 11)), None);
+    }
+
+    #[test]
+    fn skipped_and_shared_dates() {
+        let holidays = HolidaySet::new()
+            .with(
+                "Leap Day",
+                HolidayRule::Fixed {
+                    month: Month::February,
+                    day: 29,
+                },
+            )
+            .with(
+                "St. Patrick's Day",
+                HolidayRule::Fixed {
+                    month: Month::March,
+                    day: 17,
+                },
+            )
+            // Two weeks before Easter, which is St. Patrick's Day in 2024.
+            .with("Shared", HolidayRule::FromEaster(-14));
+
+        assert_eq!(
+            holidays.dates_in(year!(2023)),
+            [
+                (date!(2023 - 03 - 17), "St. Patrick's Day"),
+                (date!(2023 - 03 - 26), "Shared")
+            ]
+        );
+        assert_eq!(
+            holidays.dates_in(year!(2024)),
+            [
+                (date!(2024 - 02 - 29), "Leap Day"),
+                (date!(2024 - 03 - 17), "St. Patrick's Day"),
+                (date!(2024 - 03 - 17), "Shared"),
+            ]
+        );
+        assert_eq!(
+            holidays.holiday_on(&date!(2024 - 03 - 17)),
+            Some("St. Patrick's Day")
+        );
+    }
+}
@@ -0,0 +1,461 @@
+//! Moments in time, for modern events where the time of day and the time zone matter.
+//!
+//! A [`Date`] is local and zone-free: "July 20th of 1969" names a day on a calendar, without saying where, so it
+//! started at different moments in Houston and in UTC. A [`UtcInstant`] is a single moment instead, which falls on
+//! different dates depending on the [`UtcOffset`] it is seen from.
+//!
+//! There are no time zones, only fixed offsets from UTC. Daylight saving time has to be applied by picking the
+//! offset that was in use.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::calendar::{Calendar, StandardCalendar};
+use crate::date::gregorian::{Date, IsoYear, Month, Year, errors::DateCreationError};
+use crate::epoch::Epoch;
+
+/// How many seconds a day has. Leap seconds aren't counted, as in Unix time.
+const SECONDS_PER_DAY: i128 = 86_400;
+
+/// The error type for parsing a [`UtcInstant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstantParseError {
+    /// The input stops looking like an RFC 3339 timestamp at byte `position`.
+    Malformed { position: usize },
+    /// The date doesn't exist, such as February 30th.
+    InvalidDate(DateCreationError),
+    /// The hour, minute, second or offset is too large, such as 24:00 or an offset of +24:00.
+    InvalidTime,
+}
+
+impl fmt::Display for InstantParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { position } => {
+                write!(f, "not an RFC 3339 timestamp from byte {position}")
+            }
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+            Self::InvalidTime => f.write_str("the time or the offset is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for InstantParseError {}
+
+impl From<DateCreationError> for InstantParseError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
+
+/// A fixed offset from UTC, in minutes, such as -05:00 for Houston in summer.
+///
+/// # Examples
+/// ```
+/// use time::instant::UtcOffset;
+///
+/// let india = UtcOffset::from_minutes(5 * 60 + 30).unwrap();
+/// assert_eq!(india.to_string(), "+05:30");
+/// assert_eq!(UtcOffset::from_minutes(-3 * 60).unwrap().to_string(), "-03:00");
+/// assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+/// assert!(UtcOffset::from_minutes(24 * 60).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UtcOffset {
+    minutes: i16,
+}
+
+impl UtcOffset {
+    /// No offset.
+    pub const UTC: Self = Self { minutes: 0 };
+
+    /// The offset of `minutes` minutes ahead of UTC, or behind it if negative.
+    ///
+    /// Returns [`None`] if the offset is a whole day or more.
+    pub const fn from_minutes(minutes: i16) -> Option<Self> {
+        if minutes.unsigned_abs() < 24 * 60 {
+            Some(Self { minutes })
+        } else {
+            None
+        }
+    }
+
+    /// How many minutes ahead of UTC this offset is, or behind it if negative.
+    pub const fn minutes(self) -> i16 {
+        self.minutes
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    /// Writes the offset as in RFC 3339, like "+05:30".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// A moment in time, as the seconds since the start of January 1st of 1970 in UTC, not counting leap seconds.
+///
+/// Instants are ordered by when they happen, whatever offset they were read with. To order them among days, such
+/// as [`StandardCalendar`]s or [`Date`]s, use their [`utc_day`](UtcInstant::utc_day): an instant belongs to the day
+/// it falls in in UTC.
+///
+/// # Examples
+/// ```
+/// use time::{date::gregorian::date, instant::{UtcInstant, UtcOffset}};
+///
+/// // The first step on the Moon was late on July 20th in Houston, but already the 21st in UTC.
+/// let step: UtcInstant = "1969-07-20T21:56:15-05:00".parse().unwrap();
+/// assert_eq!(step.unix_seconds(), -14_159_025);
+/// assert_eq!(step.date_at(UtcOffset::UTC), date!(1969-07-21));
+/// assert_eq!(step.date_at(UtcOffset::from_minutes(-5 * 60).unwrap()), date!(1969-07-20));
+/// assert_eq!(step.to_string(), "1969-07-21T02:56:15Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcInstant {
+    seconds: i64,
+}
+
+impl UtcInstant {
+    /// The start of January 1st of 1970, in UTC.
+    pub const UNIX_EPOCH: Self = Self { seconds: 0 };
+
+    /// The instant `seconds` seconds after the [Unix epoch](UtcInstant::UNIX_EPOCH), or before it if negative.
+    pub const fn from_unix_seconds(seconds: i64) -> Self {
+        Self { seconds }
+    }
+
+    /// How many seconds this instant is after the [Unix epoch](UtcInstant::UNIX_EPOCH), or before it if negative.
+    pub const fn unix_seconds(self) -> i64 {
+        self.seconds
+    }
+
+    /// The date this instant falls on where the time is `offset` away from UTC.
+    pub fn date_at(self, offset: UtcOffset) -> Date {
+        self.day_at(offset).into()
+    }
+
+    /// The day this instant falls on in UTC, which it is ordered within.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{StandardCalendar, date::gregorian::date, instant::UtcInstant};
+    ///
+    /// let landing = UtcInstant::from_unix_seconds(-14_182_940);
+    /// assert_eq!(landing.utc_day(), StandardCalendar::from(&date!(1969-07-20)));
+    /// assert_eq!(UtcInstant::from_unix_seconds(-1).utc_day(), StandardCalendar::from(&date!(1969-12-31)));
+    /// ```
+    pub fn utc_day(self) -> StandardCalendar {
+        self.day_at(UtcOffset::UTC)
+    }
+
+    /// The seconds since the start of the day this instant falls on where the time is `offset` away from UTC.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::instant::{UtcInstant, UtcOffset};
+    ///
+    /// let landing = UtcInstant::from_unix_seconds(-14_182_940);
+    /// assert_eq!(landing.seconds_into_day(UtcOffset::UTC), 20 * 3_600 + 17 * 60 + 40);
+    /// assert_eq!(landing.seconds_into_day(UtcOffset::from_minutes(-5 * 60).unwrap()), 15 * 3_600 + 17 * 60 + 40);
+    /// ```
+    pub fn seconds_into_day(self, offset: UtcOffset) -> u32 {
+        // Below a day's seconds by the remainder.
+        self.local_seconds(offset).rem_euclid(SECONDS_PER_DAY) as u32
+    }
+
+    /// The day, in the [`StandardCalendar`], this instant falls on where the time is `offset` away from UTC.
+    fn day_at(self, offset: UtcOffset) -> StandardCalendar {
+        let days = self.local_seconds(offset).div_euclid(SECONDS_PER_DAY);
+        StandardCalendar::from_epoch(Epoch::Unix, days)
+    }
+
+    /// The seconds since the Unix epoch on a clock that is `offset` away from UTC.
+    fn local_seconds(self, offset: UtcOffset) -> i128 {
+        i128::from(self.seconds) + 60 * i128::from(offset.minutes)
+    }
+}
+
+impl fmt::Display for UtcInstant {
+    /// Writes the instant in UTC as in RFC 3339, like "1969-07-21T02:56:15Z".
+    ///
+    /// Years are [astronomical](Year::to_astronomical), so 1 BCE is "0000". Years too far away for RFC 3339 are
+    /// written with more digits, or with a `-` sign before year 0.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date_at(UtcOffset::UTC);
+        let seconds = self.seconds_into_day(UtcOffset::UTC);
+        write!(
+            f,
+            "{}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            IsoYear::from(date.year()),
+            u8::from(date.month()),
+            date.day(),
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+impl FromStr for UtcInstant {
+    type Err = InstantParseError;
+
+    /// Reads an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) timestamp, like
+    /// "1969-07-20T21:56:15-05:00" or "1969-07-21T02:56:15.5Z".
+    ///
+    /// The `T` may also be lowercase or a space, and `Z` lowercase. Fractions of a second are dropped, and a leap
+    /// second, written as second 60, is read as the first second of the next minute.
+    ///
+    /// # Errors
+    /// * [`InstantParseError::Malformed`] if the input doesn't have the shape of an RFC 3339 timestamp.
+    /// * [`InstantParseError::InvalidDate`] if the date doesn't exist.
+    /// * [`InstantParseError::InvalidTime`] if the time or the offset is out of range.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        const LAYOUT: &[u8] = b"0000-00-00T00:00:00";
+        let bytes = input.as_bytes();
+        for (position, expected) in LAYOUT.iter().enumerate() {
+            let valid = match (bytes.get(position), expected) {
+                (Some(byte), b'0') => byte.is_ascii_digit(),
+                (Some(byte), b'T') => matches!(byte, b'T' | b't' | b' '),
+                (Some(byte), expected) => byte == expected,
+                (None, _) => false,
+            };
+            if !valid {
+                return Err(InstantParseError::Malformed { position });
+            }
+        }
+        // The layout only has ASCII digits where numbers are.
+        let number =
+            |start: usize, len: usize| -> i128 { input[start..start + len].parse().unwrap() };
+
+        let mut rest = &input[LAYOUT.len()..];
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let digits = fraction
+                .find(|char: char| !char.is_ascii_digit())
+                .unwrap_or(fraction.len());
+            if digits == 0 {
+                return Err(InstantParseError::Malformed {
+                    position: LAYOUT.len() + 1,
+                });
+            }
+            rest = &fraction[digits..];
+        }
+        let offset_minutes = match rest.as_bytes() {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2]
+                if [h1, h2, m1, m2].iter().all(|byte| byte.is_ascii_digit()) =>
+            {
+                let start = input.len() - rest.len();
+                let (hours, minutes) = (number(start + 1, 2), number(start + 4, 2));
+                if hours > 23 || minutes > 59 {
+                    return Err(InstantParseError::InvalidTime);
+                }
+                let minutes = 60 * hours + minutes;
+                if *sign == b'-' { -minutes } else { minutes }
+            }
+            _ => {
+                return Err(InstantParseError::Malformed {
+                    position: input.len() - rest.len(),
+                });
+            }
+        };
+
+        let year = Year::from_astronomical(number(0, 4));
+        // At most 99, which is rejected as a month.
+        let month = Month::try_from(number(5, 2) as u8)?;
+        let date = Date::from_parts(year, month, number(8, 2) as u8)?;
+        let (hour, minute, second) = (number(11, 2), number(14, 2), number(17, 2));
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err(InstantParseError::InvalidTime);
+        }
+
+        let days = StandardCalendar::from(&date).to_epoch(Epoch::Unix);
+        let seconds =
+            days * SECONDS_PER_DAY + 3_600 * hour + 60 * (minute - offset_minutes) + second;
+        // Years from 0 to 9999 are well within the range of an `i64`.
+        Ok(Self::from_unix_seconds(seconds as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::date;
+
+    #[test]
+    fn parsing() {
+        // (input, Unix seconds, RFC 3339 in UTC)
+        let cases = [
+            ("1970-01-01T00:00:00Z", 0, "1970-01-01T00:00:00Z"),
+            ("1969-07-20T20:17:40Z", -14_182_940, "1969-07-20T20:17:40Z"),
+            (
+                "1969-07-20T21:56:15-05:00",
+                -14_159_025,
+                "1969-07-21T02:56:15Z",
+            ),
+            (
+                "1969-07-21t02:56:15.999z",
+                -14_159_025,
+                "1969-07-21T02:56:15Z",
+            ),
+            (
+                "2024-02-29 23:30:00-05:00",
+                1_709_267_400,
+                "2024-03-01T04:30:00Z",
+            ),
+            (
+                "2024-03-01T10:00:00+05:30",
+                1_709_267_400,
+                "2024-03-01T04:30:00Z",
+            ),
+            ("1969-12-31T23:59:59.5Z", -1, "1969-12-31T23:59:59Z"),
+            // A leap second.
+            (
+                "2016-12-31T23:59:60Z",
+                1_483_228_800,
+                "2017-01-01T00:00:00Z",
+            ),
+            (
+                "0001-01-01T00:00:00Z",
+                -62_135_596_800,
+                "0001-01-01T00:00:00Z",
+            ),
+            // 1 BCE, which RFC 3339 writes as year 0.
+            (
+                "0000-01-01T00:00:00Z",
+                -62_167_219_200,
+                "0000-01-01T00:00:00Z",
+            ),
+            (
+                "9999-12-31T23:59:59Z",
+                253_402_300_799,
+                "9999-12-31T23:59:59Z",
+            ),
+        ];
+        for (input, seconds, output) in cases {
+            let instant = input.parse::<UtcInstant>().unwrap();
+            assert_eq!(instant.unix_seconds(), seconds, "{input}");
+            assert_eq!(instant.to_string(), output);
+            assert_eq!(output.parse(), Ok(instant));
+        }
+        assert_eq!(
+            UtcInstant::from_unix_seconds(-62_167_219_201).to_string(),
+            "-0001-12-31T23:59:59Z"
+        );
+
+        let errors = [
+            ("", InstantParseError::Malformed { position: 0 }),
+            ("1969-07-20", InstantParseError::Malformed { position: 10 }),
+            (
+                "1969-07-20T20:17",
+                InstantParseError::Malformed { position: 16 },
+            ),
+            (
+                "1969-07-20T20:17:40",
+                InstantParseError::Malformed { position: 19 },
+            ),
+            (
+                "1969-7-20T20:17:40Z",
+                InstantParseError::Malformed { position: 6 },
+            ),
+            (
+                "+1969-07-20T20:17:40Z",
+                InstantParseError::Malformed { position: 0 },
+            ),
+            (
+                "1969-07-20X20:17:40Z",
+                InstantParseError::Malformed { position: 10 },
+            ),
+            (
+                "1969-07-20T20:17:40.Z",
+                InstantParseError::Malformed { position: 20 },
+            ),
+            (
+                "1969-07-20T20:17:40+0500",
+                InstantParseError::Malformed { position: 19 },
+            ),
+            (
+                "1969-07-20T20:17:40+05:0a",
+                InstantParseError::Malformed { position: 19 },
+            ),
+            (
+                "1969-07-20T20:17:40Zulu",
+                InstantParseError::Malformed { position: 19 },
+            ),
+            (
+                "1969-02-30T20:17:40Z",
+                DateCreationError::InvalidDay(30).into(),
+            ),
+            (
+                "1969-13-20T20:17:40Z",
+                DateCreationError::InvalidMonth(13).into(),
+            ),
+            ("1969-07-20T24:00:00Z", InstantParseError::InvalidTime),
+            ("1969-07-20T20:60:00Z", InstantParseError::InvalidTime),
+            ("1969-07-20T20:17:61Z", InstantParseError::InvalidTime),
+            ("1969-07-20T20:17:40+24:00", InstantParseError::InvalidTime),
+            ("1969-07-20T20:17:40-05:60", InstantParseError::InvalidTime),
+        ];
+        for (input, error) in errors {
+            assert_eq!(input.parse::<UtcInstant>(), Err(error), "{input}");
+        }
+    }
+
+    #[test]
+    fn local_dates() {
+        let houston = UtcOffset::from_minutes(-5 * 60).unwrap();
+        let tokyo = UtcOffset::from_minutes(9 * 60).unwrap();
+        // (instant, UTC date, Houston date, Tokyo date)
+        let cases = [
+            (
+                "1969-07-20T20:17:40Z",
+                date!(1969 - 07 - 20),
+                date!(1969 - 07 - 20),
+                date!(1969 - 07 - 21),
+            ),
+            (
+                "1969-07-21T02:56:15Z",
+                date!(1969 - 07 - 21),
+                date!(1969 - 07 - 20),
+                date!(1969 - 07 - 21),
+            ),
+            (
+                "1970-01-01T00:00:00Z",
+                date!(1970 - 01 - 01),
+                date!(1969 - 12 - 31),
+                date!(1970 - 01 - 01),
+            ),
+            (
+                "1969-12-31T20:00:00Z",
+                date!(1969 - 12 - 31),
+                date!(1969 - 12 - 31),
+                date!(1970 - 01 - 01),
+            ),
+        ];
+        for (input, utc, local, east) in cases {
+            let instant = input.parse::<UtcInstant>().unwrap();
+            assert_eq!(instant.date_at(UtcOffset::UTC), utc, "{input}");
+            assert_eq!(instant.utc_day(), StandardCalendar::from(&utc));
+            assert_eq!(instant.date_at(houston), local, "{input}");
+            assert_eq!(instant.date_at(tokyo), east, "{input}");
+        }
+
+        // Instants sort within their UTC day.
+        let day = StandardCalendar::from(&date!(1969 - 07 - 20));
+        let landing = "1969-07-20T20:17:40Z".parse::<UtcInstant>().unwrap();
+        let step = "1969-07-20T21:56:15-05:00".parse::<UtcInstant>().unwrap();
+        assert!(landing < step);
+        assert_eq!(landing.utc_day(), day);
+        assert_eq!(step.utc_day(), StandardCalendar::new(day.days + 1));
+        assert_eq!(step.seconds_into_day(houston), 21 * 3_600 + 56 * 60 + 15);
+
+        for text in ["+05:30", "-03:00", "+00:00", "+23:59", "-23:59"] {
+            let sign = if text.starts_with('-') { -1 } else { 1 };
+            let minutes = sign
+                * (text[1..3].parse::<i16>().unwrap() * 60 + text[4..].parse::<i16>().unwrap());
+            assert_eq!(UtcOffset::from_minutes(minutes).unwrap().to_string(), text);
+        }
+        assert_eq!(UtcOffset::from_minutes(-24 * 60), None);
+    }
+}
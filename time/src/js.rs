@@ -0,0 +1,172 @@
+//! Conversions between dates and JavaScript timestamps: milliseconds since the Unix epoch, as [`f64`]s.
+//!
+//! Timestamps are read as UTC, and dates are written as their midnight in UTC.
+
+use std::fmt;
+
+use crate::StandardCalendar;
+use crate::date::gregorian::Date;
+use crate::epoch::Epoch;
+
+/// How many milliseconds a day has.
+const MILLIS_PER_DAY: i128 = 86_400_000;
+/// January 1st of 1970, the Unix epoch, in [`StandardCalendar`] days.
+const UNIX_EPOCH: i128 = Epoch::Unix.day_zero().days;
+/// 2<sup>53</sup>: every integer up to it in magnitude is exactly representable as an [`f64`].
+const MAX_EXACT_MILLIS: i128 = 1 << 53;
+
+/// The error type for reading JavaScript timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsTimeError {
+    /// The timestamp is NaN or infinite, like the value of an invalid JavaScript `Date`.
+    NotFinite,
+    /// The timestamp is too far away to be represented.
+    OutOfRange,
+}
+
+impl fmt::Display for JsTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFinite => f.write_str("the timestamp is NaN or infinite"),
+            Self::OutOfRange => f.write_str("the timestamp is too far away to be represented"),
+        }
+    }
+}
+
+impl std::error::Error for JsTimeError {}
+
+impl StandardCalendar {
+    /// The day a JavaScript timestamp falls on, in UTC.
+    ///
+    /// Timestamps before the epoch are rounded down, so `-1.0` is December 31st of 1969.
+    ///
+    /// # Errors
+    /// * [`JsTimeError::NotFinite`] for NaN and infinities.
+    /// * [`JsTimeError::OutOfRange`] for timestamps whose days don't fit in an [`i128`].
+    pub fn from_js_millis(millis: f64) -> Result<Self, JsTimeError> {
+        if !millis.is_finite() {
+            return Err(JsTimeError::NotFinite);
+        }
+        let millis = millis.floor();
+        // `i128::MIN` is a power of two, so it converts exactly, while `i128::MAX` rounds up to the next one.
+        if !(i128::MIN as f64..i128::MAX as f64).contains(&millis) {
+            return Err(JsTimeError::OutOfRange);
+        }
+        // Whole values in this range convert exactly.
+        let days = (millis as i128).div_euclid(MILLIS_PER_DAY);
+        Ok(Self::new(UNIX_EPOCH + days))
+    }
+
+    /// The JavaScript timestamp of the start of this day, in UTC.
+    ///
+    /// Returns [`None`] if the timestamp is more than 2<sup>53</sup> milliseconds away from the epoch, past which
+    /// [`f64`]s can't represent every millisecond.
+    pub fn to_js_millis(&self) -> Option<f64> {
+        let millis = self
+            .days
+            .checked_sub(UNIX_EPOCH)?
+            .checked_mul(MILLIS_PER_DAY)?;
+        (millis.abs() <= MAX_EXACT_MILLIS).then_some(millis as f64)
+    }
+}
+
+impl Date {
+    /// The date a JavaScript timestamp falls on, in UTC.
+    ///
+    /// This is [`StandardCalendar::from_js_millis`], converted to a [`Date`].
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// assert_eq!(Date::from_js_millis(0.0), Ok(Date::from_parts(year!(1970), Month::January, 1).unwrap()));
+    /// assert_eq!(Date::from_js_millis(-1.0), Ok(Date::from_parts(year!(1969), Month::December, 31).unwrap()));
+    /// assert!(Date::from_js_millis(f64::NAN).is_err());
+    /// ```
+    pub fn from_js_millis(millis: f64) -> Result<Self, JsTimeError> {
+        StandardCalendar::from_js_millis(millis).map(Self::from)
+    }
+
+    /// The JavaScript timestamp of this date's midnight, in UTC.
+    ///
+    /// This is [`StandardCalendar::to_js_millis`] of this date.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::date::gregorian::{Date, Month, year};
+    ///
+    /// let date = Date::from_parts(year!(1969), Month::July, 20).unwrap();
+    /// assert_eq!(date.to_js_millis(), Some(-14_256_000_000.0));
+    /// ```
+    pub fn to_js_millis(&self) -> Option<f64> {
+        StandardCalendar::from(self).to_js_millis()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{Month, year};
+
+    #[test]
+    fn around_the_epoch() {
+        let date = |year, month, day| Date::from_parts(year, month, day).unwrap();
+        let new_year = date(year!(1970), Month::January, 1);
+        let new_years_eve = date(year!(1969), Month::December, 31);
+
+        let cases = [
+            (0.0, new_year),
+            (-0.0, new_year),
+            (0.5, new_year),
+            (86_399_999.0, new_year),
+            (86_400_000.0, date(year!(1970), Month::January, 2)),
+            (-0.5, new_years_eve),
+            (-1.0, new_years_eve),
+            (-86_400_000.0, new_years_eve),
+            (-86_400_001.0, date(year!(1969), Month::December, 30)),
+        ];
+        for (millis, date) in cases {
+            assert_eq!(Date::from_js_millis(millis), Ok(date), "{millis}");
+        }
+
+        assert_eq!(new_year.to_js_millis(), Some(0.0));
+        assert_eq!(new_years_eve.to_js_millis(), Some(-86_400_000.0));
+        // 1 CE, the standard calendar's day 0.
+        assert_eq!(
+            StandardCalendar::new(0).to_js_millis(),
+            Some(-62_135_596_800_000.0)
+        );
+    }
+
+    #[test]
+    fn exact_range_boundary() {
+        // The last whole days before 2^53 milliseconds, on each side of the epoch.
+        let last_days = 9_007_199_254_740_992 / 86_400_000;
+        let last = StandardCalendar::new(UNIX_EPOCH + last_days);
+        let first = StandardCalendar::new(UNIX_EPOCH - last_days);
+
+        assert_eq!(last.to_js_millis(), Some(9_007_199_222_400_000.0));
+        assert_eq!(first.to_js_millis(), Some(-9_007_199_222_400_000.0));
+        assert_eq!(last.succ().unwrap().to_js_millis(), None);
+        assert_eq!(first.pred().unwrap().to_js_millis(), None);
+
+        let max = 2.0_f64.powi(53);
+        assert_eq!(StandardCalendar::from_js_millis(max), Ok(last));
+        assert_eq!(
+            StandardCalendar::from_js_millis(-max),
+            Ok(first.pred().unwrap())
+        );
+        assert_eq!(StandardCalendar::MAX.to_js_millis(), None);
+    }
+
+    #[test]
+    fn invalid_timestamps() {
+        for millis in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(Date::from_js_millis(millis), Err(JsTimeError::NotFinite));
+        }
+        for millis in [f64::MAX, f64::MIN, 2.0_f64.powi(127)] {
+            assert_eq!(Date::from_js_millis(millis), Err(JsTimeError::OutOfRange));
+        }
+        assert!(StandardCalendar::from_js_millis(-(2.0_f64.powi(127))).is_ok());
+    }
+}
@@ -1,5 +1,20 @@
+pub mod approximate;
 pub mod calendar;
 pub mod date;
+pub mod epoch;
+pub mod error;
+pub mod format;
+pub mod holidays;
+pub mod instant;
+#[cfg(feature = "wasm")]
+pub mod js;
+pub mod locale;
+pub mod parse;
+pub mod selfcheck;
+pub mod snap;
+pub mod weekday;
 
 pub use calendar::Calendar;
 pub use calendar::StandardCalendar;
+pub use error::Error;
+pub use weekday::Weekday;
@@ -0,0 +1,269 @@
+//! Month and weekday names in different languages.
+
+/// The names used by a [`Locale`].
+///
+/// Build one of these to use names the built-in locales don't cover, through [`Locale::Custom`].
+///
+/// # Examples
+/// ```
+/// use time::{Weekday, locale::{Locale, LocaleNames}};
+///
+/// static ITALIAN: LocaleNames = LocaleNames {
+///     months: [
+///         "gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno",
+///         "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre",
+///     ],
+///     month_abbreviations: [
+///         "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+///     ],
+///     weekdays: ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"],
+///     weekday_abbreviations: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+/// };
+///
+/// assert_eq!(Weekday::Friday.name_in(Locale::Custom(&ITALIAN)), "venerdì");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocaleNames {
+    /// Month names, from January to December.
+    pub months: [&'static str; 12],
+    /// Abbreviated month names, from January to December.
+    pub month_abbreviations: [&'static str; 12],
+    /// Weekday names, from Monday to Sunday.
+    pub weekdays: [&'static str; 7],
+    /// Abbreviated weekday names, from Monday to Sunday.
+    pub weekday_abbreviations: [&'static str; 7],
+}
+
+/// A language to write month and weekday names in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// English.
+    #[default]
+    En,
+    /// Brazilian Portuguese.
+    PtBr,
+    /// Spanish.
+    Es,
+    /// French.
+    Fr,
+    /// German.
+    De,
+    /// User-provided names.
+    Custom(&'static LocaleNames),
+}
+
+impl Locale {
+    /// The names used by this locale.
+    pub fn names(self) -> &'static LocaleNames {
+        match self {
+            Self::En => &EN,
+            Self::PtBr => &PT_BR,
+            Self::Es => &ES,
+            Self::Fr => &FR,
+            Self::De => &DE,
+            Self::Custom(names) => names,
+        }
+    }
+}
+
+static EN: LocaleNames = LocaleNames {
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    month_abbreviations: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekday_abbreviations: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+};
+
+static PT_BR: LocaleNames = LocaleNames {
+    months: [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ],
+    month_abbreviations: [
+        "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+    ],
+    weekdays: [
+        "segunda-feira",
+        "terça-feira",
+        "quarta-feira",
+        "quinta-feira",
+        "sexta-feira",
+        "sábado",
+        "domingo",
+    ],
+    weekday_abbreviations: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+};
+
+static ES: LocaleNames = LocaleNames {
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    month_abbreviations: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    weekdays: [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+    weekday_abbreviations: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+};
+
+static FR: LocaleNames = LocaleNames {
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    month_abbreviations: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    weekdays: [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    weekday_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+};
+
+static DE: LocaleNames = LocaleNames {
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    month_abbreviations: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    weekdays: [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    weekday_abbreviations: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+};
+
+/// Finds which of `names` the start of `input` is, ignoring case, returning how many bytes it took and its index.
+///
+/// If several names match, the longest one is picked.
+pub(crate) fn match_name(input: &str, names: &[&str]) -> Option<(usize, usize)> {
+    names
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let mut input_chars = input.char_indices();
+            let mut len = 0;
+            for expected in name.chars() {
+                let (position, char) = input_chars.next()?;
+                if !char.to_lowercase().eq(expected.to_lowercase()) {
+                    return None;
+                }
+                len = position + char.len_utf8();
+            }
+            Some((len, index))
+        })
+        .max_by_key(|(len, _)| *len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_names() {
+        let names = Locale::De.names();
+        assert_eq!(match_name("MÄRZ 2024", &names.months), Some((5, 2)));
+        assert_eq!(match_name("Juli", &names.months), Some((4, 6)));
+        assert_eq!(match_name("Jul", &names.months), None);
+
+        // The longest match wins.
+        assert_eq!(match_name("sept.", &["sep", "sept."]), Some((5, 1)));
+    }
+
+    #[test]
+    fn tables_have_no_ambiguous_names() {
+        for locale in [Locale::En, Locale::PtBr, Locale::Es, Locale::Fr, Locale::De] {
+            let names = locale.names();
+            let lists: [&[&str]; 4] = [
+                &names.months,
+                &names.month_abbreviations,
+                &names.weekdays,
+                &names.weekday_abbreviations,
+            ];
+            for list in lists {
+                for (index, name) in list.iter().enumerate() {
+                    assert_eq!(match_name(name, list), Some((name.len(), index)), "{name}");
+                }
+            }
+        }
+    }
+}
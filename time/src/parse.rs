@@ -0,0 +1,1467 @@
+//! Parsing of dates written by people, such as "c. 44 BC" or "the 4th century BCE", or by other tools, such as
+//! Wikidata.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::Calendar;
+use crate::approximate::{ApproximateDate, Precision};
+use crate::date::gregorian::{Date, DateOffset, Month, Year, errors::DateCreationError};
+
+/// The error type for [`parse_fuzzy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzyParseError {
+    /// The start of the input doesn't look like any supported date.
+    NoMatch,
+    /// The input refers to the year 0, which doesn't exist.
+    YearZero,
+    /// The input refers to a year too far away to be represented.
+    OutOfRange,
+    /// The parts found don't make up a valid date, such as "31 February 2024".
+    InvalidDate(DateCreationError),
+}
+
+impl fmt::Display for FuzzyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoMatch => f.write_str("not a recognized date"),
+            Self::YearZero => f.write_str("there is no year 0"),
+            Self::OutOfRange => f.write_str("the date is too far away to be represented"),
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FuzzyParseError {}
+
+impl From<DateCreationError> for FuzzyParseError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
+
+/// The error type for [`parse_wikidata_time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WikidataParseError {
+    /// The input stops looking like a Wikidata time at byte `position`.
+    Malformed { position: usize },
+    /// The precision is not one of those a [`Precision`] can represent, from 6 (millennium) to 11 (day).
+    UnsupportedPrecision(u8),
+    /// The month or day don't make up a valid date for the given precision.
+    InvalidDate(DateCreationError),
+    /// The year is further away than the years of [`Date::MIN`] and [`Date::MAX`].
+    OutOfRange,
+}
+
+impl fmt::Display for WikidataParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { position } => write!(f, "not a Wikidata time from byte {position}"),
+            Self::UnsupportedPrecision(precision) => write!(
+                f,
+                "unsupported precision {precision}, only 6 (millennium) to 11 (day) are"
+            ),
+            Self::InvalidDate(error) => write!(f, "invalid date: {error}"),
+            Self::OutOfRange => f.write_str("the year is too far away to be represented"),
+        }
+    }
+}
+
+impl std::error::Error for WikidataParseError {}
+
+impl From<DateCreationError> for WikidataParseError {
+    fn from(error: DateCreationError) -> Self {
+        Self::InvalidDate(error)
+    }
+}
+
+/// The error type for [`parse_offset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetParseError {
+    /// The input has no parts at all.
+    Empty,
+    /// The input stops looking like an offset at byte `position`, such as on a unit other than `y`, `m`, `w` and
+    /// `d`.
+    Malformed { position: usize },
+    /// A part is too large to be represented.
+    OutOfRange,
+}
+
+impl fmt::Display for OffsetParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no offset"),
+            Self::Malformed { position } => write!(f, "not an offset from byte {position}"),
+            Self::OutOfRange => f.write_str("the offset is too large to be represented"),
+        }
+    }
+}
+
+impl std::error::Error for OffsetParseError {}
+
+/// A date found by [`parse_fuzzy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// The date that was read.
+    pub date: ApproximateDate,
+    /// The bytes of the input the date was read from. Anything outside of it was not understood.
+    pub span: Range<usize>,
+}
+
+/// Reads a date written the way people write them in notes, to whatever [`Precision`] it is given.
+///
+/// The supported shapes are:
+///
+/// | Input             | Precision                           |
+/// |-------------------|-------------------------------------|
+/// | `20 July 1969`    | [Day](Precision::Day)               |
+/// | `July 20th, 1969` | [Day](Precision::Day)               |
+/// | `March 1917`      | [Month](Precision::Month)           |
+/// | `1066`            | [Year](Precision::Year)             |
+/// | `1990s`           | [Decade](Precision::Decade)         |
+/// | `4th century`     | [Century](Precision::Century)       |
+/// | `2nd millennium`  | [Millennium](Precision::Millennium) |
+///
+/// Any of them may be preceded by "the", marked as [approximate](ApproximateDate::approximately) with "c.", "ca."
+/// or "circa", and given an era, either before (`AD 14`) or after (`44 BC`). Without an era, the common era is
+/// assumed. Words are matched ignoring case, and months may be abbreviated to their first 3
+/// letters or more.
+///
+/// Only the start of `input` has to be a date: the returned [`span`](FuzzyMatch::span) tells how much of it was
+/// read.
+///
+/// # Errors
+/// * [`FuzzyParseError::NoMatch`] if `input` doesn't start with a date.
+/// * [`FuzzyParseError::YearZero`] if the date would be in the year 0, such as "0 AD".
+/// * [`FuzzyParseError::OutOfRange`] if the date is too far away to be represented.
+/// * [`FuzzyParseError::InvalidDate`] if the day doesn't exist, such as "31 February 2024".
+///
+/// # Examples
+/// ```
+/// use time::{
+///     approximate::Precision,
+///     date::gregorian::{Date, Month, year},
+///     parse::parse_fuzzy,
+/// };
+///
+/// let found = parse_fuzzy("c. 44 BC, in Rome").unwrap();
+/// assert_eq!(found.date.start(), Date::from_year(year!(-44)));
+/// assert_eq!(found.date.precision(), Precision::Year);
+/// assert!(found.date.is_approximate());
+/// assert_eq!(found.span, 0..8);
+///
+/// let found = parse_fuzzy("4th century BCE").unwrap();
+/// assert_eq!(found.date.start(), Date::from_year(year!(-400)));
+/// assert_eq!(found.date.end(), Date::from_parts(year!(-301), Month::December, 31).unwrap());
+/// ```
+pub fn parse_fuzzy(input: &str) -> Result<FuzzyMatch, FuzzyParseError> {
+    let tokens = tokenize(input);
+    let mut rest = &tokens[..];
+    let start = rest.first().ok_or(FuzzyParseError::NoMatch)?.span.start;
+
+    let mut approximate = false;
+    while let Some(token) = rest.first() {
+        match token.kind {
+            Kind::Circa => approximate = true,
+            Kind::The => {}
+            _ => break,
+        }
+        rest = &rest[1..];
+    }
+    let mut era = None;
+    if let Some(Token {
+        kind: Kind::Era(prefix),
+        ..
+    }) = rest.first()
+    {
+        era = Some(*prefix);
+        rest = &rest[1..];
+    }
+
+    let (pattern, values) = PATTERNS
+        .iter()
+        .find_map(|pattern| Some((pattern, pattern.matches(rest)?)))
+        .ok_or(FuzzyParseError::NoMatch)?;
+    let mut end = rest[pattern.slots.len() - 1].span.end;
+    rest = &rest[pattern.slots.len()..];
+
+    if era.is_none()
+        && let Some(Token {
+            kind: Kind::Era(suffix),
+            span,
+        }) = rest.first()
+    {
+        era = Some(*suffix);
+        end = span.end;
+    }
+
+    let date = (pattern.build)(values, era.unwrap_or(Era::Common))?;
+    Ok(FuzzyMatch {
+        date: if approximate {
+            date.approximately()
+        } else {
+            date
+        },
+        span: start..end,
+    })
+}
+
+/// Words marking a date as approximate.
+const CIRCA: &[&str] = &["c.", "c", "ca.", "ca", "circa", "approx.", "approximately"];
+/// Words marking a date as being before the common era.
+const BEFORE_COMMON_ERA: &[&str] = &["bc", "bce", "b.c.", "b.c.e."];
+/// Words marking a date as being in the common era.
+const COMMON_ERA: &[&str] = &["ad", "ce", "a.d.", "c.e."];
+
+/// The shapes of dates [`parse_fuzzy`] understands, tried in order. The first one to match is used, so the longer
+/// shapes go first.
+const PATTERNS: &[Pattern] = &[
+    Pattern {
+        slots: &[Slot::Day, Slot::Month, Slot::Year],
+        build: |[day, month, year], era| exact(year, month, day, era),
+    },
+    Pattern {
+        slots: &[Slot::Month, Slot::Day, Slot::Year],
+        build: |[month, day, year], era| exact(year, month, day, era),
+    },
+    Pattern {
+        slots: &[Slot::Month, Slot::Year],
+        build: |[month, year, _], era| {
+            let date = Date::from_parts(year_in(year, era)?, month_from(month), 1)?;
+            Ok(ApproximateDate::new(date, Precision::Month))
+        },
+    },
+    Pattern {
+        slots: &[Slot::Ordinal, Slot::Century],
+        build: |[century, ..], era| nth_span(century, 100, era, Precision::Century),
+    },
+    Pattern {
+        slots: &[Slot::Ordinal, Slot::Millennium],
+        build: |[millennium, ..], era| nth_span(millennium, 1000, era, Precision::Millennium),
+    },
+    Pattern {
+        slots: &[Slot::Decade],
+        build: |[decade, ..], era| {
+            // The 1990s BC go from 1999 BC to 1990 BC.
+            let first = match era {
+                Era::Common => decade,
+                Era::BeforeCommon => decade.checked_add(9).ok_or(FuzzyParseError::OutOfRange)?,
+            };
+            Ok(ApproximateDate::new(
+                Date::from_year(year_in(first, era)?),
+                Precision::Decade,
+            ))
+        },
+    },
+    Pattern {
+        slots: &[Slot::Year],
+        build: |[year, ..], era| {
+            Ok(ApproximateDate::new(
+                Date::from_year(year_in(year, era)?),
+                Precision::Year,
+            ))
+        },
+    },
+];
+
+/// One of the [`PATTERNS`]: the tokens it is made of, and how to build a date from their values.
+struct Pattern {
+    slots: &'static [Slot],
+    build: fn([i128; 3], Era) -> Result<ApproximateDate, FuzzyParseError>,
+}
+
+impl Pattern {
+    /// The values of this pattern's slots, if `tokens` start with it.
+    fn matches(&self, tokens: &[Token]) -> Option<[i128; 3]> {
+        let mut values = [0; 3];
+        for (index, slot) in self.slots.iter().enumerate() {
+            values[index] = slot.value(&tokens.get(index)?.kind)?;
+        }
+        Some(values)
+    }
+}
+
+/// What a [`Pattern`] expects in each of its positions.
+#[derive(Debug, Clone, Copy)]
+enum Slot {
+    /// A plain number.
+    Year,
+    /// A plain or ordinal number that could be a day of the month.
+    Day,
+    Month,
+    /// An ordinal number, like "4th".
+    Ordinal,
+    /// A year followed by "s", like "1990s".
+    Decade,
+    /// The word "century".
+    Century,
+    /// The word "millennium".
+    Millennium,
+}
+
+impl Slot {
+    /// The value `kind` has in this slot, if it fits in it.
+    fn value(self, kind: &Kind) -> Option<i128> {
+        match (self, *kind) {
+            (Self::Year, Kind::Number(year)) => Some(year),
+            (Self::Day, Kind::Number(day) | Kind::Ordinal(day)) if (1..=31).contains(&day) => {
+                Some(day)
+            }
+            (Self::Month, Kind::Month(month)) => Some(i128::from(u8::from(month))),
+            (Self::Ordinal, Kind::Ordinal(n)) => Some(n),
+            (Self::Decade, Kind::Decade(decade)) => Some(decade),
+            (Self::Century, Kind::Century) | (Self::Millennium, Kind::Millennium) => Some(0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Era {
+    BeforeCommon,
+    Common,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Circa,
+    The,
+    Era(Era),
+    Century,
+    Millennium,
+    Month(Month),
+    Number(i128),
+    Ordinal(i128),
+    Decade(i128),
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: Kind,
+    span: Range<usize>,
+}
+
+/// Splits `input` into words, separated by whitespace or commas.
+fn tokenize(input: &str) -> Vec<Token> {
+    let is_separator = |char: char| char.is_whitespace() || char == ',';
+    let mut tokens = Vec::new();
+    let mut rest = input;
+    while let Some(start) = rest.find(|char| !is_separator(char)) {
+        let len = rest[start..]
+            .find(is_separator)
+            .unwrap_or(rest.len() - start);
+        let offset = input.len() - rest.len() + start;
+        tokens.push(Token {
+            kind: classify(&rest[start..start + len]),
+            span: offset..offset + len,
+        });
+        rest = &rest[start + len..];
+    }
+    tokens
+}
+
+fn classify(word: &str) -> Kind {
+    let lowercase = word.to_lowercase();
+    let is_any = |words: &[&str]| words.contains(&lowercase.as_str());
+
+    if is_any(CIRCA) {
+        return Kind::Circa;
+    }
+    if is_any(&["the"]) {
+        return Kind::The;
+    }
+    if is_any(BEFORE_COMMON_ERA) {
+        return Kind::Era(Era::BeforeCommon);
+    }
+    if is_any(COMMON_ERA) {
+        return Kind::Era(Era::Common);
+    }
+    if is_any(&["century"]) {
+        return Kind::Century;
+    }
+    if is_any(&["millennium"]) {
+        return Kind::Millennium;
+    }
+    // Months may be abbreviated to any of their first 3 letters or more, like "Sep" or "Sept.".
+    let name = lowercase.strip_suffix('.').unwrap_or(&lowercase);
+    if name.len() >= 3
+        && let Some(month) = Month::ALL
+            .into_iter()
+            .find(|month| month.name().to_lowercase().starts_with(name))
+    {
+        return Kind::Month(month);
+    }
+
+    let digits_end = word
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(word.len());
+    let Ok(number) = word[..digits_end].parse::<i128>() else {
+        return Kind::Unknown;
+    };
+    match &lowercase[digits_end..] {
+        "" => Kind::Number(number),
+        "st" | "nd" | "rd" | "th" if number > 0 => Kind::Ordinal(number),
+        "s" | "'s" if number % 10 == 0 => Kind::Decade(number),
+        _ => Kind::Unknown,
+    }
+}
+
+/// The [`Year`] numbered `year` in `era`, unless it is outside of [`Date::MIN`] and [`Date::MAX`].
+fn year_in(year: i128, era: Era) -> Result<Year, FuzzyParseError> {
+    let year = match era {
+        Era::Common => year,
+        // Numbers are only read from digits, so they are never negative.
+        Era::BeforeCommon => -year,
+    };
+    let year = Year::try_from(year).map_err(|_| FuzzyParseError::YearZero)?;
+    // Dates further away couldn't be converted, and their windows could overflow.
+    if !(Date::MIN.year()..=Date::MAX.year()).contains(&year) {
+        return Err(FuzzyParseError::OutOfRange);
+    }
+    Ok(year)
+}
+
+fn month_from(month: i128) -> Month {
+    // Month values only come from `Kind::Month`.
+    Month::try_from(month as u8).unwrap()
+}
+
+fn exact(year: i128, month: i128, day: i128, era: Era) -> Result<ApproximateDate, FuzzyParseError> {
+    // Day values are checked to be in 1..=31 by `Slot::Day`.
+    let date = Date::from_parts(year_in(year, era)?, month_from(month), day as u8)?;
+    Ok(ApproximateDate::from(date))
+}
+
+/// The `n`th span of `years` years in `era`, such as the 4th century BCE (400 BCE to 301 BCE).
+fn nth_span(
+    n: i128,
+    years: i128,
+    era: Era,
+    precision: Precision,
+) -> Result<ApproximateDate, FuzzyParseError> {
+    let last = n.checked_mul(years).ok_or(FuzzyParseError::OutOfRange)?;
+    let first = match era {
+        Era::Common => last - years + 1,
+        Era::BeforeCommon => last,
+    };
+    Ok(ApproximateDate::new(
+        Date::from_year(year_in(first, era)?),
+        precision,
+    ))
+}
+
+/// Reads a [Wikidata time](https://www.wikidata.org/wiki/Help:Dates) with its precision appended, such as
+/// `+1969-07-20T00:00:00Z/11`.
+///
+/// The year is [astronomical](Year::to_astronomical), so `-0043` is 44 BCE. The time of day is ignored, as are
+/// the month and day when the precision doesn't include them (Wikidata writes them as `00`). The supported
+/// precisions are:
+///
+/// | Precision | Meaning                                                                |
+/// |-----------|------------------------------------------------------------------------|
+/// | 11        | [Day](Precision::Day)                                                  |
+/// | 10        | [Month](Precision::Month)                                              |
+/// | 9         | [Year](Precision::Year)                                                |
+/// | 8         | [Decade](Precision::Decade), such as `+1995` for 1990 to 1999          |
+/// | 7         | [Century](Precision::Century), such as `+1950` for 1901 to 2000        |
+/// | 6         | [Millennium](Precision::Millennium), such as `+1950` for 1001 to 2000  |
+///
+/// Decades are aligned on astronomical years, like Wikidata does, while centuries and millennia
+/// [count from 1](Year::century).
+///
+/// # Errors
+/// * [`WikidataParseError::Malformed`] if `input` isn't shaped like a Wikidata time.
+/// * [`WikidataParseError::UnsupportedPrecision`] for precisions coarser than a millennium, or finer than a day.
+/// * [`WikidataParseError::InvalidDate`] if the month or day needed by the precision are invalid.
+/// * [`WikidataParseError::OutOfRange`] if the year is too far away to be represented.
+///
+/// # Examples
+/// ```
+/// use time::{
+///     approximate::Precision,
+///     date::gregorian::{Date, Month, year},
+///     parse::parse_wikidata_time,
+/// };
+///
+/// let date = parse_wikidata_time("+1969-07-20T00:00:00Z/11").unwrap();
+/// assert_eq!(date.start(), Date::from_parts(year!(1969), Month::July, 20).unwrap());
+/// assert_eq!(date.precision(), Precision::Day);
+///
+/// let date = parse_wikidata_time("-0043-00-00T00:00:00Z/9").unwrap();
+/// assert_eq!(date.start(), Date::from_year(year!(-44)));
+/// assert_eq!(date.precision(), Precision::Year);
+/// ```
+pub fn parse_wikidata_time(input: &str) -> Result<ApproximateDate, WikidataParseError> {
+    let malformed = |rest: &str| WikidataParseError::Malformed {
+        position: input.len() - rest.len(),
+    };
+    let number = |rest| leading_number(rest).ok_or_else(|| malformed(rest));
+
+    let (negative, rest) = match input.as_bytes().first() {
+        Some(b'+') => (false, &input[1..]),
+        Some(b'-') => (true, &input[1..]),
+        _ => return Err(malformed(input)),
+    };
+    let (year, rest) = number(rest)?;
+    let rest = rest.strip_prefix('-').ok_or_else(|| malformed(rest))?;
+    let (month, rest) = number(rest)?;
+    let rest = rest.strip_prefix('-').ok_or_else(|| malformed(rest))?;
+    let (day, rest) = number(rest)?;
+    let rest = match rest.strip_prefix('T') {
+        Some(time) => &time[time.find('/').unwrap_or(time.len())..],
+        None => rest,
+    };
+    let rest = rest.strip_prefix('/').ok_or_else(|| malformed(rest))?;
+    let (precision, rest) = number(rest)?;
+    if !rest.is_empty() {
+        return Err(malformed(rest));
+    }
+
+    let astronomical = if negative { -year } else { year };
+    // Rounding years further away to their decade or century could overflow.
+    if !(Date::MIN.year().to_astronomical()..=Date::MAX.year().to_astronomical())
+        .contains(&astronomical)
+    {
+        return Err(WikidataParseError::OutOfRange);
+    }
+    let year = Year::from_astronomical(astronomical);
+    // Out of range values are kept out of range, to be reported as invalid.
+    let month = || Month::try_from(u8::try_from(month).unwrap_or(u8::MAX));
+    let date = match precision {
+        11 => {
+            let day = day.try_into().unwrap_or(u8::MAX);
+            ApproximateDate::from(Date::from_parts(year, month()?, day)?)
+        }
+        10 => ApproximateDate::new(Date::from_parts(year, month()?, 1)?, Precision::Month),
+        9 => ApproximateDate::new(Date::from_year(year), Precision::Year),
+        8 => {
+            let start = Year::from_astronomical(astronomical.div_euclid(10) * 10);
+            ApproximateDate::new(Date::from_year(start), Precision::Decade)
+        }
+        7 => ApproximateDate::new(Date::from_year(year.century_start()), Precision::Century),
+        6 => ApproximateDate::new(
+            Date::from_year(year.millennium_start()),
+            Precision::Millennium,
+        ),
+        precision => {
+            return Err(WikidataParseError::UnsupportedPrecision(
+                precision.try_into().unwrap_or(u8::MAX),
+            ));
+        }
+    };
+    Ok(date)
+}
+
+/// Reads an offset to move dates by, written in short form like "+3y2m" or "-15d".
+///
+/// It is made of numbers with a unit, `y` for years, `m` for months, `w` for weeks and `d` for days, which can be
+/// separated by whitespace. A `+` or `-` sign applies to the parts after it, until the next sign. Weeks are counted as
+/// 7 days, and parts with the same unit add up.
+///
+/// # Errors
+/// * [`OffsetParseError::Empty`] if the input only has whitespace.
+/// * [`OffsetParseError::Malformed`] if a part is missing its number or its unit.
+/// * [`OffsetParseError::OutOfRange`] if a part doesn't fit in an [`i128`].
+///
+/// # Examples
+/// ```
+/// use time::{date::gregorian::{DateOffset, date}, parse::parse_offset};
+///
+/// assert_eq!(parse_offset("+3y 2m 10d"), Ok(DateOffset { years: 3, months: 2, days: 10 }));
+/// assert_eq!(parse_offset("-1y2m"), Ok(DateOffset { years: -1, months: -2, days: 0 }));
+///
+/// let offset = parse_offset("2w -1d").unwrap();
+/// assert_eq!(offset.days, 13);
+/// assert_eq!(date!(2024-01-01).apply_offset(&offset), date!(2024-01-14));
+/// ```
+pub fn parse_offset(input: &str) -> Result<DateOffset, OffsetParseError> {
+    let malformed = |rest: &str| OffsetParseError::Malformed {
+        position: input.len() - rest.len(),
+    };
+    let mut rest = input.trim_start();
+    if rest.is_empty() {
+        return Err(OffsetParseError::Empty);
+    }
+
+    let mut offset = DateOffset::default();
+    let mut negative = false;
+    while !rest.is_empty() {
+        if let Some(signed) = rest.strip_prefix(['+', '-']) {
+            negative = rest.starts_with('-');
+            rest = signed.trim_start();
+        }
+        let (number, after) = match leading_number(rest) {
+            Some(found) => found,
+            None if rest.starts_with(|char: char| char.is_ascii_digit()) => {
+                return Err(OffsetParseError::OutOfRange);
+            }
+            None => return Err(malformed(rest)),
+        };
+        let after = after.trim_start();
+        let (part, factor) = match after.chars().next().map(|unit| unit.to_ascii_lowercase()) {
+            Some('y') => (&mut offset.years, 1),
+            Some('m') => (&mut offset.months, 1),
+            Some('w') => (&mut offset.days, 7),
+            Some('d') => (&mut offset.days, 1),
+            _ => return Err(malformed(after)),
+        };
+        let value = number
+            .checked_mul(if negative { -factor } else { factor })
+            .ok_or(OffsetParseError::OutOfRange)?;
+        *part = part
+            .checked_add(value)
+            .ok_or(OffsetParseError::OutOfRange)?;
+        // The unit is a single ASCII letter.
+        rest = after[1..].trim_start();
+    }
+    Ok(offset)
+}
+
+/// Splits the digits at the start of `input`, which has to have at least one, from the rest.
+fn leading_number(input: &str) -> Option<(i128, &str)> {
+    let len = input
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(input.len());
+    Some((input[..len].parse().ok()?, &input[len..]))
+}
+
+/// One of the ways a [`ParserChain`] tries to read a date.
+pub enum DateParser {
+    /// An [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) calendar date, `YYYY-MM-DD`, such as `1969-07-20`.
+    ///
+    /// The year has at least 4 digits and may have a sign. Like in ISO 8601, it is
+    /// [astronomical](Year::to_astronomical), so `-0043-03-15` is March 15th of 44 BCE.
+    Iso,
+    /// A day, month and year, separated by `sep`, such as `20.07.1969` or `20/7/1969`.
+    Dmy { sep: char },
+    /// A month, day and year, separated by `sep`, such as `07/20/1969`.
+    Mdy { sep: char },
+    /// A year on its own, in any form [`Year`] parses, such as `1969` or `44 BC`.
+    YearOnly,
+    /// Anything [`parse_fuzzy`] reads, as long as it reads all of the input.
+    Fuzzy,
+    /// A parser of your own, with a name to tell it apart in reports.
+    Custom { name: String, parse: CustomParse },
+}
+
+/// The function of a [`DateParser::Custom`], giving the date it reads in its input, if any.
+pub type CustomParse = Box<dyn Fn(&str) -> Option<ApproximateDate>>;
+
+impl DateParser {
+    /// Shorthand for [`DateParser::Custom`].
+    pub fn custom(
+        name: impl Into<String>,
+        parse: impl Fn(&str) -> Option<ApproximateDate> + 'static,
+    ) -> Self {
+        Self::Custom {
+            name: name.into(),
+            parse: Box::new(parse),
+        }
+    }
+
+    /// A short name for this parser, such as "iso", or the name given to a custom one.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Iso => "iso",
+            Self::Dmy { .. } => "dmy",
+            Self::Mdy { .. } => "mdy",
+            Self::YearOnly => "year",
+            Self::Fuzzy => "fuzzy",
+            Self::Custom { name, .. } => name,
+        }
+    }
+
+    /// The date in `input`, if this parser reads all of it. Surrounding whitespace is ignored.
+    pub fn parse(&self, input: &str) -> Option<ApproximateDate> {
+        let input = input.trim();
+        match self {
+            Self::Iso => parse_iso(input),
+            Self::Dmy { sep } => {
+                let [day, month, year] = numeric_parts(input, *sep)?;
+                numeric_date(year, month, day)
+            }
+            Self::Mdy { sep } => {
+                let [month, day, year] = numeric_parts(input, *sep)?;
+                numeric_date(year, month, day)
+            }
+            Self::YearOnly => Some(ApproximateDate::new(
+                Date::from_year(input.parse().ok()?),
+                Precision::Year,
+            )),
+            Self::Fuzzy => parse_fuzzy(input)
+                .ok()
+                .filter(|found| found.span == (0..input.len()))
+                .map(|found| found.date),
+            Self::Custom { parse, .. } => parse(input),
+        }
+    }
+}
+
+impl fmt::Debug for DateParser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dmy { sep } | Self::Mdy { sep } => {
+                f.debug_struct(self.name()).field("sep", sep).finish()
+            }
+            Self::Custom { name, .. } => f.debug_struct("Custom").field("name", name).finish(),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+/// Reads a `YYYY-MM-DD` date, for [`DateParser::Iso`].
+fn parse_iso(input: &str) -> Option<ApproximateDate> {
+    let (negative, unsigned) = match input.as_bytes().first()? {
+        b'-' => (true, &input[1..]),
+        b'+' => (false, &input[1..]),
+        _ => (false, input),
+    };
+    let lengths: Vec<_> = unsigned.split('-').map(str::len).collect();
+    if !matches!(lengths[..], [year, 2, 2] if year >= 4) {
+        return None;
+    }
+    let [year, month, day] = numeric_parts(unsigned, '-')?;
+    date_of(
+        Year::from_astronomical(if negative { -year } else { year }),
+        month,
+        day,
+    )
+}
+
+/// The three numbers of `input` separated by `sep`, for [`DateParser::Dmy`] and [`DateParser::Mdy`].
+fn numeric_parts(input: &str, sep: char) -> Option<[i128; 3]> {
+    let mut parts = input.split(sep).map(|part| {
+        (!part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()))
+            .then(|| part.parse().ok())
+            .flatten()
+    });
+    let numbers = [parts.next()??, parts.next()??, parts.next()??];
+    parts.next().is_none().then_some(numbers)
+}
+
+/// The date of the numbers read by [`numeric_parts`], in the common era.
+fn numeric_date(year: i128, month: i128, day: i128) -> Option<ApproximateDate> {
+    date_of(Year::try_from(year).ok()?, month, day)
+}
+
+/// The date of `month` and `day` in `year`, if they are valid and within [`Date::MIN`] and [`Date::MAX`].
+fn date_of(year: Year, month: i128, day: i128) -> Option<ApproximateDate> {
+    if !(Date::MIN.year()..=Date::MAX.year()).contains(&year) {
+        return None;
+    }
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    let date = Date::from_parts(year, month, u8::try_from(day).ok()?);
+    date.ok().map(ApproximateDate::from)
+}
+
+/// A date read by a [`ParserChain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainMatch {
+    /// The date that was read.
+    pub date: ApproximateDate,
+    /// Which of the chain's [`parsers`](ParserChain::parsers) read it.
+    pub parser: usize,
+}
+
+/// An ordered list of [`DateParser`]s, for reading dates that may be written in several formats.
+///
+/// Each parser is tried in order, and the first one that reads the input wins. Inputs that more than one parser
+/// can read, like `01/02/2000`, are read by whichever comes first, so the order decides how they are understood.
+///
+/// # Examples
+/// ```
+/// use time::{date::gregorian::date, parse::{DateParser, ParserChain}};
+///
+/// let chain = ParserChain::new()
+///     .with(DateParser::Iso)
+///     .with(DateParser::Dmy { sep: '.' })
+///     .with(DateParser::Fuzzy);
+///
+/// let found = chain.parse("20.07.1969").unwrap();
+/// assert_eq!(found.date.start(), date!(1969-07-20));
+/// assert_eq!(chain.parsers()[found.parser].name(), "dmy");
+///
+/// assert_eq!(chain.parse("c. 44 BC").unwrap().parser, 2);
+/// assert_eq!(chain.parse("someday"), None);
+/// ```
+#[derive(Debug, Default)]
+pub struct ParserChain {
+    parsers: Vec<DateParser>,
+}
+
+impl ParserChain {
+    /// Creates an empty chain, which reads nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `parser` to the end of this chain.
+    pub fn with(mut self, parser: DateParser) -> Self {
+        self.parsers.push(parser);
+        self
+    }
+
+    /// The parsers in this chain, in the order they are tried.
+    pub fn parsers(&self) -> &[DateParser] {
+        &self.parsers
+    }
+
+    /// The date in `input`, as read by the first parser that can read it.
+    pub fn parse(&self, input: &str) -> Option<ChainMatch> {
+        self.parsers
+            .iter()
+            .enumerate()
+            .find_map(|(parser, date_parser)| {
+                Some(ChainMatch {
+                    date: date_parser.parse(input)?,
+                    parser,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::{partial::PartialDate, year};
+    use crate::date::offset::{BuddhistDate, HoloceneDate, MinguoDate};
+    use crate::date::shifted::AprilFiscalDate;
+
+    #[test]
+    fn recognized_shapes() {
+        let date = |year, month, day| Date::from_parts(year, month, day).unwrap();
+        let cases = [
+            (
+                "1066",
+                Precision::Year,
+                false,
+                Date::from_year(year!(1066)),
+                0..4,
+            ),
+            (
+                "44 BC",
+                Precision::Year,
+                false,
+                Date::from_year(year!(-44)),
+                0..5,
+            ),
+            (
+                "c. 44 BC",
+                Precision::Year,
+                true,
+                Date::from_year(year!(-44)),
+                0..8,
+            ),
+            (
+                "ca. 1200 CE",
+                Precision::Year,
+                true,
+                Date::from_year(year!(1200)),
+                0..11,
+            ),
+            (
+                "circa 500 b.c.e.",
+                Precision::Year,
+                true,
+                Date::from_year(year!(-500)),
+                0..16,
+            ),
+            (
+                "AD 14",
+                Precision::Year,
+                false,
+                Date::from_year(year!(14)),
+                0..5,
+            ),
+            (
+                "  1 BCE",
+                Precision::Year,
+                false,
+                Date::from_year(year!(-1)),
+                2..7,
+            ),
+            (
+                "March 1917",
+                Precision::Month,
+                false,
+                date(year!(1917), Month::March, 1),
+                0..10,
+            ),
+            (
+                "sept. 1939",
+                Precision::Month,
+                false,
+                date(year!(1939), Month::September, 1),
+                0..10,
+            ),
+            (
+                "Mar 44 BC",
+                Precision::Month,
+                false,
+                date(year!(-44), Month::March, 1),
+                0..9,
+            ),
+            (
+                "20 July 1969",
+                Precision::Day,
+                false,
+                date(year!(1969), Month::July, 20),
+                0..12,
+            ),
+            (
+                "July 4th, 1776",
+                Precision::Day,
+                false,
+                date(year!(1776), Month::July, 4),
+                0..14,
+            ),
+            (
+                "15 Mar 44 BCE",
+                Precision::Day,
+                false,
+                date(year!(-44), Month::March, 15),
+                0..13,
+            ),
+            (
+                "1990s",
+                Precision::Decade,
+                false,
+                date(year!(1990), Month::January, 1),
+                0..5,
+            ),
+            (
+                "the 1860's",
+                Precision::Decade,
+                false,
+                date(year!(1860), Month::January, 1),
+                0..10,
+            ),
+            (
+                "1990s BC",
+                Precision::Decade,
+                false,
+                date(year!(-1999), Month::January, 1),
+                0..8,
+            ),
+            (
+                "4th century BCE",
+                Precision::Century,
+                false,
+                date(year!(-400), Month::January, 1),
+                0..15,
+            ),
+            (
+                "the 1st century",
+                Precision::Century,
+                false,
+                date(year!(1), Month::January, 1),
+                0..15,
+            ),
+            (
+                "c. 21st Century AD",
+                Precision::Century,
+                true,
+                date(year!(2001), Month::January, 1),
+                0..18,
+            ),
+            (
+                "2nd millennium",
+                Precision::Millennium,
+                false,
+                date(year!(1001), Month::January, 1),
+                0..14,
+            ),
+            (
+                "1st millennium BC",
+                Precision::Millennium,
+                false,
+                date(year!(-1000), Month::January, 1),
+                0..17,
+            ),
+        ];
+
+        for (input, precision, approximate, start, span) in cases {
+            let found = parse_fuzzy(input).unwrap_or_else(|error| panic!("{input}: {error:?}"));
+            assert_eq!(found.date.precision(), precision, "{input}");
+            assert_eq!(found.date.is_approximate(), approximate, "{input}");
+            assert_eq!(found.date.start(), start, "{input}");
+            assert_eq!(found.span, span, "{input}");
+        }
+    }
+
+    #[test]
+    fn partial_input() {
+        let cases = [
+            ("1066 and all that", 0..4),
+            ("March 1917, Petrograd", 0..10),
+            ("March 1917 or so", 0..10),
+            // Without a year, the day is taken as one.
+            ("4 March", 0..1),
+            // The era is only read once.
+            ("AD 14 BC", 0..5),
+        ];
+
+        for (input, span) in cases {
+            assert_eq!(parse_fuzzy(input).unwrap().span, span, "{input}");
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            ("", FuzzyParseError::NoMatch),
+            ("sometime", FuzzyParseError::NoMatch),
+            ("c.", FuzzyParseError::NoMatch),
+            ("century", FuzzyParseError::NoMatch),
+            ("1995s", FuzzyParseError::NoMatch),
+            ("0th century", FuzzyParseError::NoMatch),
+            ("0 AD", FuzzyParseError::YearZero),
+            ("0s", FuzzyParseError::YearZero),
+            (
+                "170141183460469231731687303715884105727th century",
+                FuzzyParseError::OutOfRange,
+            ),
+            (
+                "170141183460469231731687303715884105727",
+                FuzzyParseError::OutOfRange,
+            ),
+            (
+                "100000000000000000000000000000000001 BC",
+                FuzzyParseError::OutOfRange,
+            ),
+            (
+                "Dec 100000000000000000000000000000000001",
+                FuzzyParseError::OutOfRange,
+            ),
+            (
+                "1 Dec 100000000000000000000000000000000001",
+                FuzzyParseError::OutOfRange,
+            ),
+            (
+                "100000000000000000000000000000001st millennium",
+                FuzzyParseError::OutOfRange,
+            ),
+            (
+                "31 February 2024",
+                FuzzyParseError::InvalidDate(DateCreationError::InvalidDay(31)),
+            ),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(parse_fuzzy(input), Err(error), "{input}");
+        }
+
+        // The furthest years are still read, and their windows don't overflow.
+        for input in [
+            "100000000000000000000000000000000000",
+            "100000000000000000000000000000000000 BC",
+            "100000000000000000000000000000000th millennium",
+        ] {
+            let date = parse_fuzzy(input).unwrap().date;
+            assert!(date.start() <= date.end(), "{input}");
+        }
+    }
+
+    #[test]
+    fn extreme_inputs() {
+        let parsers = [
+            DateParser::Iso,
+            DateParser::Dmy { sep: '.' },
+            DateParser::Mdy { sep: '/' },
+            DateParser::YearOnly,
+            DateParser::Fuzzy,
+        ];
+        let cases = [
+            (
+                "170141183460469231731687303715884105720s BC",
+                Err(FuzzyParseError::OutOfRange),
+            ),
+            (
+                "170141183460469231731687303715884105720s",
+                Err(FuzzyParseError::OutOfRange),
+            ),
+            (
+                "-170141183460469231731687303715884105727",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            (
+                "170141183460469231731687303715884105727",
+                Err(FuzzyParseError::OutOfRange),
+            ),
+            (
+                "170141183460469231731687303715884105728",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            (
+                "Dec 170141183460469231731687303715884105727",
+                Err(FuzzyParseError::OutOfRange),
+            ),
+            (
+                "31 Dec 170141183460469231731687303715884105727 BC",
+                Err(FuzzyParseError::OutOfRange),
+            ),
+            (
+                "-170141183460469231731687303715884105727-01-01",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            (
+                "+170141183460469231731687303715884105727-12-31",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            (
+                "31.12.170141183460469231731687303715884105727",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            (
+                "12/31/170141183460469231731687303715884105727",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            (
+                "99999999999999999999999999999999999999999",
+                Err(FuzzyParseError::NoMatch),
+            ),
+            ("31 Dec 0", Err(FuzzyParseError::YearZero)),
+        ];
+
+        for (input, fuzzy) in cases {
+            assert_eq!(parse_fuzzy(input).map(|found| found.date), fuzzy, "{input}");
+            for parser in &parsers {
+                assert_eq!(parser.parse(input), None, "{input} with {parser:?}");
+            }
+            assert!(input.parse::<Year>().is_err(), "{input}");
+            assert!(input.parse::<PartialDate>().is_err(), "{input}");
+        }
+
+        let years = [
+            "100000000000000000000000000000000000000",
+            "-100000000000000000000000000000000000000",
+            "170141183460469231731687303715884105727",
+            "-170141183460469231731687303715884105727",
+            "170141183460469231731687303715884105728",
+        ];
+        for year in years {
+            let input = format!("31 December BE {year}");
+            assert!(input.parse::<BuddhistDate>().is_err(), "{input}");
+            let input = format!("31 December ROC {year}");
+            assert!(input.parse::<MinguoDate>().is_err(), "{input}");
+            let input = format!("31 December HE {year}");
+            assert!(input.parse::<HoloceneDate>().is_err(), "{input}");
+            let input = format!("31 December FY{year}");
+            assert!(input.parse::<AprilFiscalDate>().is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn wikidata_precisions() {
+        let date = |year, month, day| Date::from_parts(year, month, day).unwrap();
+        let cases = [
+            (
+                "+1969-07-20T00:00:00Z/11",
+                Precision::Day,
+                date(year!(1969), Month::July, 20),
+            ),
+            (
+                "+1917-03-00T00:00:00Z/10",
+                Precision::Month,
+                date(year!(1917), Month::March, 1),
+            ),
+            (
+                "+1066-00-00T00:00:00Z/9",
+                Precision::Year,
+                Date::from_year(year!(1066)),
+            ),
+            (
+                "+1995-00-00T00:00:00Z/8",
+                Precision::Decade,
+                Date::from_year(year!(1990)),
+            ),
+            (
+                "+1950-00-00T00:00:00Z/7",
+                Precision::Century,
+                Date::from_year(year!(1901)),
+            ),
+            (
+                "+2000-00-00T00:00:00Z/7",
+                Precision::Century,
+                Date::from_year(year!(1901)),
+            ),
+            (
+                "+1200-00-00T00:00:00Z/6",
+                Precision::Millennium,
+                Date::from_year(year!(1001)),
+            ),
+            // The time of day is ignored, and may be left out.
+            (
+                "+1969-07-20T20:17:40Z/11",
+                Precision::Day,
+                date(year!(1969), Month::July, 20),
+            ),
+            (
+                "+1969-07-20/11",
+                Precision::Day,
+                date(year!(1969), Month::July, 20),
+            ),
+        ];
+
+        for (input, precision, start) in cases {
+            let parsed =
+                parse_wikidata_time(input).unwrap_or_else(|error| panic!("{input}: {error:?}"));
+            assert_eq!(parsed.precision(), precision, "{input}");
+            assert_eq!(parsed.start(), start, "{input}");
+        }
+    }
+
+    #[test]
+    fn wikidata_before_common_era() {
+        let date = |year, month, day| Date::from_parts(year, month, day).unwrap();
+        let cases = [
+            (
+                "-0043-03-15T00:00:00Z/11",
+                date(year!(-44), Month::March, 15),
+            ),
+            ("+0000-00-00T00:00:00Z/9", Date::from_year(year!(-1))),
+            ("-0043-00-00T00:00:00Z/9", Date::from_year(year!(-44))),
+            // Astronomical -50 to -41 are 51 BCE to 42 BCE.
+            ("-0043-00-00T00:00:00Z/8", Date::from_year(year!(-51))),
+            ("-0043-00-00T00:00:00Z/7", Date::from_year(year!(-100))),
+            ("-0499-00-00T00:00:00Z/7", Date::from_year(year!(-500))),
+            ("-2500-00-00T00:00:00Z/6", Date::from_year(year!(-3000))),
+        ];
+
+        for (input, start) in cases {
+            assert_eq!(
+                parse_wikidata_time(input).unwrap().start(),
+                start,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn wikidata_errors() {
+        let cases = [
+            (
+                "1969-07-20T00:00:00Z/11",
+                WikidataParseError::Malformed { position: 0 },
+            ),
+            (
+                "+1969-07T00:00:00Z/11",
+                WikidataParseError::Malformed { position: 8 },
+            ),
+            (
+                "+1969-07-20T00:00:00Z",
+                WikidataParseError::Malformed { position: 21 },
+            ),
+            (
+                "+1969-07-20T00:00:00Z/11x",
+                WikidataParseError::Malformed { position: 24 },
+            ),
+            (
+                "+13798000000-00-00T00:00:00Z/3",
+                WikidataParseError::UnsupportedPrecision(3),
+            ),
+            (
+                "+1969-07-20T00:00:00Z/12",
+                WikidataParseError::UnsupportedPrecision(12),
+            ),
+            (
+                "+1969-00-00T00:00:00Z/10",
+                WikidataParseError::InvalidDate(DateCreationError::InvalidMonth(0)),
+            ),
+            (
+                "+1969-02-30T00:00:00Z/11",
+                WikidataParseError::InvalidDate(DateCreationError::InvalidDay(30)),
+            ),
+            (
+                "-170141183460469231731687303715884105727-00-00T00:00:00Z/8",
+                WikidataParseError::OutOfRange,
+            ),
+            (
+                "-170141183460469231731687303715884105727-00-00T00:00:00Z/7",
+                WikidataParseError::OutOfRange,
+            ),
+            (
+                "+100000000000000000000000000000000000001-00-00T00:00:00Z/9",
+                WikidataParseError::OutOfRange,
+            ),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(parse_wikidata_time(input), Err(error), "{input}");
+        }
+    }
+
+    #[test]
+    fn parser_formats() {
+        let date =
+            |year, month, day| ApproximateDate::from(Date::from_parts(year, month, day).unwrap());
+        let cases = [
+            (
+                DateParser::Iso,
+                "1969-07-20",
+                Some(date(year!(1969), Month::July, 20)),
+            ),
+            (
+                DateParser::Iso,
+                "-0043-03-15",
+                Some(date(year!(-44), Month::March, 15)),
+            ),
+            (
+                DateParser::Iso,
+                "+0000-01-01",
+                Some(date(year!(-1), Month::January, 1)),
+            ),
+            (DateParser::Iso, "969-07-20", None),
+            (DateParser::Iso, "1969-7-20", None),
+            (DateParser::Iso, "1969-02-30", None),
+            (DateParser::Iso, "1969-07-20-01", None),
+            (
+                DateParser::Dmy { sep: '.' },
+                " 20.7.1969 ",
+                Some(date(year!(1969), Month::July, 20)),
+            ),
+            (DateParser::Dmy { sep: '.' }, "20/07/1969", None),
+            (DateParser::Dmy { sep: '.' }, "20.07.0", None),
+            (
+                DateParser::Mdy { sep: '/' },
+                "07/20/1969",
+                Some(date(year!(1969), Month::July, 20)),
+            ),
+            (DateParser::Mdy { sep: '/' }, "20/07/1969", None),
+            (DateParser::Mdy { sep: '/' }, "07/+20/1969", None),
+            (
+                DateParser::YearOnly,
+                "44 BC",
+                Some(ApproximateDate::new(
+                    Date::from_year(year!(-44)),
+                    Precision::Year,
+                )),
+            ),
+            (DateParser::YearOnly, "1969-07-20", None),
+            (
+                DateParser::Fuzzy,
+                "c. 44 BC",
+                Some(
+                    ApproximateDate::new(Date::from_year(year!(-44)), Precision::Year)
+                        .approximately(),
+                ),
+            ),
+            // Only part of it is a date.
+            (DateParser::Fuzzy, "44 BC, in Rome", None),
+        ];
+        for (parser, input, expected) in cases {
+            assert_eq!(parser.parse(input), expected, "{parser:?} {input}");
+        }
+    }
+
+    #[test]
+    fn chain_order() {
+        let day_first = ParserChain::new()
+            .with(DateParser::Dmy { sep: '/' })
+            .with(DateParser::Mdy { sep: '/' });
+        let month_first = ParserChain::new()
+            .with(DateParser::Mdy { sep: '/' })
+            .with(DateParser::Dmy { sep: '/' });
+
+        let found = day_first.parse("01/02/2000").unwrap();
+        assert_eq!(
+            found.date.start(),
+            Date::from_parts(year!(2000), Month::February, 1).unwrap()
+        );
+        assert_eq!(found.parser, 0);
+        let found = month_first.parse("01/02/2000").unwrap();
+        assert_eq!(
+            found.date.start(),
+            Date::from_parts(year!(2000), Month::January, 2).unwrap()
+        );
+        assert_eq!(found.parser, 0);
+
+        // Only one of them can read it, wherever it is in the chain.
+        let found = day_first.parse("12/31/2000").unwrap();
+        assert_eq!(
+            found.date.start(),
+            Date::from_parts(year!(2000), Month::December, 31).unwrap()
+        );
+        assert_eq!(day_first.parsers()[found.parser].name(), "mdy");
+        assert_eq!(month_first.parse("12/31/2000").unwrap().parser, 0);
+        assert_eq!(ParserChain::new().parse("2000"), None);
+    }
+
+    #[test]
+    fn custom_parsers() {
+        // Unix timestamps, in seconds.
+        let timestamp = DateParser::custom("unix", |input| {
+            let seconds: i128 = input.strip_prefix('@')?.parse().ok()?;
+            let days = crate::epoch::Epoch::Unix.day_zero().days + seconds.div_euclid(86_400);
+            Some(ApproximateDate::from(Date::from(
+                crate::StandardCalendar::new(days),
+            )))
+        });
+        let chain = ParserChain::new().with(DateParser::Iso).with(timestamp);
+
+        let found = chain.parse("@86400").unwrap();
+        assert_eq!(
+            found.date.start(),
+            Date::from_parts(year!(1970), Month::January, 2).unwrap()
+        );
+        assert_eq!(chain.parsers()[found.parser].name(), "unix");
+        assert_eq!(
+            format!("{:?}", chain.parsers()[1]),
+            r#"Custom { name: "unix" }"#
+        );
+        assert_eq!(chain.parse("@soon"), None);
+    }
+
+    #[test]
+    fn offsets() {
+        let offset = |years, months, days| DateOffset {
+            years,
+            months,
+            days,
+        };
+        let cases = [
+            ("+3y2m10d", offset(3, 2, 10), "+3y2m10d"),
+            (" 3y 2m 10d ", offset(3, 2, 10), "+3y2m10d"),
+            ("-15d", offset(0, 0, -15), "-15d"),
+            ("- 1 Y 2 M", offset(-1, -2, 0), "-1y2m"),
+            ("1y -2m +3d", offset(1, -2, 3), "+1y-2m+3d"),
+            ("2w3d1w", offset(0, 0, 24), "+24d"),
+            ("1m -1m", offset(0, 0, 0), "+0d"),
+            ("0y", offset(0, 0, 0), "+0d"),
+        ];
+        for (input, expected, canonical) in cases {
+            assert_eq!(parse_offset(input), Ok(expected), "{input}");
+            assert_eq!(expected.to_string(), canonical);
+            assert_eq!(parse_offset(canonical), Ok(expected));
+        }
+
+        let errors = [
+            ("", OffsetParseError::Empty),
+            ("   ", OffsetParseError::Empty),
+            ("3", OffsetParseError::Malformed { position: 1 }),
+            ("3y 2", OffsetParseError::Malformed { position: 4 }),
+            ("3x", OffsetParseError::Malformed { position: 1 }),
+            ("3yd", OffsetParseError::Malformed { position: 2 }),
+            ("y", OffsetParseError::Malformed { position: 0 }),
+            ("+", OffsetParseError::Malformed { position: 1 }),
+            ("--3d", OffsetParseError::Malformed { position: 1 }),
+            ("3 years", OffsetParseError::Malformed { position: 3 }),
+            (
+                "170141183460469231731687303715884105728d",
+                OffsetParseError::OutOfRange,
+            ),
+            (
+                "24305883351495604533098186245126300819w",
+                OffsetParseError::OutOfRange,
+            ),
+            (
+                "170141183460469231731687303715884105727d 1d",
+                OffsetParseError::OutOfRange,
+            ),
+        ];
+        for (input, error) in errors {
+            assert_eq!(parse_offset(input), Err(error), "{input}");
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Run-time checks that a [`Calendar`] implementation agrees with itself.
+//!
+//! These are meant to run at startup in debug builds, to catch a broken calendar before it corrupts any data. For
+//! the checks built into the crate's own conversions, see the `validate` feature.
+
+use crate::StandardCalendar;
+use crate::calendar::Calendar;
+
+/// How far from _day 0_, in either direction, [`verify_roundtrip`] picks its samples.
+const SAMPLE_SPAN: u64 = 1_000_000;
+
+/// A day where a [`Calendar`] disagrees with itself, found by [`verify_roundtrip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The day that was checked.
+    pub standard: StandardCalendar,
+    /// Which check failed.
+    pub check: Check,
+    /// What the calendar gave instead of [`standard`](Mismatch::standard) (or the day after it, for
+    /// [`Check::AddDays`]).
+    pub found: StandardCalendar,
+}
+
+/// One of the checks done by [`verify_roundtrip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    /// Converting a day to the calendar and back gives the same day.
+    Roundtrip,
+    /// [`Calendar::as_days`] counts from where [`Calendar::reference_date`] is.
+    AsDays,
+    /// Adding a day with [`Calendar::add_days`] gives the following day.
+    AddDays,
+}
+
+/// Checks that `C` converts `samples` days, spread around _day 0_, back to the same days, and that its
+/// [`as_days`](Calendar::as_days) and [`add_days`](Calendar::add_days) agree with those conversions.
+///
+/// The samples always start with the days right around _day 0_, and are otherwise picked deterministically, so
+/// that a failure can be reproduced.
+///
+/// # Errors
+/// The first [`Mismatch`] found.
+///
+/// # Examples
+/// ```
+/// use time::{date::gregorian::Date, selfcheck::verify_roundtrip};
+///
+/// assert_eq!(verify_roundtrip::<Date>(1000), Ok(()));
+/// ```
+pub fn verify_roundtrip<C: Calendar>(samples: usize) -> Result<(), Mismatch> {
+    let reference = C::reference_date().to_standard();
+    let mismatch = |standard, check, found| {
+        Err(Mismatch {
+            standard,
+            check,
+            found,
+        })
+    };
+
+    for standard in sample_days().take(samples).map(StandardCalendar::new) {
+        let mut date = C::from_standard(standard);
+        let found = date.to_standard();
+        if found != standard {
+            return mismatch(standard, Check::Roundtrip, found);
+        }
+
+        let found = StandardCalendar::new(reference.days + date.as_days());
+        if found != standard {
+            return mismatch(standard, Check::AsDays, found);
+        }
+
+        date.add_days(1);
+        let found = date.to_standard();
+        if found.days != standard.days + 1 {
+            return mismatch(standard, Check::AddDays, found);
+        }
+    }
+    Ok(())
+}
+
+/// The days around _day 0_, followed by pseudo-random ones within [`SAMPLE_SPAN`] of it.
+fn sample_days() -> impl Iterator<Item = i128> {
+    let mut random = SplitMix64::default();
+    let random = std::iter::repeat_with(move || {
+        i128::from(random.below(2 * SAMPLE_SPAN + 1)) - i128::from(SAMPLE_SPAN)
+    });
+    (-400..=400).chain(random)
+}
+
+/// The SplitMix64 generator, which is enough to spread samples deterministically, without needing a dependency.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// The next number below `bound`, which must not be 0.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        (z ^ (z >> 31)) % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::Date;
+
+    /// A calendar that's the standard calendar, but with a broken `add_days`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Skipping(i128);
+
+    impl From<StandardCalendar> for Skipping {
+        fn from(standard: StandardCalendar) -> Self {
+            Self(standard.days)
+        }
+    }
+
+    impl From<&Skipping> for StandardCalendar {
+        fn from(date: &Skipping) -> Self {
+            StandardCalendar::new(date.0)
+        }
+    }
+
+    impl Calendar for Skipping {
+        type Month = u8;
+        type Day = u8;
+        type Year = i128;
+
+        fn day(&self) -> Self::Day {
+            1
+        }
+        fn month(&self) -> Self::Month {
+            1
+        }
+        fn year(&self) -> Self::Year {
+            self.0
+        }
+        fn reference_date() -> Self {
+            Self(0)
+        }
+        fn add_days(&mut self, days: i128) {
+            // Skips a day every time day 100 is crossed.
+            let skip = i128::from(self.0 < 100 && self.0 + days >= 100);
+            self.0 += days + skip;
+        }
+        fn as_days(&self) -> i128 {
+            self.0
+        }
+        fn days_between(first: &Self, second: &Self) -> i128 {
+            (second.0 - first.0).abs()
+        }
+        fn is_leap_year(_: Self::Year) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn gregorian_agrees_with_itself() {
+        assert_eq!(verify_roundtrip::<Date>(10_000), Ok(()));
+    }
+
+    #[test]
+    fn finds_mismatches() {
+        assert_eq!(
+            verify_roundtrip::<Skipping>(10_000),
+            Err(Mismatch {
+                standard: StandardCalendar::new(99),
+                check: Check::AddDays,
+                found: StandardCalendar::new(101),
+            })
+        );
+        // The broken day is never sampled.
+        assert_eq!(verify_roundtrip::<Skipping>(10), Ok(()));
+    }
+
+    #[test]
+    fn samples_are_spread() {
+        let samples: Vec<_> = sample_days().take(10_000).collect();
+        assert_eq!(samples[..3], [-400, -399, -398]);
+        assert!(
+            samples
+                .iter()
+                .all(|day| day.unsigned_abs() <= u128::from(SAMPLE_SPAN))
+        );
+        assert!(samples.iter().any(|day| *day < -900_000));
+        assert!(samples.iter().any(|day| *day > 900_000));
+    }
+}
@@ -0,0 +1,236 @@
+//! Snapping days to the start of calendar units, such as when dragging events in an editor.
+
+use crate::date::gregorian::Date;
+use crate::{Calendar, StandardCalendar, Weekday};
+
+/// The boundaries to snap to.
+///
+/// Decades and centuries start on the same years as [`Year::decade_start`] and [`Year::century_start`]: 1991 and
+/// 1901 rather than 1990 and 1900, since there is no year 0.
+///
+/// [`Year::decade_start`]: crate::date::gregorian::Year::decade_start
+/// [`Year::century_start`]: crate::date::gregorian::Year::century_start
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapUnit {
+    /// Every day. Since [`StandardCalendar`] only counts whole days, this never moves anything.
+    Day,
+    /// Mondays, which start [ISO 8601](https://en.wikipedia.org/wiki/ISO_week_date) weeks.
+    Week,
+    /// The 1st of every month.
+    MonthStart,
+    /// January 1st of every year.
+    YearStart,
+    /// January 1st of the first year of every decade.
+    DecadeStart,
+    /// January 1st of the first year of every century.
+    CenturyStart,
+}
+
+/// Which boundary to snap to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapMode {
+    /// The closest boundary. Days exactly halfway between two go to the later one.
+    Nearest,
+    /// The last boundary on or before the day.
+    Floor,
+    /// The first boundary on or after the day.
+    Ceil,
+}
+
+/// Moves `day` to a boundary of `unit`, chosen by `mode`.
+///
+/// Days already on a boundary are left where they are.
+///
+/// # Examples
+/// ```
+/// use time::{StandardCalendar, date::gregorian::date, snap::{SnapMode, SnapUnit, snap}};
+///
+/// let day = StandardCalendar::from(&date!(1995-08-20));
+/// assert_eq!(snap(&day, SnapUnit::MonthStart, SnapMode::Floor), StandardCalendar::from(&date!(1995-08-01)));
+/// assert_eq!(snap(&day, SnapUnit::MonthStart, SnapMode::Nearest), StandardCalendar::from(&date!(1995-09-01)));
+/// assert_eq!(snap(&day, SnapUnit::DecadeStart, SnapMode::Ceil), StandardCalendar::from(&date!(2001-01-01)));
+/// ```
+pub fn snap(day: &StandardCalendar, unit: SnapUnit, mode: SnapMode) -> StandardCalendar {
+    let floor = floor(day, unit);
+    if floor == *day {
+        return floor;
+    }
+    let ceil = next(floor, unit);
+    match mode {
+        SnapMode::Floor => floor,
+        SnapMode::Ceil => ceil,
+        SnapMode::Nearest if day.days - floor.days < ceil.days - day.days => floor,
+        SnapMode::Nearest => ceil,
+    }
+}
+
+/// The last boundary of `unit` on or before `day`.
+fn floor(day: &StandardCalendar, unit: SnapUnit) -> StandardCalendar {
+    let date = Date::from(*day);
+    let start = match unit {
+        SnapUnit::Day => return *day,
+        SnapUnit::Week => {
+            let since_monday = date.weekday() as i128 - Weekday::Monday as i128;
+            return StandardCalendar::new(day.days - since_monday);
+        }
+        // The 1st exists in every month.
+        SnapUnit::MonthStart => Date::from_parts(date.year(), date.month(), 1).unwrap(),
+        SnapUnit::YearStart => Date::from_year(date.year()),
+        SnapUnit::DecadeStart => Date::from_year(date.year().decade_start()),
+        SnapUnit::CenturyStart => Date::from_year(date.year().century_start()),
+    };
+    StandardCalendar::from(&start)
+}
+
+/// The boundary of `unit` right after `boundary`, which must be one itself.
+fn next(boundary: StandardCalendar, unit: SnapUnit) -> StandardCalendar {
+    match unit {
+        SnapUnit::Day => StandardCalendar::new(boundary.days + 1),
+        SnapUnit::Week => StandardCalendar::new(boundary.days + 7),
+        // Boundaries are on the 1st, which every month has, so they are never clamped.
+        SnapUnit::MonthStart => boundary.add_months(1),
+        SnapUnit::YearStart => boundary.add_years(1),
+        // Both kinds of bucket have a fixed size, so the next one starts that many years later, skipping year 0.
+        SnapUnit::DecadeStart => boundary.add_years(10),
+        SnapUnit::CenturyStart => boundary.add_years(100),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::gregorian::date;
+
+    fn day(date: Date) -> StandardCalendar {
+        StandardCalendar::from(&date)
+    }
+
+    #[test]
+    fn boundaries() {
+        // (day, unit, floor, ceil)
+        let cases = [
+            (
+                date!(2024 - 02 - 29),
+                SnapUnit::Week,
+                date!(2024 - 02 - 26),
+                date!(2024 - 03 - 04),
+            ),
+            (
+                date!(2024 - 12 - 15),
+                SnapUnit::MonthStart,
+                date!(2024 - 12 - 01),
+                date!(2025 - 01 - 01),
+            ),
+            (
+                date!(-1 - 12 - 31),
+                SnapUnit::MonthStart,
+                date!(-1 - 12 - 01),
+                date!(1 - 01 - 01),
+            ),
+            (
+                date!(-1 - 06 - 01),
+                SnapUnit::YearStart,
+                date!(-1 - 01 - 01),
+                date!(1 - 01 - 01),
+            ),
+            (
+                date!(2000 - 12 - 31),
+                SnapUnit::DecadeStart,
+                date!(1991 - 01 - 01),
+                date!(2001 - 01 - 01),
+            ),
+            (
+                date!(-5 - 03 - 01),
+                SnapUnit::DecadeStart,
+                date!(-10 - 01 - 01),
+                date!(1 - 01 - 01),
+            ),
+            (
+                date!(-11 - 03 - 01),
+                SnapUnit::DecadeStart,
+                date!(-20 - 01 - 01),
+                date!(-10 - 01 - 01),
+            ),
+            (
+                date!(1900 - 01 - 02),
+                SnapUnit::CenturyStart,
+                date!(1801 - 01 - 01),
+                date!(1901 - 01 - 01),
+            ),
+            (
+                date!(-44 - 03 - 15),
+                SnapUnit::CenturyStart,
+                date!(-100 - 01 - 01),
+                date!(1 - 01 - 01),
+            ),
+        ];
+        for (date, unit, floor, ceil) in cases {
+            assert_eq!(
+                snap(&day(date), unit, SnapMode::Floor),
+                day(floor),
+                "{date:?} {unit:?}"
+            );
+            assert_eq!(
+                snap(&day(date), unit, SnapMode::Ceil),
+                day(ceil),
+                "{date:?} {unit:?}"
+            );
+            for mode in [SnapMode::Floor, SnapMode::Ceil, SnapMode::Nearest] {
+                assert_eq!(snap(&day(floor), unit, mode), day(floor), "{floor:?}");
+                assert_eq!(snap(&day(ceil), unit, mode), day(ceil), "{ceil:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        let cases = [
+            (
+                date!(2024 - 06 - 15),
+                SnapUnit::MonthStart,
+                date!(2024 - 06 - 01),
+            ),
+            // Halfway through a 30-day month goes to the later boundary.
+            (
+                date!(2024 - 06 - 16),
+                SnapUnit::MonthStart,
+                date!(2024 - 07 - 01),
+            ),
+            (
+                date!(2024 - 06 - 17),
+                SnapUnit::MonthStart,
+                date!(2024 - 07 - 01),
+            ),
+            (date!(2024 - 02 - 29), SnapUnit::Week, date!(2024 - 02 - 26)),
+            (date!(2024 - 03 - 01), SnapUnit::Week, date!(2024 - 03 - 04)),
+            (
+                date!(1995 - 12 - 31),
+                SnapUnit::DecadeStart,
+                date!(1991 - 01 - 01),
+            ),
+            (
+                date!(1996 - 01 - 02),
+                SnapUnit::DecadeStart,
+                date!(2001 - 01 - 01),
+            ),
+            (
+                date!(-6 - 01 - 01),
+                SnapUnit::DecadeStart,
+                date!(-10 - 01 - 01),
+            ),
+            (
+                date!(-4 - 01 - 01),
+                SnapUnit::DecadeStart,
+                date!(1 - 01 - 01),
+            ),
+            (date!(2024 - 06 - 15), SnapUnit::Day, date!(2024 - 06 - 15)),
+        ];
+        for (date, unit, nearest) in cases {
+            assert_eq!(
+                snap(&day(date), unit, SnapMode::Nearest),
+                day(nearest),
+                "{date:?} {unit:?}"
+            );
+        }
+    }
+}
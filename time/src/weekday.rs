@@ -0,0 +1,126 @@
+use crate::locale::Locale;
+
+/// A day of the week.
+///
+/// The discriminants follow [ISO 8601](https://en.wikipedia.org/wiki/ISO_week_date), where the week starts on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 7,
+}
+
+impl Weekday {
+    /// All weekdays, starting from [`Weekday::Monday`].
+    pub const ALL: [Weekday; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// The English name of this weekday.
+    pub fn name(self) -> &'static str {
+        self.name_in(Locale::En)
+    }
+
+    /// The English abbreviation of this weekday's name.
+    pub fn abbreviation(self) -> &'static str {
+        self.abbreviation_in(Locale::En)
+    }
+
+    /// The name of this weekday in the given `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Weekday, locale::Locale};
+    ///
+    /// assert_eq!(Weekday::Wednesday.name_in(Locale::Es), "miércoles");
+    /// assert_eq!(Weekday::Wednesday.name_in(Locale::De), "Mittwoch");
+    /// ```
+    pub fn name_in(self, locale: Locale) -> &'static str {
+        locale.names().weekdays[self as usize - 1]
+    }
+
+    /// The abbreviation of this weekday's name in the given `locale`.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Weekday, locale::Locale};
+    ///
+    /// assert_eq!(Weekday::Saturday.abbreviation_in(Locale::PtBr), "sáb");
+    /// ```
+    pub fn abbreviation_in(self, locale: Locale) -> &'static str {
+        locale.names().weekday_abbreviations[self as usize - 1]
+    }
+
+    /// How many days it takes to go forward from `self` to the next `other`, from 0 to 6.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.days_until(Weekday::Thursday), 3);
+    /// assert_eq!(Weekday::Thursday.days_until(Weekday::Monday), 4);
+    /// assert_eq!(Weekday::Sunday.days_until(Weekday::Sunday), 0);
+    /// ```
+    pub fn days_until(self, other: Weekday) -> u8 {
+        (other as u8 + 7 - self as u8) % 7
+    }
+}
+
+/// Which days of the week are not working days.
+///
+/// Defaults to Saturday and Sunday.
+///
+/// # Examples
+/// ```
+/// use time::{Weekday, weekday::WeekendDef};
+///
+/// let default = WeekendDef::default();
+/// assert!(default.is_weekend(Weekday::Sunday));
+/// assert!(!default.is_weekend(Weekday::Friday));
+///
+/// let friday_saturday = WeekendDef::new(&[Weekday::Friday, Weekday::Saturday]);
+/// assert!(friday_saturday.is_weekend(Weekday::Friday));
+/// assert!(!friday_saturday.is_weekend(Weekday::Sunday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekendDef {
+    /// Whether each weekday is part of the weekend, starting from [`Weekday::Monday`].
+    days: [bool; 7],
+}
+
+impl WeekendDef {
+    /// Creates a [`WeekendDef`] where the given `days` are the weekend.
+    pub fn new(days: &[Weekday]) -> Self {
+        let mut weekend = [false; 7];
+        for day in days {
+            weekend[*day as usize - 1] = true;
+        }
+        Self { days: weekend }
+    }
+
+    /// Whether `day` is part of the weekend.
+    pub fn is_weekend(&self, day: Weekday) -> bool {
+        self.days[day as usize - 1]
+    }
+
+    /// How many working days there are in a week.
+    pub fn working_days_per_week(&self) -> u8 {
+        self.days.iter().filter(|weekend| !**weekend).count() as u8
+    }
+}
+
+impl Default for WeekendDef {
+    fn default() -> Self {
+        Self::new(&[Weekday::Saturday, Weekday::Sunday])
+    }
+}